@@ -1,13 +1,15 @@
 #![allow(clippy::needless_return)]
 
+use ferrum_compiler::config::{Config, MessageFormat};
+use ferrum_compiler::diagnostics::{Diagnostics, ErrorCode};
 use ferrum_compiler::helpers;
+use ferrum_compiler::lint::{Lint, LintLevel};
 use ferrum_compiler::result::Result;
 
 use std::env;
+use std::io::{self, IsTerminal};
 use std::path;
-
-use env_logger;
-use log;
+use std::process;
 
 fn main() -> Result {
     if env::var("RUST_LOG").is_ok() {
@@ -18,9 +20,34 @@ fn main() -> Result {
             .init();
     }
 
-    let root_dir = get_root_dir();
+    if env::args().nth(1).as_deref() == Some("explain") {
+        explain(env::args().nth(2));
+    }
+
+    let (root_dir, message_format, lint_levels, apply_fixes) = parse_args();
+
+    // Plain output for CI logs and anything else that isn't a terminal
+    let color = env::var_os("NO_COLOR").is_none() && io::stderr().is_terminal();
+
+    let cfg = lint_levels
+        .into_iter()
+        .fold(Config::builder(), |builder, (lint, level)| {
+            builder.lint_level(lint, level)
+        })
+        .default_from_project_root(root_dir)
+        .color_diagnostics(color)
+        .message_format(message_format)
+        .apply_fixes(apply_fixes)
+        .build();
 
-    let out = helpers::run_full(root_dir.into())?;
+    let out = match helpers::run_full(cfg) {
+        Ok(out) => out,
+
+        // Already reported by run_full
+        Err(e) if Diagnostics::from_error(&e).is_some() => process::exit(1),
+
+        Err(e) => return Err(e),
+    };
 
     println!("{}", String::from_utf8(out.stderr)?);
     println!("Output:\n------\n");
@@ -30,12 +57,62 @@ fn main() -> Result {
     return Ok(());
 }
 
-fn get_root_dir() -> path::PathBuf {
-    if env::args().count() != 2 {
-        panic!("Expected 1 arg: path to the root of the project directory");
+fn explain(code: Option<String>) -> ! {
+    let Some(code) = code else {
+        eprintln!("Expected an error code to explain, such as 'explain FE0006'");
+        process::exit(1);
+    };
+
+    let Some(error_code) = ErrorCode::lookup(&code) else {
+        eprintln!("{code} is not a known error code");
+        process::exit(1);
+    };
+
+    println!("{}: {}\n", error_code.code, error_code.title);
+    println!("{}", error_code.explanation);
+
+    process::exit(0);
+}
+
+fn parse_args() -> (path::PathBuf, MessageFormat, Vec<(Lint, LintLevel)>, bool) {
+    let mut message_format = MessageFormat::Human;
+    let mut lint_levels = vec![];
+    let mut apply_fixes = false;
+    let mut root_dir = None;
+
+    for arg in env::args().skip(1) {
+        if arg == "--fix" {
+            apply_fixes = true;
+            continue;
+        }
+
+        // --allow=unused_mut, --warn=shadowed_names, --deny=unused_imports
+        let lint_level = arg.strip_prefix("--").and_then(|arg| arg.split_once('='));
+        if let Some((level, name)) = lint_level {
+            if let Some(level) = LintLevel::from_name(level) {
+                let Some(lint) = Lint::from_name(name) else {
+                    panic!("Unknown lint {name:?}");
+                };
+
+                lint_levels.push((lint, level));
+                continue;
+            }
+        }
+
+        match arg.strip_prefix("--message-format=") {
+            Some("human") => message_format = MessageFormat::Human,
+            Some("json") => message_format = MessageFormat::Json,
+            Some(other) => panic!("Unknown message format {other:?}, expected 'human' or 'json'"),
+
+            None if root_dir.is_none() => root_dir = Some(arg),
+            None => panic!("Expected 1 arg: path to the root of the project directory"),
+        }
     }
 
-    let root_dir = env::args().last().unwrap();
+    let Some(root_dir) = root_dir else {
+        panic!("Expected 1 arg: path to the root of the project directory");
+    };
+
     let root_dir = path::PathBuf::from(root_dir);
     if !root_dir.is_dir() {
         panic!(
@@ -44,5 +121,5 @@ fn get_root_dir() -> path::PathBuf {
         );
     }
 
-    return root_dir;
+    return (root_dir, message_format, lint_levels, apply_fixes);
 }
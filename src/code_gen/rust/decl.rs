@@ -2,9 +2,7 @@ use super::*;
 
 impl ir::RustIRDeclVisitor<Result<Arc<str>>> for RustCodeGen {
     fn visit_fn_decl(&mut self, decl: &mut ir::RustIRFnDecl) -> Result<Arc<str>> {
-        let mut out = String::new();
-
-        // TODO: Handle proc-macros
+        let mut out = self.macros(&decl.macros);
 
         match &decl.decl_mod {
             Some(ir::RustIRDeclMod::Pub) => out.push_str("pub "),
@@ -16,9 +14,31 @@ impl ir::RustIRDeclVisitor<Result<Arc<str>>> for RustCodeGen {
             out.push_str("async ");
         }
 
-        // TODO: Handle generics
+        out.push_str(&format!("fn {}", decl.name));
+
+        if let Some(generics) = &decl.generics {
+            out.push_str(&format!("<{}>", generics.params.join(", ")));
+        }
+
+        out.push('(');
 
-        out.push_str(&format!("fn {}(", decl.name));
+        if let Some(self_param) = &decl.self_param {
+            match self_param.ref_type {
+                Some(ir::RustIRRefType::Shared) => out.push('&'),
+                Some(ir::RustIRRefType::Mut) => out.push_str("&mut "),
+                None => {}
+            }
+
+            if self_param.is_mut {
+                out.push_str("mut ");
+            }
+
+            out.push_str("self");
+
+            if !decl.params.is_empty() {
+                out.push_str(", ");
+            }
+        }
 
         for mut param in decl.params.clone() {
             out.push_str(&format!("{}: ", param.name));
@@ -35,7 +55,7 @@ impl ir::RustIRDeclVisitor<Result<Arc<str>>> for RustCodeGen {
         if let Some(return_type) = &mut decl.return_type {
             out.push_str("-> ");
 
-            let code = self.translate_static_type(return_type);
+            let code = return_type.accept(self)?;
             out.push_str(&code);
 
             out.push(' ');
@@ -44,11 +64,11 @@ impl ir::RustIRDeclVisitor<Result<Arc<str>>> for RustCodeGen {
         let code = decl.body.accept(self)?;
         out.push_str(&code);
 
-        return Ok(out.into());
+        return Ok(self.mark(&decl.span, out.into()));
     }
 
     fn visit_struct_decl(&mut self, decl: &mut ir::RustIRStructDecl) -> Result<Arc<str>> {
-        let mut out = String::new();
+        let mut out = self.macros(&decl.macros);
 
         match &decl.decl_mod {
             Some(ir::RustIRDeclMod::Pub) => out.push_str("pub "),
@@ -60,6 +80,10 @@ impl ir::RustIRDeclVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push_str(&decl.name);
 
+        if let Some(generics) = &decl.generics {
+            out.push_str(&format!("<{}>", generics.params.join(", ")));
+        }
+
         out.push_str(" {");
 
         self.indent += 1;
@@ -98,6 +122,117 @@ impl ir::RustIRDeclVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push('}');
 
-        return Ok(out.into());
+        return Ok(self.mark(&decl.span, out.into()));
+    }
+
+    fn visit_enum_decl(&mut self, decl: &mut ir::RustIREnumDecl) -> Result<Arc<str>> {
+        let mut out = self.macros(&decl.macros);
+
+        match &decl.decl_mod {
+            Some(ir::RustIRDeclMod::Pub) => out.push_str("pub "),
+
+            None => {}
+        }
+
+        out.push_str("enum ");
+
+        out.push_str(&decl.name);
+
+        out.push_str(" {");
+
+        self.indent += 1;
+
+        for variant in &mut decl.variants {
+            out.push_str(&self.new_line());
+
+            out.push_str(&variant.name);
+
+            match &mut variant.fields {
+                ir::RustIREnumVariantFields::Unit => {}
+
+                ir::RustIREnumVariantFields::Tuple(types) => {
+                    let types_code = types
+                        .iter_mut()
+                        .map(|typ| typ.accept(self))
+                        .collect::<Result<Vec<Arc<str>>>>()?
+                        .join(", ");
+
+                    out.push_str(&format!("({types_code})"));
+                }
+
+                ir::RustIREnumVariantFields::Struct(fields) => {
+                    let fields_code = fields
+                        .iter_mut()
+                        .map(|field| {
+                            let typ = field.static_type_ref.accept(self)?;
+                            Ok(format!("{}: {typ}", field.name).into())
+                        })
+                        .collect::<Result<Vec<Arc<str>>>>()?
+                        .join(", ");
+
+                    out.push_str(&format!(" {{ {fields_code} }}"));
+                }
+            }
+
+            out.push(',');
+        }
+
+        self.indent -= 1;
+        out.push_str(&self.new_line());
+
+        out.push('}');
+
+        return Ok(self.mark(&decl.span, out.into()));
+    }
+
+    fn visit_impl_decl(&mut self, decl: &mut ir::RustIRImplDecl) -> Result<Arc<str>> {
+        let mut out = String::from("impl");
+
+        let generics = decl
+            .generics
+            .as_ref()
+            .map(|generics| format!("<{}>", generics.params.join(", ")))
+            .unwrap_or_default();
+
+        out.push_str(&format!("{generics} {}{generics} {{", decl.name));
+
+        self.indent += 1;
+
+        for (i, method) in decl.methods.iter_mut().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+
+            out.push_str(&self.new_line());
+
+            let code = method.accept(self)?;
+            out.push_str(&code);
+        }
+
+        self.indent -= 1;
+        out.push_str(&self.new_line());
+
+        out.push('}');
+
+        return Ok(self.mark(&decl.span, out.into()));
+    }
+}
+
+impl RustCodeGen {
+    /// The attributes of a declaration, each on its own line before it.
+    fn macros(&self, macros: &[ir::RustIRMacro]) -> String {
+        let mut out = String::new();
+
+        for r#macro in macros {
+            match r#macro {
+                ir::RustIRMacro::Derive(traits) => {
+                    out.push_str(&format!("#[derive({})]", traits.join(", ")));
+                }
+            }
+
+            out.push_str(&self.new_line());
+        }
+
+        return out;
     }
 }
@@ -19,7 +19,13 @@ impl ir::RustIRExprVisitor<Result<Arc<str>>> for RustCodeGen {
         &mut self,
         expr: &mut ir::RustIRStringLiteralExpr,
     ) -> Result<Arc<str>> {
-        return Ok(expr.literal.clone());
+        // Keep the source map marks unambiguous
+        let literal = expr
+            .literal
+            .replace(MARK_START, "\\u{e000}")
+            .replace(MARK_END, "\\u{e001}");
+
+        return Ok(literal.into());
     }
 
     fn visit_ident_expr(&mut self, expr: &mut ir::RustIRIdentExpr) -> Result<Arc<str>> {
@@ -107,9 +113,21 @@ impl ir::RustIRExprVisitor<Result<Arc<str>>> for RustCodeGen {
             ir::RustIRUnaryOp::Not => {
                 out.push('!');
             }
+            ir::RustIRUnaryOp::Negate => {
+                out.push('-');
+            }
         }
 
-        out.push_str(&expr.value.accept(self)?);
+        let value = expr.value.accept(self)?;
+
+        // `--x` is linted as a mistaken decrement
+        if matches!(expr.op, ir::RustIRUnaryOp::Negate) && value.starts_with('-') {
+            out.push('(');
+            out.push_str(&value);
+            out.push(')');
+        } else {
+            out.push_str(&value);
+        }
 
         return Ok(out.into());
     }
@@ -117,20 +135,29 @@ impl ir::RustIRExprVisitor<Result<Arc<str>>> for RustCodeGen {
     fn visit_binary_expr(&mut self, expr: &mut ir::RustIRBinaryExpr) -> Result<Arc<str>> {
         let mut out = String::new();
 
-        out.push_str(&expr.lhs.accept(self)?);
+        out.push_str(&self.binary_operand(&mut expr.lhs, &expr.op, false)?);
         out.push(' ');
 
         match &expr.op {
             ir::RustIRBinaryOp::Add => out.push('+'),
             ir::RustIRBinaryOp::Subtract => out.push('-'),
+            ir::RustIRBinaryOp::Multiply => out.push('*'),
+            ir::RustIRBinaryOp::Divide => out.push('/'),
+            ir::RustIRBinaryOp::Modulo => out.push('%'),
             ir::RustIRBinaryOp::Less => out.push('<'),
             ir::RustIRBinaryOp::LessEq => out.push_str("<="),
             ir::RustIRBinaryOp::Greater => out.push('>'),
             ir::RustIRBinaryOp::GreaterEq => out.push_str(">="),
+            ir::RustIRBinaryOp::Eq => out.push_str("=="),
+            ir::RustIRBinaryOp::NotEq => out.push_str("!="),
+            ir::RustIRBinaryOp::And => out.push_str("&&"),
+            ir::RustIRBinaryOp::Or => out.push_str("||"),
+            ir::RustIRBinaryOp::Range => out.push_str(".."),
+            ir::RustIRBinaryOp::RangeInclusive => out.push_str("..="),
         }
 
         out.push(' ');
-        out.push_str(&expr.rhs.accept(self)?);
+        out.push_str(&self.binary_operand(&mut expr.rhs, &expr.op, true)?);
 
         return Ok(out.into());
     }
@@ -145,6 +172,9 @@ impl ir::RustIRExprVisitor<Result<Arc<str>>> for RustCodeGen {
             ir::RustIRAssignOp::Eq => out.push('='),
             ir::RustIRAssignOp::PlusEq => out.push_str("+="),
             ir::RustIRAssignOp::MinusEq => out.push_str("-="),
+            ir::RustIRAssignOp::MultiplyEq => out.push_str("*="),
+            ir::RustIRAssignOp::DivideEq => out.push_str("/="),
+            ir::RustIRAssignOp::ModuloEq => out.push_str("%="),
         }
 
         out.push(' ');
@@ -244,6 +274,47 @@ impl ir::RustIRExprVisitor<Result<Arc<str>>> for RustCodeGen {
         return Ok(out.into());
     }
 
+    fn visit_match_expr(&mut self, expr: &mut ir::RustIRMatchExpr) -> Result<Arc<str>> {
+        let mut out = String::from("match ");
+        out.push_str(&expr.value.accept(self)?);
+        out.push_str(" {");
+
+        self.indent += 1;
+
+        for arm in &mut expr.arms {
+            out.push_str(&self.new_line());
+            out.push_str(&self.pattern(&mut arm.pattern)?);
+
+            if let Some(guard) = &mut arm.guard {
+                out.push_str(" if ");
+                out.push_str(&guard.accept(self)?);
+            }
+
+            out.push_str(" => {");
+
+            self.indent += 1;
+            out.push_str(&self.new_line());
+
+            let stmts_code = arm
+                .stmts
+                .iter_mut()
+                .map(|stmt| stmt.accept(self))
+                .collect::<Result<Vec<Arc<str>>>>()?
+                .join(&self.new_line());
+            out.push_str(&stmts_code);
+
+            self.indent -= 1;
+            out.push_str(&self.new_line());
+            out.push('}');
+        }
+
+        self.indent -= 1;
+        out.push_str(&self.new_line());
+        out.push('}');
+
+        return Ok(out.into());
+    }
+
     fn visit_construct_expr(&mut self, expr: &mut ir::RustIRConstructExpr) -> Result<Arc<str>> {
         let mut out = String::new();
 
@@ -296,3 +367,85 @@ impl ir::RustIRExprVisitor<Result<Arc<str>>> for RustCodeGen {
         return Ok(out.into());
     }
 }
+
+impl RustCodeGen {
+    fn pattern(&mut self, pattern: &mut ir::RustIRPattern) -> Result<String> {
+        match pattern {
+            ir::RustIRPattern::Wildcard => return Ok("_".to_string()),
+            ir::RustIRPattern::Literal(expr) => return Ok(expr.accept(self)?.to_string()),
+            ir::RustIRPattern::Binding(name) => return Ok(name.to_string()),
+            ir::RustIRPattern::Path(path) => {
+                return Ok(Self::translate_static_path(path).to_string())
+            }
+
+            ir::RustIRPattern::TupleStruct(pattern) => {
+                let fields = pattern
+                    .fields
+                    .iter_mut()
+                    .map(|field| self.pattern(field))
+                    .collect::<Result<Vec<String>>>()?
+                    .join(", ");
+
+                return Ok(format!(
+                    "{}({fields})",
+                    Self::translate_static_path(&mut pattern.path)
+                ));
+            }
+
+            ir::RustIRPattern::Struct(pattern) => {
+                let mut fields = vec![];
+
+                for field in &mut pattern.fields {
+                    match &field.pattern {
+                        // Shorthand for binding a field to its own name
+                        ir::RustIRPattern::Binding(name) if *name == field.name => {
+                            fields.push(name.to_string());
+                        }
+                        _ => {
+                            let code = self.pattern(&mut field.pattern)?;
+                            fields.push(format!("{}: {code}", field.name));
+                        }
+                    }
+                }
+
+                if pattern.has_rest {
+                    fields.push("..".to_string());
+                }
+
+                let path = Self::translate_static_path(&mut pattern.path);
+
+                if fields.is_empty() {
+                    return Ok(format!("{path} {{}}"));
+                }
+
+                return Ok(format!("{path} {{ {} }}", fields.join(", ")));
+            }
+        }
+    }
+
+    /// Generates an operand of `op`, in parentheses when Rust would otherwise group it
+    /// differently, e.g. `(a < b) == c` since Rust comparisons can't be chained.
+    fn binary_operand(
+        &mut self,
+        operand: &mut ir::RustIRExpr,
+        op: &ir::RustIRBinaryOp,
+        is_rhs: bool,
+    ) -> Result<String> {
+        let code = operand.accept(self)?;
+
+        let ir::RustIRExpr::Binary(operand) = operand else {
+            return Ok(code.to_string());
+        };
+
+        let (precedence, operand_precedence) = (op.precedence(), operand.op.precedence());
+
+        let needs_parens = operand_precedence < precedence
+            || (operand_precedence == precedence && (is_rhs || op.is_comparison()));
+
+        if needs_parens {
+            return Ok(format!("({code})"));
+        }
+
+        return Ok(code.to_string());
+    }
+}
@@ -1,9 +1,12 @@
 mod decl;
 mod expr;
+mod source_map;
 mod r#static;
 mod stmt;
 mod r#use;
 
+pub use source_map::*;
+
 use std::path::PathBuf;
 
 use super::*;
@@ -14,6 +17,7 @@ use crate::{
         self, RustIRDeclAccept, RustIRExprAccept, RustIRRefType, RustIRStaticAccept,
         RustIRStmtAccept, RustIRUseAccept,
     },
+    token::Span,
 };
 
 #[derive(Debug, Clone)]
@@ -25,6 +29,7 @@ pub struct RustCodeGen {
     out: RustCode,
 
     indent: usize,
+    spans: Vec<Span>,
 }
 
 #[derive(Debug, Clone)]
@@ -36,6 +41,7 @@ pub struct RustCode {
 pub struct RustCodeFile {
     pub path: PathBuf,
     pub content: Arc<str>,
+    pub source_map: RustSourceMap,
 }
 
 impl IRToCode for ir::RustIR {
@@ -56,6 +62,7 @@ impl RustCodeGen {
             out: RustCode { files: vec![] },
 
             indent: 0,
+            spans: vec![],
         };
     }
 
@@ -63,8 +70,13 @@ impl RustCodeGen {
         let entry = self.entry.clone();
 
         for file in &mut entry.lock().unwrap().files {
-            let mut content =
-                "#![allow(unreachable_code, while_true, unused_labels)]\n\n".to_string();
+            // Unused names and labels are reported by the Ferrum linter, at the levels the
+            // user configured, so rustc shouldn't report them again
+            let mut content = concat!(
+                "#![allow(unused_labels, unused_variables, unused_mut, unused_imports)]",
+                "\n\n",
+            )
+            .to_string();
 
             for mod_decl in &mut file.mods {
                 let mut code = String::new();
@@ -94,9 +106,14 @@ impl RustCodeGen {
                 content.push_str(&self.new_line());
             }
 
+            let spans = std::mem::take(&mut self.spans);
+            let (content, source_map) =
+                RustSourceMap::extract(file.source.clone(), &content, &spans);
+
             self.out.files.push(RustCodeFile {
                 path: file.path.clone(),
                 content: content.into(),
+                source_map,
             });
         }
 
@@ -134,20 +151,6 @@ impl RustCodeGen {
         return Ok(out.into());
     }
 
-    fn translate_static_type(&mut self, static_type: &mut ir::RustIRStaticType) -> Arc<str> {
-        let mut out = String::new();
-
-        match static_type.ref_type {
-            Some(ir::RustIRRefType::Shared) => out.push('&'),
-            Some(ir::RustIRRefType::Mut) => out.push_str("&mut "),
-            None => {}
-        }
-
-        out.push_str(&Self::translate_static_path(&mut static_type.static_path));
-
-        return out.into();
-    }
-
     fn translate_static_path(static_path: &mut ir::RustIRStaticPath) -> Arc<str> {
         if let Some(root) = &mut static_path.root {
             let mut out = Self::translate_static_path(&mut *root).to_string();
@@ -162,6 +165,18 @@ impl RustCodeGen {
         return static_path.name.clone();
     }
 
+    /// Marks where the code for `span` starts in the generated output. The marks are
+    /// replaced by a [`RustSourceMap`] once the whole file is generated.
+    fn mark(&mut self, span: &Option<Span>, code: Arc<str>) -> Arc<str> {
+        let Some(span) = span else {
+            return code;
+        };
+
+        self.spans.push(span.clone());
+
+        return format!("{MARK_START}{}{MARK_END}{code}", self.spans.len() - 1).into();
+    }
+
     fn new_line(&self) -> String {
         let mut out = String::from("\n");
 
@@ -0,0 +1,109 @@
+use crate::token::Span;
+
+use std::path::PathBuf;
+
+/// Brackets the index of a span in the code being generated, see [`RustCodeGen::mark`].
+///
+/// Private-use characters never appear in generated code otherwise: identifiers are ASCII,
+/// and string literals escape them.
+pub(super) const MARK_START: char = '\u{E000}';
+pub(super) const MARK_END: char = '\u{E001}';
+
+/// Maps positions in a generated Rust file back to the Ferrum code they were compiled from.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RustSourceMap {
+    /// The Ferrum file the Rust file was generated from.
+    pub source: PathBuf,
+
+    /// Sorted by position in the generated file.
+    pub mappings: Vec<RustSourceMapping>,
+}
+
+/// The generated code starting at `line` and `column` (both 1-based) came from `span`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustSourceMapping {
+    pub line: usize,
+    pub column: usize,
+    pub span: Span,
+}
+
+impl RustSourceMap {
+    /// Finds the Ferrum span for a position in the generated file, which is the span of the
+    /// closest statement or declaration header starting at or before that position.
+    pub fn lookup(&self, line: usize, column: usize) -> Option<&Span> {
+        let idx = self
+            .mappings
+            .partition_point(|mapping| (mapping.line, mapping.column) <= (line, column));
+
+        return idx
+            .checked_sub(1)
+            .and_then(|idx| self.mappings.get(idx))
+            .map(|mapping| &mapping.span);
+    }
+
+    /// Strips the marks out of generated `content`, recording where each marked span ended up.
+    pub(super) fn extract(source: PathBuf, content: &str, spans: &[Span]) -> (String, Self) {
+        let mut out = String::with_capacity(content.len());
+        let mut mappings = vec![];
+
+        let mut line = 1;
+        let mut column = 1;
+
+        let mut chars = content.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                MARK_START => {
+                    let idx: String = chars.by_ref().take_while(|c| *c != MARK_END).collect();
+                    let idx: usize = idx.parse().expect("marks always hold an index");
+
+                    mappings.push(RustSourceMapping {
+                        line,
+                        column,
+                        span: spans[idx].clone(),
+                    });
+                }
+
+                '\n' => {
+                    out.push(c);
+
+                    line += 1;
+                    column = 1;
+                }
+
+                c => {
+                    out.push(c);
+
+                    column += 1;
+                }
+            }
+        }
+
+        return (out, Self { source, mappings });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_and_lookup() {
+        let content = format!(
+            "fn main() {{\n    {MARK_START}0{MARK_END}let x = 1;\n    {MARK_START}1{MARK_END}print(x);\n}}\n"
+        );
+
+        let (content, source_map) = RustSourceMap::extract(
+            "_main.fe".into(),
+            &content,
+            &[Span::on_line(0, 2, 5, 10), Span::on_line(0, 3, 5, 10)],
+        );
+
+        assert_eq!(content, "fn main() {\n    let x = 1;\n    print(x);\n}\n");
+
+        assert_eq!(source_map.lookup(1, 1), None);
+        assert_eq!(source_map.lookup(2, 5), Some(&Span::on_line(0, 2, 5, 10)));
+        assert_eq!(source_map.lookup(2, 14), Some(&Span::on_line(0, 2, 5, 10)));
+        assert_eq!(source_map.lookup(3, 11), Some(&Span::on_line(0, 3, 5, 10)));
+        assert_eq!(source_map.lookup(4, 1), Some(&Span::on_line(0, 3, 5, 10)));
+    }
+}
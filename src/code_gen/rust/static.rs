@@ -13,6 +13,16 @@ impl ir::RustIRStaticVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push_str(&static_type.static_path.accept(self)?);
 
+        if !static_type.generic_args.is_empty() {
+            let args = static_type
+                .generic_args
+                .iter_mut()
+                .map(|arg| arg.accept(self))
+                .collect::<Result<Vec<Arc<str>>>>()?;
+
+            out.push_str(&format!("<{}>", args.join(", ")));
+        }
+
         return Ok(out.into());
     }
 
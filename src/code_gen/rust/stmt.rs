@@ -5,7 +5,9 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
         &mut self,
         stmt: &mut ir::RustIRImplicitReturnStmt,
     ) -> Result<Arc<str>> {
-        return stmt.expr.accept(self);
+        let code = stmt.expr.accept(self)?;
+
+        return Ok(self.mark(&stmt.span, code));
     }
 
     fn visit_expr_stmt(&mut self, stmt: &mut ir::RustIRExprStmt) -> Result<Arc<str>> {
@@ -16,7 +18,7 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push(';');
 
-        return Ok(out.into());
+        return Ok(self.mark(&stmt.span, out.into()));
     }
 
     fn visit_let_stmt(&mut self, stmt: &mut ir::RustIRLetStmt) -> Result<Arc<str>> {
@@ -27,14 +29,14 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
         }
 
         out.push_str(&stmt.name);
-        out.push(' ');
 
-        if stmt.explicit_type.is_some() {
-            todo!()
+        if let Some(explicit_type) = &mut stmt.explicit_type {
+            out.push_str(": ");
+            out.push_str(&explicit_type.static_type.accept(self)?);
         }
 
         if let Some(value) = &mut stmt.value {
-            out.push_str("= ");
+            out.push_str(" = ");
 
             let code = value.expr.accept(self)?;
             out.push_str(&code);
@@ -42,7 +44,7 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push(';');
 
-        return Ok(out.into());
+        return Ok(self.mark(&stmt.span, out.into()));
     }
 
     fn visit_return_stmt(&mut self, stmt: &mut ir::RustIRReturnStmt) -> Result<Arc<str>> {
@@ -54,7 +56,7 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push(';');
 
-        return Ok(out.into());
+        return Ok(self.mark(&stmt.span, out.into()));
     }
 
     fn visit_while_stmt(&mut self, stmt: &mut ir::RustIRWhileStmt) -> Result<Arc<str>> {
@@ -79,7 +81,41 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
         out.push_str(&self.new_line());
         out.push('}');
 
-        return Ok(out.into());
+        return Ok(self.mark(&stmt.span, out.into()));
+    }
+
+    fn visit_for_stmt(&mut self, stmt: &mut ir::RustIRForStmt) -> Result<Arc<str>> {
+        let mut out = String::new();
+
+        if let Some(label) = &stmt.label {
+            out.push_str(label);
+            out.push_str(": ");
+        }
+
+        out.push_str("for ");
+        out.push_str(&stmt.name);
+        out.push_str(" in ");
+
+        out.push_str(&stmt.iter.accept(self)?);
+
+        out.push_str(" {");
+
+        self.indent += 1;
+        out.push_str(&self.new_line());
+
+        let stmts_code = stmt
+            .stmts
+            .iter_mut()
+            .map(|stmt| stmt.accept(self))
+            .collect::<Result<Vec<Arc<str>>>>()?
+            .join(&self.new_line());
+        out.push_str(&stmts_code);
+
+        self.indent -= 1;
+        out.push_str(&self.new_line());
+        out.push('}');
+
+        return Ok(self.mark(&stmt.span, out.into()));
     }
 
     fn visit_break_stmt(&mut self, stmt: &mut ir::RustIRBreakStmt) -> Result<Arc<str>> {
@@ -97,6 +133,6 @@ impl ir::RustIRStmtVisitor<Result<Arc<str>>> for RustCodeGen {
 
         out.push(';');
 
-        return Ok(out.into());
+        return Ok(self.mark(&stmt.span, out.into()));
     }
 }
@@ -1,3 +1,5 @@
+use crate::lint::{Lint, LintLevel, LintLevels};
+
 use std::{default, path::PathBuf};
 
 pub const DEFAULT_SRC_DIR: &str = "src";
@@ -12,6 +14,23 @@ pub struct Config {
     pub rust_gen_dir: PathBuf,
     pub builds_dir: PathBuf,
     pub binary_file: PathBuf,
+    pub color_diagnostics: bool,
+    pub message_format: MessageFormat,
+    pub lint_levels: LintLevels,
+
+    /// Rewrite the source files with the machine-applicable suggestions of any diagnostics.
+    pub apply_fixes: bool,
+}
+
+/// How diagnostics are reported when compilation fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageFormat {
+    /// Rendered with source snippets, to stderr.
+    #[default]
+    Human,
+
+    /// One JSON object per diagnostic, one per line, to stdout.
+    Json,
 }
 
 impl Config {
@@ -43,6 +62,10 @@ pub struct ConfigBuilder {
     rust_gen_dir: Option<PathBuf>,
     builds_dir: Option<PathBuf>,
     binary_file: Option<PathBuf>,
+    color_diagnostics: bool,
+    message_format: MessageFormat,
+    lint_levels: LintLevels,
+    apply_fixes: bool,
 }
 
 impl ConfigBuilder {
@@ -60,6 +83,31 @@ impl ConfigBuilder {
         return self;
     }
 
+    pub fn color_diagnostics(mut self, color: bool) -> Self {
+        self.color_diagnostics = color;
+
+        return self;
+    }
+
+    pub fn message_format(mut self, format: MessageFormat) -> Self {
+        self.message_format = format;
+
+        return self;
+    }
+
+    /// Sets the level for the whole project, declarations can still override it.
+    pub fn lint_level(mut self, lint: Lint, level: LintLevel) -> Self {
+        self.lint_levels.set(lint, level);
+
+        return self;
+    }
+
+    pub fn apply_fixes(mut self, apply_fixes: bool) -> Self {
+        self.apply_fixes = apply_fixes;
+
+        return self;
+    }
+
     pub fn build(self) -> Config {
         return Config {
             src_dir: self.src_dir.unwrap_or(DEFAULT_SRC_DIR.into()),
@@ -71,6 +119,11 @@ impl ConfigBuilder {
             binary_file: self
                 .binary_file
                 .unwrap_or(PathBuf::from(DEFAULT_BUILDS_DIR).join("dev/out")),
+
+            color_diagnostics: self.color_diagnostics,
+            message_format: self.message_format,
+            lint_levels: self.lint_levels,
+            apply_fixes: self.apply_fixes,
         };
     }
 }
@@ -0,0 +1,693 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// A stable identifier for a kind of diagnostic, with a long-form explanation
+/// that can be printed on request (`explain FE0006`).
+///
+/// Codes are never reused or renumbered once released. Retired codes stay in
+/// [`ErrorCode::ALL`] so old output can still be explained.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub explanation: &'static str,
+}
+
+impl ErrorCode {
+    /// Finds a registered code, ignoring case (`fe0006` finds `FE0006`).
+    pub fn lookup(code: &str) -> Option<&'static ErrorCode> {
+        return Self::ALL
+            .iter()
+            .find(|error_code| error_code.code.eq_ignore_ascii_case(code));
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.code);
+    }
+}
+
+impl From<ErrorCode> for Arc<str> {
+    fn from(value: ErrorCode) -> Self {
+        return value.code.into();
+    }
+}
+
+macro_rules! error_codes {
+    ($($name:ident = $code:literal, $title:literal, $explanation:literal;)*) => {
+        impl ErrorCode {
+            $(
+                pub const $name: ErrorCode = ErrorCode {
+                    code: $code,
+                    title: $title,
+                    explanation: $explanation,
+                };
+            )*
+
+            pub const ALL: &'static [ErrorCode] = &[$(Self::$name),*];
+        }
+    };
+}
+
+error_codes! {
+    INVALID_TOKEN = "FE0001", "Invalid token",
+"The source contains characters that don't form a valid token, such as a stray
+symbol or a string literal that is never closed.
+
+    pub fn main()
+        print(\"Hello)   // missing closing quote
+    ;
+
+Remove the character or close the literal.";
+
+    SYNTAX_ERROR = "FE0002", "Syntax error",
+"The tokens are valid, but they aren't arranged in a way the grammar allows,
+for example a declaration missing its name or a block missing its closing `;`.
+
+    pub fn ()   // expected a function name
+    ;
+
+The diagnostic points at the first token that couldn't be parsed.";
+
+    UNEXPECTED_EOF = "FE0003", "Unexpected end of file",
+"The file ended while a declaration, statement or expression was still open.
+This is usually a block that is missing its closing `;`.
+
+    pub fn main()
+        print(\"hi\")
+    // missing `;` to close `main`";
+
+    UNSUPPORTED = "FE0004", "Unsupported feature",
+"The code uses part of the language that the compiler doesn't support yet,
+such as short function bodies, `CRASH!` or the `then` and `else` branches of a
+`while` expression. Rewrite it using supported syntax, for example a block body
+instead of a short body.";
+
+    UNRESOLVABLE_TYPES = "FE0005", "Types can't be resolved",
+"The type resolver stopped making progress before every declaration had a
+type, without finding a more specific error. This happens when declarations
+depend on each other in a cycle that no amount of resolving can break.
+
+Give the declarations involved explicit types, or break the cycle.";
+
+    UNREACHABLE_CODE = "FE0006", "Unreachable code",
+"A statement follows a `return`, `break` or `then` that always exits the
+block, so it can never run.
+
+    fn answer(): Int
+        return 42
+        print(\"done\")   // never runs
+    ;
+
+Remove the statement, or move it before the terminal statement. This is the
+`unreachable_code` lint.";
+
+    GENERATED_RUST = "FE0007", "Error in the generated Rust",
+"The Ferrum code passed every check, but rustc rejected the Rust code generated
+from it. This is usually a bug in the compiler, or a Ferrum feature whose
+translation to Rust isn't complete yet.
+
+The diagnostic points at the Ferrum statement the failing Rust was generated
+from, and its notes include rustc's original message and error code. The
+generated project is kept in `.ferrum/compiled_rust` for inspection.";
+
+    UNKNOWN_ATTRIBUTE = "FE0008", "Unknown attribute",
+"An attribute on a declaration isn't one the compiler knows. The supported
+attributes set lint levels: `@allow(...)`, `@warn(...)` and `@deny(...)`.
+
+    @alow(unused_variables)   // typo for `allow`
+    fn draft()
+    ;";
+
+    UNKNOWN_LINT = "FE0009", "Unknown lint",
+"A lint attribute names a lint that doesn't exist, so it has no effect.
+
+    @allow(unused_variable)   // the lint is `unused_variables`
+
+The lints are `unused_variables`, `unused_imports`, `unused_labels`,
+`unused_mut`, `shadowed_names`, `unreachable_code`, `constant_conditions` and
+`while_true`.";
+
+    UNKNOWN_NAME = "FE0010", "Unknown name",
+"A name is used that isn't declared in this scope or any enclosing scope.
+
+    pub fn main()
+        print(mesage)   // typo for `message`
+    ;
+
+Check the spelling, declare the variable first, or import it with `use`.";
+
+    UNKNOWN_TYPE = "FE0011", "Unknown type",
+"A type name is used that isn't a built-in type, a declared struct, or an
+imported type.
+
+    fn area(shape: Shap): Int   // typo for `Shape`
+
+Check the spelling or import the type with `use`.";
+
+    UNRESOLVED_IMPORT = "FE0012", "Unresolved import",
+"A `use` names a package or item that doesn't exist.
+
+    use ./utilz   // no `utilz.fe` or `utilz/_pkg.fe` next to this file
+    use ::fe::prnt   // the std lib has no `prnt`
+
+Local packages are found relative to the current directory (`./`), the
+project's `src` directory (`~/`), and std lib items under `::fe`.";
+
+    NOT_A_PACKAGE = "FE0013", "Not a package",
+"A `use` path continues through something that isn't a package.
+
+    use ::fe::print::inner   // `print` is a function, not a package";
+
+    MISMATCHED_TYPES = "FE0014", "Mismatched types",
+"A value's type doesn't match the type expected where it's used, for example
+passing a `String` to an `Int` parameter or returning the wrong type.
+
+Sized numbers only match the same size, so a `U8` can't be passed as an `I64`.
+`Int` is an alias of `I64`.
+
+    fn double(n: Int): Int
+        return \"two\"   // expected Int, found String
+    ;";
+
+    NO_VALUE = "FE0015", "Expression has no value",
+"An expression is used as a value, but it doesn't produce one, such as a call
+to a function without a return type.
+
+    const x = print(\"hi\")   // `print` doesn't return anything";
+
+    NON_BOOL_CONDITION = "FE0016", "Condition isn't a Bool",
+"The condition of an `if` or `while` must have type `Bool`; other types aren't
+converted to a truth value.
+
+    if 1   // use a comparison such as `if count > 0`";
+
+    NOT_CALLABLE = "FE0017", "Not callable",
+"Something that isn't a function is called.
+
+    const count = 1
+    count()   // `count` is an Int";
+
+    TOO_MANY_ARGS = "FE0018", "Too many arguments",
+"A function is called with more arguments than it has parameters.
+
+    print(\"a\", \"b\")   // `print` takes 1 argument";
+
+    MISSING_ARGS = "FE0019", "Missing arguments",
+"A function is called with fewer arguments than it has parameters.
+
+    fn greet(name: String)
+    ;
+
+    greet()   // missing `name`";
+
+    INVALID_UNARY_OPERAND = "FE0020", "Invalid unary operand",
+"A unary operator is applied to a type it doesn't support, for example `not`
+on an `Int`, or `-` on an unsigned number like a `U8`.";
+
+    INVALID_BINARY_OPERANDS = "FE0021", "Invalid binary operands",
+"A binary operator is applied to types it doesn't support, for example adding a
+`Bool` to an `Int` or comparing two `String`s with `<`.
+
+`==` and `!=` only compare values of the same type: an `Int` with an `Int`, a
+decimal with a decimal, and instances of the same struct.
+
+Arithmetic and comparisons only apply to numbers of the same kind and size,
+like two `U8`s, while literals take the size of the other side. Dividing two
+`Int`s truncates towards zero, so `7 / 2` is `3`.
+
+Ranges (`..` and `..=`) are only made from integers.
+
+    if count == \"3\"   // Int and String";
+
+    NOT_CONSTRUCTABLE = "FE0022", "Not constructable",
+"Construction syntax (`Name { ... }`) is used on something that isn't a struct.";
+
+    UNKNOWN_FIELD = "FE0023", "Unknown field",
+"A struct construction sets a field that the struct doesn't declare.
+
+    pub struct Point
+        x: Int,
+        y: Int,
+    ;
+
+    Point { x: 1, y: 2, z: 3 }   // Point has no field `z`";
+
+    DUPLICATE_FIELD = "FE0024", "Duplicate field",
+"A struct construction sets the same field more than once.
+
+    Point { x: 1, x: 2, y: 3 }";
+
+    MISSING_FIELDS = "FE0025", "Missing fields",
+"A struct construction doesn't set every field of the struct.
+
+    Point { x: 1 }   // missing `y`";
+
+    NOT_AN_INSTANCE = "FE0026", "Not an instance",
+"A property is accessed on a value that isn't a struct instance.
+
+    const n = 1
+    print(n.value)   // Int has no properties";
+
+    UNKNOWN_PROPERTY = "FE0027", "Unknown property",
+"A property is accessed that the struct doesn't declare.
+
+    const p = Point { x: 1, y: 2 }
+    print(p.z)   // Point has no field `z`";
+
+    NOT_ASSIGNABLE = "FE0028", "Not assignable",
+"The left side of an assignment isn't a variable or field that can be written
+to, such as a literal or a function call.";
+
+    ASSIGN_TO_CONST = "FE0029", "Assignment to a const variable",
+"A variable declared with `const` is assigned after it was initialized.
+
+    const total = 0
+    total = 1   // declare it with `mut` instead
+
+A `const` declared without a value can be assigned later, but only once on
+any path.";
+
+    ASSIGN_THROUGH_SHARED_REF = "FE0030", "Assignment through a shared reference",
+"A value is assigned through a shared `&` reference. Only `&mut` references
+allow changing the value they refer to.";
+
+    RETURN_OUTSIDE_FN = "FE0031", "Return outside of a function",
+"A `return` statement appears somewhere other than a function body.";
+
+    MISSING_RETURN_VALUE = "FE0032", "Missing return value",
+"A bare `return` is used in a function that declares a return type.
+
+    fn answer(): Int
+        return   // return an Int, such as `return 42`
+    ;";
+
+    UNEXPECTED_RETURN_VALUE = "FE0033", "Unexpected return value",
+"A `return` has a value in a function that doesn't declare a return type.
+
+    fn log(text: String)
+        return text   // add `: String` to the signature, or return nothing
+    ;";
+
+    BREAK_OUTSIDE_LOOP = "FE0034", "Break outside of a loop",
+"A `break` appears outside of any `loop` or `while` body.";
+
+    THEN_OUTSIDE_IF = "FE0035", "Then outside of an if",
+"A `then` appears outside of any `if` block.";
+
+    UNKNOWN_LABEL = "FE0036", "Unknown label",
+"A `break'label` or `then'label` refers to a label that isn't on any enclosing
+block.
+
+    loop'outer
+        break'outr   // typo for `outer`
+    ;";
+
+    BREAK_WITH_VALUE = "FE0037", "Break with a value from a loop statement",
+"A `break` carries a value, but the loop it exits is used as a statement, so
+the value would be discarded. Use the loop as an expression, or drop the value.";
+
+    THEN_WITH_VALUE = "FE0038", "Then with a value from an if statement",
+"A `then` carries a value, but the `if` it exits is used as a statement, so the
+value would be discarded. Use the `if` as an expression, or drop the value.";
+
+    PRIVATE_IMPORT = "FE0039", "Private import",
+"A `use` imports an item from another package that isn't marked `pub`. Only
+public items can be used outside of the file that declares them.
+
+    // shapes.fe
+    fn area(width: Int, height: Int): Int   // missing `pub`
+
+    // _main.fe
+    use ./shapes::area
+
+Add `pub` to the declaration, or keep using it only inside its own file.";
+
+    UNUSED_VARIABLE = "FE0040", "Unused variable",
+"A variable or parameter is declared but its value is never read. Assigning to
+it doesn't count as reading it.
+
+    fn area(width: Int, height: Int): Int
+        const depth = 3   // never read
+        return width + height
+    ;
+
+Remove it, or start its name with an underscore (`_depth`) if it's intentional.
+This is the `unused_variables` lint.";
+
+    UNUSED_IMPORT = "FE0041", "Unused import",
+"A name brought into scope with `use` is never referred to in the file.
+
+    use ::fe::print   // nothing calls `print`
+
+Remove the `use`. Re-exports (`pub use`) are never reported. This is the
+`unused_imports` lint.";
+
+    UNUSED_LABEL = "FE0042", "Unused label",
+"A block has a label, but no `break` or `then` refers to it.
+
+    loop'outer   // no `break'outer`
+        break
+    ;
+
+Remove the label. This is the `unused_labels` lint.";
+
+    UNUSED_MUT = "FE0043", "Variable doesn't need to be mutable",
+"A variable is declared with `mut`, but it's never assigned to again and no
+`&mut` reference is taken to it.
+
+    mut total = 10   // use `const`
+    print(\"{total}\")
+
+Declare it with `const` instead. This is the `unused_mut` lint.";
+
+    SHADOWED_NAME = "FE0044", "Shadowed name",
+"A variable is declared with the same name as a variable or parameter that is
+still in scope, hiding the earlier one for the rest of the block.
+
+    fn greet(name: String)
+        const name = \"Hello, {name}\"   // hides the parameter
+    ;
+
+Give the new variable a different name. This is the `shadowed_names` lint.";
+
+    DIVISION_BY_ZERO = "FE0045", "Division by zero",
+"The right-hand side of `/` or `%` is an `Int` known to be zero at compile
+time, so the division would panic when the program runs.
+
+    const half = total / 0
+
+Decimals aren't reported: dividing a decimal by zero gives infinity or NaN.";
+
+    NOT_ITERABLE = "FE0046", "Not iterable",
+"A `for` loop is given something it can't loop over. Only ranges of `Int`s can
+be iterated, written `start..end` (excluding `end`) or `start..=end`.
+
+    for i in 10   // use `0..10`
+        print(\"{i}\")
+    ;";
+
+    NUMBER_OUT_OF_RANGE = "FE0047", "Number out of range",
+"A number known at compile time doesn't fit the sized type it's used as. Integer
+literals take the size of where they're used, so they have to be within its
+bounds: `0..=255` for a `U8`, `-128..=127` for an `I8`, and so on.
+
+    fn brightness(level: U8)
+        print(\"{level}\")
+    ;
+
+    brightness(300)   // U8 only goes up to 255";
+
+    ARITHMETIC_OVERFLOW = "FE0048", "Arithmetic overflow",
+"An operation on numbers known at compile time gives a result that doesn't fit
+their size. Integers without a size, like literals, are computed as an `Int`.
+
+    const seconds = 9223372036854775807 + 1   // larger than an Int can hold";
+
+    CONSTANT_CONDITION = "FE0049", "Constant condition",
+"The condition of an `if` or `while` is known at compile time, so it always
+takes the same branch.
+
+    if 1 > 2   // always false
+        print(\"never\")
+    ;
+
+Remove the condition and keep the branch that runs. This is the
+`constant_conditions` lint.";
+
+    WHILE_TRUE = "FE0050", "Infinite 'while' loop",
+"A `while` loop's condition is always true, so it only ends through a `break`
+or `return`.
+
+    while true   // use `loop`
+        print(\"again\")
+    ;
+
+Write it as a `loop` instead. This is the `while_true` lint.";
+
+    UNASSIGNED_VARIABLE = "FE0051", "Use of an unassigned variable",
+"A variable declared without a value is read before it's assigned on every
+path that reaches the read.
+
+    const label: String
+    if verbose
+        label = \"long\"
+    ;
+    print(label)   // not assigned when `verbose` is false
+
+Assign it in every branch, for example by adding an `else`.";
+
+    MISSING_TYPE_ANNOTATION = "FE0052", "Missing type annotation",
+"A variable is declared without a value, so its type can't be inferred.
+
+    const total   // write `const total: Int`
+
+Give it an explicit type, or a value to infer the type from.";
+
+    WRONG_GENERIC_ARG_COUNT = "FE0053", "Wrong number of type arguments",
+"A type is given a different number of type arguments than it has type
+parameters.
+
+    struct Pair<A, B> { first: A, second: B }
+
+    const pair: Pair<Int> = Pair { first: 1, second: 2 }   // needs `Pair<Int, Int>`
+
+Types without type parameters take no `<...>` at all.";
+
+    CANNOT_INFER_GENERIC = "FE0054", "Cannot infer type parameter",
+"A type parameter of a function or struct isn't used by any of the arguments
+or fields it's called or constructed with, so there's nothing to infer it from.
+
+    fn make<T>(): Int
+        return 1
+    ;
+
+    make()   // nothing says what `T` is
+
+Use the type parameter in a parameter or field, or remove it.";
+
+    IMPL_TARGET_NOT_STRUCT = "FE0055", "Methods on a non-struct type",
+"An `impl` block names something other than a struct.
+
+    impl Int   // only structs can have methods
+        fn double(self): Int
+            return self * 2
+        ;
+    ;
+
+Declare a struct and add the methods to it instead.";
+
+    FOREIGN_IMPL = "FE0056", "Methods on a struct from another file",
+"An `impl` block adds methods to a struct that's imported from another file.
+
+    use ./shapes::Circle
+
+    impl Circle   // `Circle` is declared in `shapes`
+    ;
+
+Move the `impl` block into the file that declares the struct.";
+
+    DUPLICATE_METHOD = "FE0057", "Duplicate method",
+"Two methods or associated functions of the same struct have the same name,
+even if they're in different `impl` blocks.
+
+    impl Counter
+        fn get(&self): Int
+            return self.count
+        ;
+
+        fn get(&self): Int   // already declared above
+            return self.count
+        ;
+    ;
+
+Rename or remove one of them.";
+
+    UNKNOWN_METHOD = "FE0058", "Unknown associated function",
+"A path names a function that the struct doesn't have.
+
+    struct Counter { count: Int }
+
+    Counter::new()   // there's no `impl Counter` with a `fn new`
+
+Check the spelling, or declare the function in an `impl` block.";
+
+    NOT_A_METHOD = "FE0059", "Associated function called as a method",
+"A function that doesn't take `self` is called on an instance.
+
+    impl Counter
+        fn new(): Counter
+            return Counter { count: 0 }
+        ;
+    ;
+
+    counter.new()   // write `Counter::new()`
+
+Call it through the struct's name instead.";
+
+    MUT_METHOD_ON_CONST = "FE0060", "Mutating method called on a const value",
+"A method that takes `&mut self` is called on a const variable or through a
+shared reference, so it can't change the instance.
+
+    const counter = Counter::new()
+    counter.increment()   // `increment` takes `&mut self`
+
+Declare the variable with `mut`, or pass a `&mut` reference.";
+
+    DUPLICATE_VARIANT = "FE0061", "Duplicate enum variant",
+"Two variants of the same enum have the same name.
+
+    enum Shape {
+        Circle { r: Int },
+        Circle(Int),   // already declared above
+    }
+
+Rename or remove one of them.";
+
+    UNKNOWN_VARIANT = "FE0062", "Unknown enum variant",
+"A path names a variant that the enum doesn't have.
+
+    enum Shape {
+        Circle { r: Int },
+    }
+
+    Shape::Square { side: 2 }   // there's no `Square` in `Shape`
+
+Check the spelling, or add the variant to the enum.";
+
+    NON_EXHAUSTIVE_MATCH = "FE0063", "Non-exhaustive match",
+"The arms of a `match` don't cover every value it could be given, so some
+values wouldn't match any arm.
+
+    match shape
+    Shape::Circle { r } then r
+    ;   // `Shape::Square(_)` isn't covered
+
+Add an arm for the missing pattern, or a `_` arm that matches anything else.
+Arms with an `if` guard don't count towards covering a pattern.";
+
+    PATTERN_FIELD_COUNT = "FE0064", "Wrong number of fields in pattern",
+"A pattern for a tuple variant lists a different number of fields than the
+variant has.
+
+    enum Shape {
+        Rect(Int, Int),
+    }
+
+    match shape
+    Shape::Rect(w) then w   // `Rect` has 2 fields
+    ;
+
+Add a pattern, or `_`, for every field.";
+
+    NOT_A_PATTERN = "FE0065", "Not a pattern",
+"The path in a pattern doesn't name something a value can be matched against,
+like a function, or a tuple variant used without its fields.
+
+    match shape
+    Shape::Rect then 0   // write `Shape::Rect(_, _)`
+    ;
+
+Use an enum variant or a struct, with patterns for its fields.";
+
+    MISSING_ARM_VALUE = "FE0066", "Match arm without a value",
+"A `match` is used as a value, but one of its arms doesn't give it one: a
+block arm finishes without `then`, or an arm's expression has no value.
+
+    const area = match shape
+    Shape::Circle { r } then r * r
+    Shape::Square(side)
+        print(side)   // missing `then side * side`
+    ;
+    ;
+
+End the block with a `then` statement, or with `return`.";
+
+    NOT_FORMATTABLE = "FE0067", "Value can't be formatted",
+"A format string interpolates a value that has no text form. A type parameter
+like `T` can be any type, so a generic function can't print it, and an optional
+may be `none`.
+
+    fn show<T>(value: T, count: Int?)
+        print(\"{value}\")   // T
+        print(\"{count}\")   // Int?
+    ;
+
+Interpolate a field or the result of a function that gives a concrete type, and
+check an optional with `if` or `match` first.";
+
+    UNLABELED_BREAK = "FE0068", "Break without the label of its loop",
+"A `break` without a label only exits loops without one, so a labeled loop has
+to be exited with its label.
+
+    loop'outer
+        break   // write `break'outer`
+    ;";
+
+    UNLABELED_THEN = "FE0069", "Then without a block to give a value to",
+"A `then` without a label gives its value to the closest `if` or `match` around
+it, but none of the blocks around it can take one. Label the block the value is
+for, and give it with `then'label`.";
+
+    UNKNOWN_NONE_TYPE = "FE0070", "Type of `none` can't be inferred",
+"A variable is initialized with `none`, which doesn't say what the optional
+would hold.
+
+    const found = none   // write `const found: Int? = none`
+
+Give the variable an explicit optional type.";
+
+    RECURSIVE_TYPE = "FE0071", "Recursive type",
+"A struct or enum holds a value of its own type in a field, directly or through
+other types, so a value of it would never end.
+
+    enum List {
+        Cons(Int, List),   // a `List` inside every `List`
+        Nil,
+    }
+
+An optional doesn't help, since `Node?` still holds a whole `Node`. There's no
+indirection like a box yet, so such a type can't be declared.";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_are_unique() {
+        let mut seen = HashSet::new();
+
+        for error_code in ErrorCode::ALL {
+            assert!(seen.insert(error_code.code), "duplicate {error_code}");
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(
+            ErrorCode::lookup("fe0006"),
+            Some(&ErrorCode::UNREACHABLE_CODE)
+        );
+        assert_eq!(
+            ErrorCode::lookup("FE0068"),
+            Some(&ErrorCode::UNLABELED_BREAK)
+        );
+        assert_eq!(
+            ErrorCode::lookup("FE0069"),
+            Some(&ErrorCode::UNLABELED_THEN)
+        );
+        assert_eq!(
+            ErrorCode::lookup("FE0070"),
+            Some(&ErrorCode::UNKNOWN_NONE_TYPE)
+        );
+        assert_eq!(
+            ErrorCode::lookup("FE0071"),
+            Some(&ErrorCode::RECURSIVE_TYPE)
+        );
+        assert_eq!(ErrorCode::lookup("FE9999"), None);
+    }
+}
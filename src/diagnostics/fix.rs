@@ -0,0 +1,113 @@
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// The machine-applicable suggestions of `diagnostics`, grouped by the file they apply to.
+///
+/// Suggestions without a file of their own apply to the file of their diagnostic.
+pub fn machine_applicable_fixes(
+    diagnostics: &[Diagnostic],
+) -> BTreeMap<PathBuf, Vec<&DiagnosticSuggestion>> {
+    let mut fixes: BTreeMap<PathBuf, Vec<&DiagnosticSuggestion>> = BTreeMap::new();
+
+    for diagnostic in diagnostics {
+        for suggestion in &diagnostic.suggestions {
+            if suggestion.applicability != Applicability::MachineApplicable {
+                continue;
+            }
+
+            let Some(file) = suggestion.file.as_ref().or(diagnostic.file.as_ref()) else {
+                continue;
+            };
+
+            fixes.entry(file.clone()).or_default().push(suggestion);
+        }
+    }
+
+    return fixes;
+}
+
+/// Replaces the span of each suggestion in `content`, returning the new content
+/// and how many suggestions were applied.
+///
+/// Suggestions overlapping one applied before them are skipped, so applying the
+/// same fix reported by two diagnostics only changes the code once.
+pub fn apply_suggestions(content: &str, suggestions: &[&DiagnosticSuggestion]) -> (String, usize) {
+    let mut suggestions = suggestions.to_vec();
+    suggestions.sort_by_key(|suggestion| suggestion.span.start.index);
+
+    let chars: Vec<char> = content.chars().collect();
+
+    let mut out = String::with_capacity(content.len());
+    let mut next = 0;
+    let mut applied = 0;
+
+    for suggestion in suggestions {
+        let start = suggestion.span.start.index;
+        let end = suggestion.span.end.index;
+
+        if start < next || end < start || end >= chars.len() {
+            continue;
+        }
+
+        out.extend(&chars[next..start]);
+        out.push_str(&suggestion.replacement);
+
+        next = end + 1;
+        applied += 1;
+    }
+
+    out.extend(&chars[next..]);
+
+    return (out, applied);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    #[test]
+    fn test_apply_suggestions() {
+        let content = "const x = 1;\nx = 2;\nprint(y);\n";
+
+        let diagnostics = vec![
+            Diagnostic::error("Cannot assign to const x")
+                .with_file("./_main.fe")
+                .with_suggestion(DiagnosticSuggestion::new(
+                    "use mut",
+                    Span::on_line(0, 1, 1, 5),
+                    "mut",
+                )),
+            Diagnostic::error("Cannot assign to const x")
+                .with_file("./_main.fe")
+                .with_suggestion(DiagnosticSuggestion::new(
+                    "use mut",
+                    Span::on_line(0, 1, 1, 5),
+                    "mut",
+                )),
+            Diagnostic::error("Unknown name y")
+                .with_file("./_main.fe")
+                .with_suggestion(DiagnosticSuggestion::new(
+                    "did you mean",
+                    Span::on_line(26, 1, 27, 27),
+                    "x",
+                )),
+            Diagnostic::error("Missing field")
+                .with_file("./_main.fe")
+                .with_suggestion(
+                    DiagnosticSuggestion::new("add it", Span::on_line(19, 1, 20, 20), "z")
+                        .maybe_incorrect(),
+                ),
+        ];
+
+        let fixes = machine_applicable_fixes(&diagnostics);
+        assert_eq!(fixes.len(), 1);
+
+        let (content, applied) = apply_suggestions(content, &fixes[Path::new("./_main.fe")]);
+
+        assert_eq!(content, "mut x = 1;\nx = 2;\nprint(x);\n");
+        assert_eq!(applied, 2);
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+use crate::token::Position;
+
+use serde::Serialize;
+
+/// Emits diagnostics as JSON for editors and CI, one object per line,
+/// in the spirit of `cargo --message-format=json`.
+///
+/// ```json
+/// {"file":"src/main.fe","severity":"error","code":null,"message":"Unexpected character '$'","span":{"start":{"index":18,"line":2,"column":5},"end":{"index":19,"line":2,"column":6},"label":"character not recognized"},"secondary":[],"notes":[],"suggestions":[]}
+/// ```
+///
+/// Each `end` is exclusive, just past the last character of its span, so a
+/// suggestion's replacement goes from `start` up to but not including `end`.
+///
+/// The field names are part of the output format: add new fields rather than
+/// renaming or removing existing ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DiagnosticJsonEmitter;
+
+impl DiagnosticJsonEmitter {
+    pub fn new() -> Self {
+        return Self;
+    }
+
+    /// Renders `diagnostic` as a single line of JSON, without a trailing newline.
+    pub fn emit(&self, diagnostic: &Diagnostic) -> String {
+        return serde_json::to_string(&JsonDiagnostic::from(diagnostic))
+            .expect("diagnostics always serialize");
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic<'a> {
+    file: Option<String>,
+    severity: &'static str,
+    code: Option<&'a str>,
+    message: &'a str,
+    span: Option<JsonLabel<'a>>,
+    secondary: Vec<JsonLabel<'a>>,
+    notes: Vec<&'a str>,
+    suggestions: Vec<JsonSuggestion<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLabel<'a> {
+    start: JsonPosition,
+    end: JsonPosition,
+    label: Option<&'a str>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonSuggestion<'a> {
+    message: &'a str,
+    start: JsonPosition,
+    end: JsonPosition,
+    replacement: &'a str,
+    applicability: &'static str,
+    file: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonPosition {
+    index: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> From<&'a Diagnostic> for JsonDiagnostic<'a> {
+    fn from(value: &'a Diagnostic) -> Self {
+        let severity = match value.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        };
+
+        return Self {
+            file: value
+                .file
+                .as_ref()
+                .map(|file| file.to_string_lossy().into_owned()),
+            severity,
+            code: value.code.as_deref(),
+            message: &value.message,
+            span: value.primary.as_ref().map(JsonLabel::from),
+            secondary: value.secondary.iter().map(JsonLabel::from).collect(),
+            notes: value.notes.iter().map(|note| note.as_ref()).collect(),
+            suggestions: value
+                .suggestions
+                .iter()
+                .map(|suggestion| JsonSuggestion {
+                    message: &suggestion.message,
+                    start: (&suggestion.span.start).into(),
+                    end: JsonPosition::after(&suggestion.span.end),
+                    replacement: &suggestion.replacement,
+                    applicability: match suggestion.applicability {
+                        Applicability::MachineApplicable => "machine-applicable",
+                        Applicability::MaybeIncorrect => "maybe-incorrect",
+                    },
+                    file: suggestion
+                        .file
+                        .as_ref()
+                        .map(|file| file.to_string_lossy().into_owned()),
+                })
+                .collect(),
+        };
+    }
+}
+
+impl<'a> From<&'a DiagnosticLabel> for JsonLabel<'a> {
+    fn from(value: &'a DiagnosticLabel) -> Self {
+        return Self {
+            start: (&value.span.start).into(),
+            end: JsonPosition::after(&value.span.end),
+            label: value.message.as_deref(),
+        };
+    }
+}
+
+impl JsonPosition {
+    /// The position just past `value`, since spans include their last character.
+    fn after(value: &Position) -> Self {
+        return Self {
+            index: value.index + 1,
+            line: value.line,
+            column: value.column + 1,
+        };
+    }
+}
+
+impl From<&Position> for JsonPosition {
+    fn from(value: &Position) -> Self {
+        return Self {
+            index: value.index,
+            line: value.line,
+            column: value.column,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emit_json() {
+        let diagnostic = Diagnostic::error("Unknown import 'fmt'")
+            .with_code("FE0001")
+            .with_file("src/main.fe")
+            .with_label(Span::on_line(4, 1, 5, 7), "not found")
+            .with_note("Imports must name a local package or the std lib")
+            .with_suggestion(
+                DiagnosticSuggestion::new("Import from std", Span::on_line(4, 1, 5, 7), "std::fmt")
+                    .maybe_incorrect(),
+            );
+
+        let emitted = DiagnosticJsonEmitter::new().emit(&diagnostic);
+
+        assert_eq!(
+            emitted,
+            [
+                r#"{"file":"src/main.fe","severity":"error","code":"FE0001","#,
+                r#""message":"Unknown import 'fmt'","#,
+                r#""span":{"start":{"index":4,"line":1,"column":5},"end":{"index":7,"line":1,"column":8},"label":"not found"},"#,
+                r#""secondary":[],"#,
+                r#""notes":["Imports must name a local package or the std lib"],"#,
+                r#""suggestions":[{"message":"Import from std","start":{"index":4,"line":1,"column":5},"end":{"index":7,"line":1,"column":8},"replacement":"std::fmt","applicability":"maybe-incorrect","file":null}]}"#,
+            ]
+            .concat()
+        );
+    }
+}
@@ -0,0 +1,243 @@
+mod codes;
+pub use codes::*;
+
+mod fix;
+pub use fix::*;
+
+mod json;
+pub use json::*;
+
+mod render;
+pub use render::*;
+
+use crate::token::Span;
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error => return write!(f, "error"),
+            Self::Warning => return write!(f, "warning"),
+            Self::Note => return write!(f, "note"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticLabel {
+    pub span: Span,
+    pub message: Option<Arc<str>>,
+}
+
+/// How confident a suggestion is, which decides whether it's applied automatically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+    /// The fix is certainly what was meant and can be applied without review.
+    MachineApplicable,
+
+    /// The fix is a good guess, or contains placeholder values, so it's only shown.
+    MaybeIncorrect,
+}
+
+/// A fix for a diagnostic: replace the text at `span` with `replacement`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticSuggestion {
+    pub message: Arc<str>,
+    pub span: Span,
+    pub replacement: Arc<str>,
+    pub applicability: Applicability,
+
+    /// Set when the fix is in a different file than the diagnostic, e.g. adding
+    /// `pub` to an item in the package it was imported from.
+    pub file: Option<PathBuf>,
+}
+
+impl DiagnosticSuggestion {
+    pub fn new(message: impl Into<Arc<str>>, span: Span, replacement: impl Into<Arc<str>>) -> Self {
+        return Self {
+            message: message.into(),
+            span,
+            replacement: replacement.into(),
+            applicability: Applicability::MachineApplicable,
+            file: None,
+        };
+    }
+
+    pub fn maybe_incorrect(mut self) -> Self {
+        self.applicability = Applicability::MaybeIncorrect;
+        return self;
+    }
+
+    pub fn in_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        return self;
+    }
+}
+
+/// A problem found in the user's source, reported by any phase of the compiler.
+///
+/// Diagnostics are returned as the error of a phase (wrapped in `anyhow::Error`),
+/// so callers can recover the structured form with `err.downcast_ref::<Diagnostic>()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<Arc<str>>,
+    pub message: Arc<str>,
+    pub file: Option<PathBuf>,
+    pub primary: Option<DiagnosticLabel>,
+    pub secondary: Vec<DiagnosticLabel>,
+    pub notes: Vec<Arc<str>>,
+    pub suggestions: Vec<DiagnosticSuggestion>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<Arc<str>>) -> Self {
+        return Self {
+            severity,
+            code: None,
+            message: message.into(),
+            file: None,
+            primary: None,
+            secondary: vec![],
+            notes: vec![],
+            suggestions: vec![],
+        };
+    }
+
+    pub fn error(message: impl Into<Arc<str>>) -> Self {
+        return Self::new(Severity::Error, message);
+    }
+
+    pub fn warning(message: impl Into<Arc<str>>) -> Self {
+        return Self::new(Severity::Warning, message);
+    }
+
+    pub fn with_code(mut self, code: impl Into<Arc<str>>) -> Self {
+        self.code = Some(code.into());
+        return self;
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        return self;
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.primary = Some(DiagnosticLabel {
+            span,
+            message: None,
+        });
+        return self;
+    }
+
+    pub fn with_label(mut self, span: Span, message: impl Into<Arc<str>>) -> Self {
+        self.primary = Some(DiagnosticLabel {
+            span,
+            message: Some(message.into()),
+        });
+        return self;
+    }
+
+    pub fn with_secondary(mut self, span: Span, message: impl Into<Arc<str>>) -> Self {
+        self.secondary.push(DiagnosticLabel {
+            span,
+            message: Some(message.into()),
+        });
+        return self;
+    }
+
+    pub fn with_note(mut self, note: impl Into<Arc<str>>) -> Self {
+        self.notes.push(note.into());
+        return self;
+    }
+
+    pub fn with_suggestion(mut self, suggestion: DiagnosticSuggestion) -> Self {
+        self.suggestions.push(suggestion);
+        return self;
+    }
+
+    pub fn is_error(&self) -> bool {
+        return self.severity == Severity::Error;
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+
+        if let Some(code) = &self.code {
+            write!(f, "[{code}]")?;
+        }
+
+        write!(f, ": {}", self.message)?;
+
+        match (&self.file, &self.primary) {
+            (Some(file), Some(primary)) => write!(
+                f,
+                "\n --> {}:{}:{}",
+                file.display(),
+                primary.span.start.line,
+                primary.span.start.column
+            )?,
+            (Some(file), None) => write!(f, "\n --> {}", file.display())?,
+            (None, Some(primary)) => write!(
+                f,
+                "\n --> {}:{}",
+                primary.span.start.line, primary.span.start.column
+            )?,
+            (None, None) => {}
+        }
+
+        for note in &self.notes {
+            write!(f, "\n  = note: {note}")?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// Every diagnostic reported by a phase that keeps going after the first error.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diagnostics(pub Vec<Diagnostic>);
+
+impl Diagnostics {
+    /// Recovers the diagnostics carried by an error returned from any phase,
+    /// whether it failed with a single [`Diagnostic`] or with many.
+    pub fn from_error(err: &anyhow::Error) -> Option<Self> {
+        if let Some(diagnostics) = err.downcast_ref::<Diagnostics>() {
+            return Some(diagnostics.clone());
+        }
+
+        return err
+            .downcast_ref::<Diagnostic>()
+            .map(|diagnostic| Self(vec![diagnostic.clone()]));
+    }
+}
+
+impl fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, diagnostic) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "\n\n")?;
+            }
+
+            write!(f, "{diagnostic}")?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl std::error::Error for Diagnostics {}
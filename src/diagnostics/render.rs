@@ -0,0 +1,216 @@
+use super::*;
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BOLD_RED: &str = "\x1b[1;31m";
+const BOLD_YELLOW: &str = "\x1b[1;33m";
+const BOLD_GREEN: &str = "\x1b[1;32m";
+const BOLD_BLUE: &str = "\x1b[1;34m";
+
+/// Renders diagnostics in the style of rustc, quoting the offending source
+/// lines and underlining the labelled spans.
+///
+/// ```text
+/// error: Unexpected character '$'
+///  --> src/main.fe:2:5
+///   |
+/// 2 |     $
+///   |     ^ character not recognized
+///   |
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticRenderer {
+    color: bool,
+}
+
+impl DiagnosticRenderer {
+    pub fn new(color: bool) -> Self {
+        return Self { color };
+    }
+
+    pub fn plain() -> Self {
+        return Self::new(false);
+    }
+
+    pub fn colored() -> Self {
+        return Self::new(true);
+    }
+
+    /// Renders `diagnostic` as a multi-line string, ending in a newline.
+    ///
+    /// `source` is the content of `diagnostic.file`. Without it, only the
+    /// header, location and notes are rendered.
+    pub fn render(&self, diagnostic: &Diagnostic, source: Option<&str>) -> String {
+        let mut out = String::new();
+
+        let severity_style = match diagnostic.severity {
+            Severity::Error => BOLD_RED,
+            Severity::Warning => BOLD_YELLOW,
+            Severity::Note => BOLD_GREEN,
+        };
+
+        out.push_str(&self.paint(severity_style, &diagnostic.severity.to_string()));
+        if let Some(code) = &diagnostic.code {
+            out.push_str(&self.paint(severity_style, &format!("[{code}]")));
+        }
+        out.push_str(&self.paint(BOLD, &format!(": {}", diagnostic.message)));
+        out.push('\n');
+
+        let mut labels = vec![];
+        if let Some(primary) = &diagnostic.primary {
+            labels.push((primary, true));
+        }
+        labels.extend(diagnostic.secondary.iter().map(|label| (label, false)));
+
+        let lines = source.map(|source| source.lines().collect::<Vec<&str>>());
+
+        let gutter_width = labels
+            .iter()
+            .map(|(label, _)| label.span.start.line.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let gutter = " ".repeat(gutter_width);
+
+        match (&diagnostic.file, &diagnostic.primary) {
+            (Some(file), Some(primary)) => out.push_str(&format!(
+                "{gutter}{} {}:{}:{}\n",
+                self.paint(BOLD_BLUE, "-->"),
+                file.display(),
+                primary.span.start.line,
+                primary.span.start.column,
+            )),
+            (Some(file), None) => out.push_str(&format!(
+                "{gutter}{} {}\n",
+                self.paint(BOLD_BLUE, "-->"),
+                file.display(),
+            )),
+            (None, _) => {}
+        }
+
+        if let Some(lines) = &lines {
+            labels.sort_by_key(|(label, _)| (label.span.start.line, label.span.start.column));
+
+            let bar = self.paint(BOLD_BLUE, "|");
+
+            if !labels.is_empty() {
+                out.push_str(&format!("{gutter} {bar}\n"));
+            }
+
+            let mut prev_line: Option<usize> = None;
+
+            for (label, is_primary) in &labels {
+                let line_num = label.span.start.line;
+
+                let Some(line) = line_num.checked_sub(1).and_then(|idx| lines.get(idx)) else {
+                    continue;
+                };
+
+                if prev_line != Some(line_num) {
+                    if let Some(prev_line) = prev_line {
+                        if line_num > prev_line + 1 {
+                            out.push_str(&format!("{}\n", self.paint(BOLD_BLUE, "...")));
+                        }
+                    }
+
+                    out.push_str(&format!(
+                        "{} {bar} {line}\n",
+                        self.paint(BOLD_BLUE, &format!("{line_num:>gutter_width$}")),
+                    ));
+                }
+                prev_line = Some(line_num);
+
+                let line_len = line.chars().count();
+
+                let start = label.span.start.column.max(1);
+                let end = if label.span.end.line == line_num {
+                    label.span.end.column
+                } else {
+                    line_len
+                }
+                .max(start);
+
+                let (marker, style) = if *is_primary {
+                    ('^', severity_style)
+                } else {
+                    ('-', BOLD_BLUE)
+                };
+
+                let mut underline = marker.to_string().repeat(end - start + 1);
+                if let Some(message) = &label.message {
+                    underline.push(' ');
+                    underline.push_str(message);
+                }
+
+                out.push_str(&format!(
+                    "{gutter} {bar} {}{}\n",
+                    " ".repeat(start - 1),
+                    self.paint(style, &underline),
+                ));
+            }
+
+            if !labels.is_empty() {
+                out.push_str(&format!("{gutter} {bar}\n"));
+            }
+        }
+
+        for note in &diagnostic.notes {
+            out.push_str(&format!(
+                "{gutter} {} {note}\n",
+                self.paint(BOLD, "= note:"),
+            ));
+        }
+
+        for suggestion in &diagnostic.suggestions {
+            out.push_str(&format!(
+                "{gutter} {} {}: `{}`\n",
+                self.paint(BOLD, "= help:"),
+                suggestion.message,
+                suggestion.replacement,
+            ));
+        }
+
+        return out;
+    }
+
+    fn paint(&self, style: &str, text: &str) -> String {
+        if self.color {
+            return format!("{style}{text}{RESET}");
+        }
+
+        return text.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_plain() {
+        let source = "pub fn main()\n    print(1, 2)\n;\n";
+
+        let diagnostic = Diagnostic::error("Too many arguments")
+            .with_file("src/main.fe")
+            .with_label(Span::on_line(0, 2, 14, 14), "unexpected argument")
+            .with_secondary(Span::on_line(0, 2, 5, 9), "this function takes 1 argument")
+            .with_note("Remove the extra argument");
+
+        let rendered = DiagnosticRenderer::plain().render(&diagnostic, Some(source));
+
+        assert_eq!(
+            rendered,
+            [
+                "error: Too many arguments",
+                " --> src/main.fe:2:14",
+                "  |",
+                "2 |     print(1, 2)",
+                "  |     ----- this function takes 1 argument",
+                "  |              ^ unexpected argument",
+                "  |",
+                "  = note: Remove the extra argument",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
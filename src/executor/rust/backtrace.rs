@@ -0,0 +1,159 @@
+use super::find_generated_file;
+
+use crate::code_gen::RustCode;
+
+use std::path::Path;
+
+/// Rewrites the locations in panic messages and backtraces printed by the
+/// generated program, so they point at the Ferrum code instead of the generated Rust.
+///
+/// ```text
+/// thread 'main' panicked at src/main.rs:9:5:     ->  thread 'main' panicked at ./_main.fe:4:5:
+///              at ./src/main.rs:9:5              ->               at ./_main.fe:4:5
+/// ```
+///
+/// Locations outside of the generated code, like the standard library, are kept as is.
+pub(super) fn map_runtime_output(output: &str, rust_gen_dir: &Path, code: &RustCode) -> String {
+    return output
+        .split_inclusive('\n')
+        .map(|line| map_line(line, rust_gen_dir, code))
+        .collect();
+}
+
+fn map_line(line: &str, rust_gen_dir: &Path, code: &RustCode) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(idx) = rest.find(".rs:") {
+        // The location starts after the last whitespace, e.g. `at ./src/main.rs:9:5`
+        let start = rest[..idx]
+            .rfind(char::is_whitespace)
+            .map(|idx| idx + 1)
+            .unwrap_or(0);
+        let path_end = idx + ".rs".len();
+
+        let mapped = parse_line_column(&rest[path_end..]).and_then(|((line, column), len)| {
+            let path = Path::new(&rest[start..path_end]);
+            let location = map_location(path, line, column, rust_gen_dir, code)?;
+
+            return Some((location, len));
+        });
+
+        match mapped {
+            Some((location, len)) => {
+                out.push_str(&rest[..start]);
+                out.push_str(&location);
+                rest = &rest[path_end + len..];
+            }
+            None => {
+                out.push_str(&rest[..path_end]);
+                rest = &rest[path_end..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+
+    return out;
+}
+
+/// Parses `:line:column` at the start of `text`, returning them with the length parsed.
+fn parse_line_column(text: &str) -> Option<((usize, usize), usize)> {
+    let mut len = 0;
+    let mut numbers = [0; 2];
+
+    for number in &mut numbers {
+        let rest = text[len..].strip_prefix(':')?;
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+
+        *number = rest[..digits].parse().ok()?;
+        len += 1 + digits;
+    }
+
+    return Some(((numbers[0], numbers[1]), len));
+}
+
+fn map_location(
+    path: &Path,
+    line: usize,
+    column: usize,
+    rust_gen_dir: &Path,
+    code: &RustCode,
+) -> Option<String> {
+    let path = path.strip_prefix(rust_gen_dir).unwrap_or(path);
+    let file = find_generated_file(code, path)?;
+
+    let span = file.source_map.lookup(line, column)?;
+
+    return Some(format!(
+        "{}:{}:{}",
+        file.source_map.source.display(),
+        span.start.line,
+        span.start.column,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::code_gen::{RustCodeFile, RustSourceMap, RustSourceMapping};
+    use crate::token::{Position, Span};
+
+    #[test]
+    fn test_map_runtime_output() {
+        let position = Position {
+            index: 0,
+            line: 4,
+            column: 5,
+        };
+
+        let code = RustCode {
+            files: vec![RustCodeFile {
+                path: "./main.rs".into(),
+                content: "".into(),
+                source_map: RustSourceMap {
+                    source: "./_main.fe".into(),
+                    mappings: vec![RustSourceMapping {
+                        line: 9,
+                        column: 5,
+                        span: Span {
+                            start: position.clone(),
+                            end: position,
+                        },
+                    }],
+                },
+            }],
+        };
+
+        let output = [
+            "thread 'main' panicked at src/main.rs:9:5:",
+            "attempt to add with overflow",
+            "stack backtrace:",
+            "   0: core::panicking::panic",
+            "             at /rustc/library/core/src/panicking.rs:145:5",
+            "   1: compiled_rust::main",
+            "             at /project/.ferrum/compiled_rust/src/main.rs:9:12",
+            "",
+        ]
+        .join("\n");
+
+        let mapped =
+            map_runtime_output(&output, Path::new("/project/.ferrum/compiled_rust"), &code);
+
+        assert_eq!(
+            mapped,
+            [
+                "thread 'main' panicked at ./_main.fe:4:5:",
+                "attempt to add with overflow",
+                "stack backtrace:",
+                "   0: core::panicking::panic",
+                "             at /rustc/library/core/src/panicking.rs:145:5",
+                "   1: compiled_rust::main",
+                "             at ./_main.fe:4:5",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -1,19 +1,117 @@
-use std::{process, sync::Arc};
+mod backtrace;
+mod rustc;
 
-use crate::{config::Config, result::Result};
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+    process,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    code_gen::{RustCode, RustCodeFile},
+    config::Config,
+    diagnostics::{Diagnostic, Diagnostics, Severity},
+    result::Result,
+};
 
 #[derive(Debug, Clone)]
 pub struct RustExecutor {}
 
 impl RustExecutor {
-    pub fn cargo_run(cfg: Arc<Config>) -> Result<process::Output> {
-        let out = process::Command::new("cargo")
+    /// Builds and runs the generated project. Warnings from the build are collected
+    /// into `warnings`, mapped to the Ferrum code like errors are.
+    pub fn cargo_run(
+        cfg: Arc<Config>,
+        code: Arc<Mutex<RustCode>>,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<process::Output> {
+        let code = &*code.lock().unwrap();
+
+        let Some(executable) = Self::cargo_build(&cfg, code, warnings)? else {
+            // Not a compile error (e.g. cargo itself failed), let `cargo run` report it as before
+            return Ok(Self::cargo(&cfg)
+                .args(["run", "--release", "-q"])
+                .stdin(process::Stdio::inherit())
+                .stdout(process::Stdio::piped())
+                .output()?);
+        };
+
+        // Run directly instead of through `cargo run`, so only the program's own
+        // output is rewritten, not the warnings cargo replays from the build
+        let mut out = process::Command::new(executable)
             .current_dir(&cfg.rust_gen_dir)
-            .args(["run", "--release", "-q"])
             .stdin(process::Stdio::inherit())
             .stdout(process::Stdio::piped())
             .output()?;
 
+        // Backtraces print absolute paths to the generated files
+        let rust_gen_dir =
+            fs::canonicalize(&cfg.rust_gen_dir).unwrap_or_else(|_| cfg.rust_gen_dir.clone());
+
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        out.stderr = backtrace::map_runtime_output(&stderr, &rust_gen_dir, code).into_bytes();
+
         return Ok(out);
     }
+
+    /// Builds the generated project first, so any rustc errors can be reported
+    /// on the Ferrum code they came from instead of the generated Rust.
+    ///
+    /// Returns the path to the built program, if cargo reported one.
+    fn cargo_build(
+        cfg: &Config,
+        code: &RustCode,
+        warnings: &mut Vec<Diagnostic>,
+    ) -> Result<Option<PathBuf>> {
+        let out = Self::cargo(cfg)
+            .args(["build", "--release", "-q", "--message-format=json"])
+            .stdin(process::Stdio::null())
+            .output()?;
+
+        let json_lines = String::from_utf8(out.stdout)?;
+
+        let (errors, rustc_warnings): (Vec<Diagnostic>, Vec<Diagnostic>) =
+            rustc::rustc_messages_to_diagnostics(&json_lines, code)
+                .into_iter()
+                .partition(|diagnostic| diagnostic.severity == Severity::Error);
+
+        warnings.extend(rustc_warnings);
+
+        if out.status.success() {
+            return Ok(rustc::built_executable(&json_lines));
+        }
+
+        if errors.is_empty() {
+            return Ok(None);
+        }
+
+        return Err(Diagnostics(errors).into());
+    }
+
+    fn cargo(cfg: &Config) -> process::Command {
+        let mut cmd = process::Command::new("cargo");
+
+        cmd.current_dir(&cfg.rust_gen_dir)
+            // Line numbers in backtraces, so they can be mapped back to the Ferrum source
+            .env("CARGO_PROFILE_RELEASE_DEBUG", "line-tables-only");
+
+        return cmd;
+    }
+}
+
+/// Finds the generated file at `path`, relative to the generated crate's root.
+fn find_generated_file<'a>(code: &'a RustCode, path: &Path) -> Option<&'a RustCodeFile> {
+    let path = path.strip_prefix(".").unwrap_or(path);
+
+    // Generated files are relative to the crate's `src` dir
+    let path = path.strip_prefix("src").ok()?;
+
+    return code.files.iter().find(|file| {
+        return file
+            .path
+            .components()
+            .filter(|component| *component != Component::CurDir)
+            .eq(path.components());
+    });
 }
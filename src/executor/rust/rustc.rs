@@ -0,0 +1,215 @@
+use super::find_generated_file;
+
+use crate::code_gen::{RustCode, RustCodeFile};
+use crate::diagnostics::{Diagnostic, ErrorCode, Severity};
+use crate::token::Span;
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// One line of `cargo build --message-format=json`. Only compiler messages and
+/// the path of the built program are kept.
+#[derive(Debug, Clone, Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<RustcMessage>,
+    executable: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RustcMessage {
+    message: String,
+    code: Option<RustcCode>,
+    level: String,
+    spans: Vec<RustcSpan>,
+    children: Vec<RustcMessage>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RustcCode {
+    code: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RustcSpan {
+    file_name: String,
+    line_start: usize,
+    column_start: usize,
+    is_primary: bool,
+    label: Option<String>,
+}
+
+/// Converts the errors and warnings in cargo's JSON output into diagnostics on
+/// the Ferrum code the Rust they're about was generated from.
+pub(super) fn rustc_messages_to_diagnostics(json_lines: &str, code: &RustCode) -> Vec<Diagnostic> {
+    return json_lines
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CargoMessage>(line).ok())
+        .filter(|msg| msg.reason == "compiler-message")
+        .filter_map(|msg| msg.message)
+        .filter(|msg| matches!(msg.level.as_str(), "error" | "warning") && !is_summary(msg))
+        .map(|msg| to_diagnostic(msg, code))
+        .collect();
+}
+
+/// Whether `msg` only counts the messages before it, like "aborting due to 2 previous errors".
+fn is_summary(msg: &RustcMessage) -> bool {
+    return msg.spans.is_empty()
+        && (msg.message.starts_with("aborting due to")
+            || msg.message.ends_with("warning emitted")
+            || msg.message.ends_with("warnings emitted"));
+}
+
+/// The path of the program built by the generated project, from cargo's JSON output.
+pub(super) fn built_executable(json_lines: &str) -> Option<PathBuf> {
+    return json_lines
+        .lines()
+        .filter_map(|line| serde_json::from_str::<CargoMessage>(line).ok())
+        .filter(|msg| msg.reason == "compiler-artifact")
+        .find_map(|msg| msg.executable);
+}
+
+fn to_diagnostic(msg: RustcMessage, code: &RustCode) -> Diagnostic {
+    let severity = match msg.level.as_str() {
+        "warning" => Severity::Warning,
+        _ => Severity::Error,
+    };
+
+    let mut diagnostic =
+        Diagnostic::new(severity, msg.message.clone()).with_code(ErrorCode::GENERATED_RUST);
+
+    if let Some(rustc_code) = &msg.code {
+        diagnostic = diagnostic.with_note(format!("rustc {}[{}]", msg.level, rustc_code.code));
+    }
+
+    for span in &msg.spans {
+        let generated_at = format!(
+            "generated Rust at {}:{}:{}",
+            span.file_name, span.line_start, span.column_start
+        );
+
+        let Some((file, fe_span)) = map_span(span, code) else {
+            diagnostic = diagnostic.with_note(generated_at);
+            continue;
+        };
+
+        if diagnostic.file.is_none() {
+            diagnostic = diagnostic.with_file(file.source_map.source.clone());
+        }
+
+        // Labels can only point into one file
+        if diagnostic.file.as_ref() != Some(&file.source_map.source) {
+            diagnostic = diagnostic.with_note(generated_at);
+            continue;
+        }
+
+        let label = span.label.clone().unwrap_or_default();
+
+        diagnostic = match (span.is_primary, diagnostic.primary.is_some()) {
+            (true, false) if label.is_empty() => diagnostic.with_span(fe_span.clone()),
+            (true, false) => diagnostic.with_label(fe_span.clone(), label),
+            _ => diagnostic.with_secondary(fe_span.clone(), label),
+        };
+    }
+
+    // Spans inside the same statement all map to its span, so only label each once
+    let mut labeled: Vec<Span> = diagnostic
+        .primary
+        .iter()
+        .map(|label| label.span.clone())
+        .collect();
+    diagnostic.secondary.retain(|label| {
+        if labeled.contains(&label.span) {
+            return false;
+        }
+
+        labeled.push(label.span.clone());
+        return true;
+    });
+
+    for child in &msg.children {
+        diagnostic = match child.level.as_str() {
+            "note" => diagnostic.with_note(child.message.clone()),
+            level => diagnostic.with_note(format!("{level}: {}", child.message)),
+        };
+    }
+
+    return diagnostic;
+}
+
+fn map_span<'a>(span: &RustcSpan, code: &'a RustCode) -> Option<(&'a RustCodeFile, &'a Span)> {
+    let file = find_generated_file(code, Path::new(&span.file_name))?;
+    let fe_span = file.source_map.lookup(span.line_start, span.column_start)?;
+
+    return Some((file, fe_span));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::code_gen::{RustSourceMap, RustSourceMapping};
+
+    #[test]
+    fn test_rustc_messages_to_diagnostics() {
+        let code = RustCode {
+            files: vec![RustCodeFile {
+                path: "./main.rs".into(),
+                content: "".into(),
+                source_map: RustSourceMap {
+                    source: "./_main.fe".into(),
+                    mappings: vec![RustSourceMapping {
+                        line: 4,
+                        column: 5,
+                        span: Span::on_line(0, 2, 5, 14),
+                    }],
+                },
+            }],
+        };
+
+        let json_lines = [
+            r#"{"reason":"compiler-artifact","package_id":"compiled_rust"}"#,
+            r#"{"reason":"compiler-message","message":{"message":"mismatched types","code":{"code":"E0308","explanation":null},"level":"error","spans":[{"file_name":"src/main.rs","line_start":4,"column_start":13,"is_primary":true,"label":"expected `i64`, found `&str`"}],"children":[]}}"#,
+            r#"{"reason":"compiler-message","message":{"message":"value assigned to `x` is never read","code":{"code":"unused_assignments","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","line_start":4,"column_start":9,"is_primary":true,"label":null},{"file_name":"src/main.rs","line_start":4,"column_start":5,"is_primary":false,"label":"first assigned here"}],"children":[]}}"#,
+            r#"{"reason":"compiler-message","message":{"message":"1 warning emitted","code":null,"level":"warning","spans":[],"children":[]}}"#,
+            r#"{"reason":"compiler-message","message":{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"children":[]}}"#,
+            r#"{"reason":"build-finished","success":false}"#,
+        ]
+        .join("\n");
+
+        let diagnostics = rustc_messages_to_diagnostics(&json_lines, &code);
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::error("mismatched types")
+                    .with_code(ErrorCode::GENERATED_RUST)
+                    .with_note("rustc error[E0308]")
+                    .with_file("./_main.fe")
+                    .with_label(Span::on_line(0, 2, 5, 14), "expected `i64`, found `&str`"),
+                Diagnostic::warning("value assigned to `x` is never read")
+                    .with_code(ErrorCode::GENERATED_RUST)
+                    .with_note("rustc warning[unused_assignments]")
+                    .with_file("./_main.fe")
+                    .with_span(Span::on_line(0, 2, 5, 14)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_built_executable() {
+        let json_lines = [
+            r#"{"reason":"compiler-artifact","package_id":"serde","executable":null}"#,
+            r#"{"reason":"compiler-artifact","package_id":"compiled_rust","executable":"/tmp/target/release/compiled_rust"}"#,
+            r#"{"reason":"build-finished","success":true}"#,
+        ]
+        .join("\n");
+
+        assert_eq!(
+            built_executable(&json_lines),
+            Some(PathBuf::from("/tmp/target/release/compiled_rust"))
+        );
+        assert_eq!(built_executable(""), None);
+    }
+}
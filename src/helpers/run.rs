@@ -1,11 +1,17 @@
 use crate::code_gen::RustCodeGen;
-use crate::config::Config;
+use crate::config::{Config, MessageFormat};
+use crate::diagnostics::{
+    apply_suggestions, machine_applicable_fixes, Diagnostic, DiagnosticJsonEmitter,
+    DiagnosticRenderer, Diagnostics,
+};
 use crate::executor::RustExecutor;
 use crate::ir::RustSyntaxCompiler;
 use crate::lexer::FeLexer;
+use crate::lint::FeLinter;
 use crate::parser::FeSyntaxParser;
 use crate::project_gen::RustProjectGen;
 use crate::reader::SourceReader;
+use crate::source::FeSourcePackage;
 use crate::type_resolver::FeTypeResolver;
 
 use crate::log;
@@ -26,10 +32,87 @@ pub fn run_full(cfg: Config) -> Result<process::Output> {
     // let release_build_dir = builds_dir.join("release");
 
     // Read source files
-    let source = Arc::new(Mutex::new(SourceReader::read_src_files(cfg.clone())?));
+    let source = SourceReader::read_src_files(cfg.clone())?;
+
+    let mut warnings = vec![];
+    let out = compile_and_run(cfg.clone(), source.clone(), &mut warnings);
+
+    let mut diagnostics = warnings;
+
+    if let Err(e) = &out {
+        if let Some(Diagnostics(errors)) = Diagnostics::from_error(e) {
+            emit_diagnostics(&cfg, &errors, &source);
+
+            diagnostics.extend(errors);
+        }
+    }
+
+    if cfg.apply_fixes {
+        apply_fixes(cfg.clone(), &diagnostics, &source)?;
+    }
+
+    return out;
+}
+
+/// Rewrites the source files with the machine-applicable suggestions of `diagnostics`.
+fn apply_fixes(cfg: Arc<Config>, diagnostics: &[Diagnostic], source: &FeSourcePackage) -> Result {
+    let reader = SourceReader::new(cfg);
+
+    for (path, suggestions) in machine_applicable_fixes(diagnostics) {
+        let Some(file) = source.find_file(&path) else {
+            continue;
+        };
+
+        let (content, applied) = apply_suggestions(&file.content, &suggestions);
+        if applied == 0 {
+            continue;
+        }
+
+        std::fs::write(reader.disk_path(&path), content)?;
+
+        let problems = if applied == 1 { "problem" } else { "problems" };
+        eprintln!("Fixed {applied} {problems} in {}", path.display());
+    }
+
+    return Ok(());
+}
+
+fn emit_diagnostics(cfg: &Config, diagnostics: &[Diagnostic], source: &FeSourcePackage) {
+    for diagnostic in diagnostics {
+        match cfg.message_format {
+            MessageFormat::Human => eprintln!("{}", render_diagnostic(cfg, diagnostic, source)),
+            MessageFormat::Json => println!("{}", DiagnosticJsonEmitter::new().emit(diagnostic)),
+        }
+    }
+}
+
+pub fn render_diagnostic(
+    cfg: &Config,
+    diagnostic: &Diagnostic,
+    source: &FeSourcePackage,
+) -> String {
+    let file = diagnostic
+        .file
+        .as_ref()
+        .and_then(|path| source.find_file(path));
+
+    return DiagnosticRenderer::new(cfg.color_diagnostics)
+        .render(diagnostic, file.as_ref().map(|file| file.content.as_ref()));
+}
+
+/// Warnings are emitted as soon as they're found, and also collected into `warnings`.
+fn compile_and_run(
+    cfg: Arc<Config>,
+    source: FeSourcePackage,
+    warnings: &mut Vec<Diagnostic>,
+) -> Result<process::Output> {
+    let source = Arc::new(Mutex::new(source));
 
     // Scan to tokens
-    let tokens = Arc::new(Mutex::new(FeLexer::scan_package(cfg.clone(), source)?));
+    let tokens = Arc::new(Mutex::new(FeLexer::scan_package(
+        cfg.clone(),
+        source.clone(),
+    )?));
 
     log::debug!(&tokens);
 
@@ -44,6 +127,12 @@ pub fn run_full(cfg: Config) -> Result<process::Output> {
 
     log::debug!(&typed_pkg);
 
+    // Check for likely mistakes, only denied lints stop compilation
+    let lints = FeLinter::lint_package(cfg.clone(), typed_pkg.clone())?;
+
+    emit_diagnostics(&cfg, &lints, &source.try_lock().unwrap());
+    warnings.extend(lints);
+
     // Compile to Rust IR
     let rust_ir = Arc::new(Mutex::new(RustSyntaxCompiler::compile_package(
         cfg.clone(),
@@ -59,12 +148,16 @@ pub fn run_full(cfg: Config) -> Result<process::Output> {
     log::debug!(&rust_code);
 
     // Write Rust output source files
-    let generated = RustProjectGen::generate_project_files(cfg.clone(), rust_code)?;
+    let generated = RustProjectGen::generate_project_files(cfg.clone(), rust_code.clone())?;
 
     log::debug!(&generated);
 
     // Run generated Rust project
-    let out = RustExecutor::cargo_run(cfg.clone())?;
+    let mut rust_warnings = vec![];
+    let out = RustExecutor::cargo_run(cfg.clone(), rust_code, &mut rust_warnings);
+
+    emit_diagnostics(&cfg, &rust_warnings, &source.try_lock().unwrap());
+    warnings.extend(rust_warnings);
 
-    return Ok(out);
+    return out;
 }
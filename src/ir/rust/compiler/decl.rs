@@ -2,48 +2,59 @@ use super::*;
 
 impl DeclVisitor<FeType, Result> for RustSyntaxCompiler {
     fn visit_function_decl(&mut self, decl: Arc<Mutex<FnDecl<FeType>>>) -> Result {
-        let mut decl = decl.try_lock().unwrap();
+        let fn_ir = self.translate_fn_decl(&mut decl.try_lock().unwrap())?;
 
-        let fn_ir = ir::RustIRFnDecl {
-            macros: vec![],
+        let file_idx = self.out.files.len() - 1;
+        self.out.files[file_idx]
+            .decls
+            .push(ir::RustIRDecl::Fn(fn_ir));
 
+        return Ok(());
+    }
+
+    fn visit_struct_decl(&mut self, decl: Arc<Mutex<StructDecl<FeType>>>) -> Result {
+        let mut decl = decl.try_lock().unwrap();
+
+        let struct_ir = ir::RustIRStructDecl {
+            // Instances can be compared with `==`
+            macros: vec![ir::RustIRMacro::Derive(vec!["PartialEq".into()])],
             decl_mod: decl
                 .decl_mod
                 .as_ref()
                 .map(|decl_mod| self.translate_decl_mod(decl_mod)),
 
-            is_async: false, // TODO
+            name: decl.name.lexeme.clone(),
 
-            generics: None,
+            generics: decl
+                .generics
+                .as_ref()
+                .map(|generics| ir::RustIRStructGenerics {
+                    params: generics.names(),
+                }),
 
-            name: decl.name.lexeme.clone(),
-            params: decl
-                .params
+            fields: decl
+                .fields
                 .iter_mut()
-                .map(|param| self.translate_fn_param(param))
+                .map(|field| self.translate_struct_field(field))
                 .collect(),
 
-            return_type: decl
-                .return_type
-                .as_mut()
-                .map(|return_type| self.translate_fn_return_type(return_type)),
-
-            body: self.translate_fn_body(&mut decl.body)?,
+            span: Some(decl.span()),
         };
 
         let file_idx = self.out.files.len() - 1;
         self.out.files[file_idx]
             .decls
-            .push(ir::RustIRDecl::Fn(fn_ir));
+            .push(ir::RustIRDecl::Struct(struct_ir));
 
         return Ok(());
     }
 
-    fn visit_struct_decl(&mut self, decl: Arc<Mutex<StructDecl<FeType>>>) -> Result {
+    fn visit_enum_decl(&mut self, decl: Arc<Mutex<EnumDecl<FeType>>>) -> Result {
         let mut decl = decl.try_lock().unwrap();
 
-        let struct_ir = ir::RustIRStructDecl {
-            macros: vec![],
+        let enum_ir = ir::RustIREnumDecl {
+            // Values can be compared with `==`
+            macros: vec![ir::RustIRMacro::Derive(vec!["PartialEq".into()])],
             decl_mod: decl
                 .decl_mod
                 .as_ref()
@@ -51,20 +62,132 @@ impl DeclVisitor<FeType, Result> for RustSyntaxCompiler {
 
             name: decl.name.lexeme.clone(),
 
-            generics: None,
-
-            fields: decl
-                .fields
+            variants: decl
+                .variants
                 .iter_mut()
-                .map(|field| self.translate_struct_field(field))
+                .map(|variant| ir::RustIREnumVariant {
+                    name: variant.name.lexeme.clone(),
+                    fields: match &mut variant.fields {
+                        EnumDeclVariantFields::Unit => ir::RustIREnumVariantFields::Unit,
+
+                        EnumDeclVariantFields::Tuple { fields, .. } => {
+                            ir::RustIREnumVariantFields::Tuple(
+                                fields
+                                    .iter_mut()
+                                    .map(|field| {
+                                        self.translate_static_type(&mut field.static_type_ref)
+                                    })
+                                    .collect(),
+                            )
+                        }
+
+                        EnumDeclVariantFields::Struct { fields, .. } => {
+                            ir::RustIREnumVariantFields::Struct(
+                                fields
+                                    .iter_mut()
+                                    .map(|field| self.translate_struct_field(field))
+                                    .collect(),
+                            )
+                        }
+                    },
+                })
                 .collect(),
+
+            span: Some(decl.span()),
         };
 
         let file_idx = self.out.files.len() - 1;
         self.out.files[file_idx]
             .decls
-            .push(ir::RustIRDecl::Struct(struct_ir));
+            .push(ir::RustIRDecl::Enum(enum_ir));
+
+        return Ok(());
+    }
+
+    fn visit_impl_decl(&mut self, decl: Arc<Mutex<ImplDecl<FeType>>>) -> Result {
+        let decl = decl.try_lock().unwrap();
+
+        let generics = match &decl.target.resolved_type {
+            FeType::Struct(target) if !target.generics.is_empty() => Some(ir::RustIRImplGenerics {
+                params: target.generics.clone(),
+            }),
+
+            _ => None,
+        };
+
+        let impl_ir = ir::RustIRImplDecl {
+            generics,
+            name: decl.target.name.lexeme.clone(),
+            methods: decl
+                .methods
+                .iter()
+                .map(|method| self.translate_fn_decl(&mut method.try_lock().unwrap()))
+                .collect::<Result<Vec<ir::RustIRFnDecl>>>()?,
+
+            span: Some(decl.span()),
+        };
+
+        let file_idx = self.out.files.len() - 1;
+        self.out.files[file_idx]
+            .decls
+            .push(ir::RustIRDecl::Impl(impl_ir));
 
         return Ok(());
     }
 }
+
+impl RustSyntaxCompiler {
+    fn translate_fn_decl(&mut self, decl: &mut FnDecl<FeType>) -> Result<ir::RustIRFnDecl> {
+        let return_type = decl
+            .return_type
+            .as_ref()
+            .map(|return_type| return_type.static_type.resolved_type.clone());
+
+        let outer_return_type = std::mem::replace(&mut self.current_return_type, return_type);
+        let body = self.translate_fn_body(&mut decl.body);
+        self.current_return_type = outer_return_type;
+
+        return Ok(ir::RustIRFnDecl {
+            macros: vec![],
+
+            decl_mod: decl
+                .decl_mod
+                .as_ref()
+                .map(|decl_mod| self.translate_decl_mod(decl_mod)),
+
+            is_async: false, // TODO
+
+            generics: decl.generics.as_ref().map(|generics| ir::RustIRFnGenerics {
+                params: generics.names(),
+            }),
+
+            name: decl.name.lexeme.clone(),
+
+            self_param: decl
+                .self_param
+                .as_ref()
+                .map(|self_param| ir::RustIRSelfParam {
+                    ref_type: self_param.ref_type.as_ref().map(|ref_type| match ref_type {
+                        RefType::Shared { .. } => ir::RustIRRefType::Shared,
+                        RefType::Mut { .. } => ir::RustIRRefType::Mut,
+                    }),
+                    is_mut: self_param.mut_token.is_some(),
+                }),
+
+            params: decl
+                .params
+                .iter_mut()
+                .map(|param| self.translate_fn_param(param))
+                .collect(),
+
+            return_type: decl
+                .return_type
+                .as_mut()
+                .map(|return_type| self.translate_fn_return_type(return_type)),
+
+            body: body?,
+
+            span: Some(decl.span()),
+        });
+    }
+}
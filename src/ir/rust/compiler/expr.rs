@@ -12,6 +12,13 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
         }));
     }
 
+    fn visit_none_literal_expr(
+        &mut self,
+        _expr: Arc<Mutex<NoneLiteralExpr<FeType>>>,
+    ) -> Result<ir::RustIRExpr> {
+        return Ok(Self::none_expr());
+    }
+
     fn visit_number_literal_expr(
         &mut self,
         expr: Arc<Mutex<NumberLiteralExpr<FeType>>>,
@@ -155,16 +162,21 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
         }
 
         let callee = expr.callee.0.try_lock().unwrap();
+
+        let params = match callee.resolved_type() {
+            Some(FeType::Callable(callable)) => callable.params,
+            _ => vec![],
+        };
+
         let callee = Box::new(callee.accept(self)?);
 
         let mut args = vec![];
 
         // TODO: Handle named, variadic, optional, etc params
-        for arg in &expr.args {
-            let value = arg.value.0.try_lock().unwrap();
-            let arg_ir = value.accept(self)?;
+        for (idx, arg) in expr.args.iter().enumerate() {
+            let param_type = params.get(idx).map(|(_, typ)| typ);
 
-            args.push(arg_ir);
+            args.push(self.translate_value(&arg.value, param_type)?);
         }
 
         return Ok(ir::RustIRExpr::Call(ir::RustIRCallExpr { callee, args }));
@@ -173,6 +185,12 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
     fn visit_unary_expr(&mut self, expr: Arc<Mutex<UnaryExpr<FeType>>>) -> Result<ir::RustIRExpr> {
         let expr = &mut *expr.try_lock().unwrap();
 
+        if let UnaryOp::Not(_) | UnaryOp::Minus(_) = &expr.op {
+            if let Some(folded) = Self::folded_literal(&expr.resolved_type, &[&expr.value]) {
+                return Ok(folded);
+            }
+        }
+
         match &expr.op {
             UnaryOp::Ref(RefType::Shared { .. }) => {
                 return Ok(ir::RustIRExpr::Unary(ir::RustIRUnaryExpr {
@@ -192,6 +210,12 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
                     value: Box::new(expr.value.0.try_lock().unwrap().accept(self)?),
                 }));
             }
+            UnaryOp::Minus(_) => {
+                return Ok(ir::RustIRExpr::Unary(ir::RustIRUnaryExpr {
+                    op: ir::RustIRUnaryOp::Negate,
+                    value: Box::new(expr.value.0.try_lock().unwrap().accept(self)?),
+                }));
+            }
         }
     }
 
@@ -201,8 +225,27 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
     ) -> Result<ir::RustIRExpr> {
         let expr = &mut *expr.try_lock().unwrap();
 
-        let lhs = Box::new(expr.lhs.0.try_lock().unwrap().accept(self)?);
-        let rhs = Box::new(expr.rhs.0.try_lock().unwrap().accept(self)?);
+        if let Some(folded) = Self::folded_literal(&expr.resolved_type, &[&expr.lhs, &expr.rhs]) {
+            return Ok(folded);
+        }
+
+        let lhs_type = expr.lhs.0.try_lock().unwrap().resolved_type();
+        let rhs_type = expr.rhs.0.try_lock().unwrap().resolved_type();
+
+        // A value compared to an optional is wrapped to match it
+        let (lhs, rhs) = match (&expr.op, &lhs_type, &rhs_type) {
+            (BinaryOp::Equal(_) | BinaryOp::NotEqual(_), Some(lhs_type), Some(rhs_type)) => (
+                self.translate_value(&expr.lhs, Some(rhs_type))?,
+                self.translate_value(&expr.rhs, Some(lhs_type))?,
+            ),
+            _ => (
+                expr.lhs.0.try_lock().unwrap().accept(self)?,
+                expr.rhs.0.try_lock().unwrap().accept(self)?,
+            ),
+        };
+
+        let lhs = Box::new(lhs);
+        let rhs = Box::new(rhs);
 
         match &expr.op {
             BinaryOp::Add(_) => {
@@ -219,6 +262,27 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
                     rhs,
                 }));
             }
+            BinaryOp::Multiply(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::Multiply,
+                    rhs,
+                }));
+            }
+            BinaryOp::Divide(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::Divide,
+                    rhs,
+                }));
+            }
+            BinaryOp::Modulo(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::Modulo,
+                    rhs,
+                }));
+            }
 
             BinaryOp::Less(_) => {
                 return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
@@ -248,6 +312,66 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
                     rhs,
                 }));
             }
+
+            BinaryOp::Equal(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::Eq,
+                    rhs,
+                }));
+            }
+            BinaryOp::NotEqual(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::NotEq,
+                    rhs,
+                }));
+            }
+            BinaryOp::Range(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::Range,
+                    rhs,
+                }));
+            }
+            BinaryOp::RangeInclusive(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::RangeInclusive,
+                    rhs,
+                }));
+            }
+        }
+    }
+
+    fn visit_logical_expr(
+        &mut self,
+        expr: Arc<Mutex<LogicalExpr<FeType>>>,
+    ) -> Result<ir::RustIRExpr> {
+        let expr = &mut *expr.try_lock().unwrap();
+
+        if let Some(folded) = Self::folded_literal(&expr.resolved_type, &[&expr.lhs, &expr.rhs]) {
+            return Ok(folded);
+        }
+
+        let lhs = Box::new(expr.lhs.0.try_lock().unwrap().accept(self)?);
+        let rhs = Box::new(expr.rhs.0.try_lock().unwrap().accept(self)?);
+
+        match &expr.op {
+            LogicalOp::And(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::And,
+                    rhs,
+                }));
+            }
+            LogicalOp::Or(_) => {
+                return Ok(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs,
+                    op: ir::RustIRBinaryOp::Or,
+                    rhs,
+                }));
+            }
         }
     }
 
@@ -280,13 +404,20 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
             }
         };
 
+        let field_types = Self::construct_field_types(&expr);
+
         let mut args = vec![];
         for arg in &mut expr.args {
             match arg {
                 ConstructArg::Field(field) => {
+                    let field_type = field_types
+                        .iter()
+                        .find(|(name, _)| *name == field.name.lexeme)
+                        .map(|(_, typ)| typ);
+
                     args.push(ir::RustIRConstructArg {
                         name: field.name.lexeme.clone(),
-                        value: field.value.0.try_lock().unwrap().accept(self)?,
+                        value: self.translate_value(&field.value, field_type)?,
                     });
                 }
             }
@@ -316,39 +447,25 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
     fn visit_if_expr(&mut self, expr: Arc<Mutex<IfExpr<FeType>>>) -> Result<ir::RustIRExpr> {
         let expr = expr.try_lock().unwrap();
         let node_id = expr.node_id().to_string();
+        let typ = expr.resolved_type.clone();
 
         let condition = Box::new(expr.condition.0.try_lock().unwrap().accept(self)?);
 
         let then = match &expr.then {
             IfExprThen::Ternary(then) => {
-                let expr = then.then_expr.0.try_lock().unwrap().accept(self)?;
+                let expr = self.translate_value(&then.then_expr, typ.as_ref())?;
 
-                let stmt = ir::RustIRStmt::ImplicitReturn(ir::RustIRImplicitReturnStmt { expr });
+                let stmt = ir::RustIRStmt::ImplicitReturn(ir::RustIRImplicitReturnStmt {
+                    expr,
+                    span: None,
+                });
 
                 vec![stmt]
             }
             IfExprThen::Block(then) => {
-                let mut then_stmts = vec![];
-
-                for stmt in &then.block.stmts {
-                    let stmts = stmt.try_lock().unwrap().accept(self)?;
-                    then_stmts.extend(stmts);
-                }
-
-                if then.label.is_some() {
-                    let label = self.map_label(node_id.clone(), &then.label);
+                let label = self.map_label(node_id.clone(), &then.label);
 
-                    vec![ir::RustIRStmt::ImplicitReturn(
-                        ir::RustIRImplicitReturnStmt {
-                            expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
-                                label,
-                                stmts: then_stmts,
-                            }),
-                        },
-                    )]
-                } else {
-                    then_stmts
-                }
+                self.translate_if_expr_block(&then.block.stmts, typ.as_ref(), label)?
             }
         };
 
@@ -358,10 +475,10 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
                 IfExprElseIf::Ternary(else_if) => {
                     let condition = Box::new(else_if.condition.0.try_lock().unwrap().accept(self)?);
 
-                    let expr = else_if.expr.0.try_lock().unwrap().accept(self)?;
+                    let expr = self.translate_value(&else_if.expr, typ.as_ref())?;
 
                     let then = vec![ir::RustIRStmt::ImplicitReturn(
-                        ir::RustIRImplicitReturnStmt { expr },
+                        ir::RustIRImplicitReturnStmt { expr, span: None },
                     )];
 
                     else_ifs.push(ir::RustIRElseIf { condition, then });
@@ -369,27 +486,10 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
                 IfExprElseIf::Block(else_if) => {
                     let condition = Box::new(else_if.condition.0.try_lock().unwrap().accept(self)?);
 
-                    let mut then = vec![];
-
-                    for stmt in &else_if.block.stmts {
-                        let stmts = stmt.try_lock().unwrap().accept(self)?;
-                        then.extend(stmts);
-                    }
-
-                    then = if else_if.label.is_some() {
-                        let label = self.map_label(node_id.clone(), &else_if.label);
+                    let label = self.map_label(node_id.clone(), &else_if.label);
 
-                        vec![ir::RustIRStmt::ImplicitReturn(
-                            ir::RustIRImplicitReturnStmt {
-                                expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
-                                    label,
-                                    stmts: then,
-                                }),
-                            },
-                        )]
-                    } else {
-                        then
-                    };
+                    let then =
+                        self.translate_if_expr_block(&else_if.block.stmts, typ.as_ref(), label)?;
 
                     else_ifs.push(ir::RustIRElseIf { condition, then });
                 }
@@ -399,40 +499,33 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
         let else_ = if let Some(else_) = &expr.else_ {
             match else_ {
                 IfExprElse::Ternary(else_) => {
-                    let expr = else_.else_expr.0.try_lock().unwrap().accept(self)?;
+                    let expr = self.translate_value(&else_.else_expr, typ.as_ref())?;
 
                     let then = vec![ir::RustIRStmt::ImplicitReturn(
-                        ir::RustIRImplicitReturnStmt { expr },
+                        ir::RustIRImplicitReturnStmt { expr, span: None },
                     )];
 
                     Some(ir::RustIRElse { then })
                 }
                 IfExprElse::Block(else_) => {
-                    let mut then = vec![];
-
-                    for stmt in &else_.block.stmts {
-                        let stmts = stmt.try_lock().unwrap().accept(self)?;
-                        then.extend(stmts);
-                    }
+                    let label = self.map_label(node_id, &else_.label);
 
-                    then = if else_.label.is_some() {
-                        let label = self.map_label(node_id, &else_.label);
-
-                        vec![ir::RustIRStmt::ImplicitReturn(
-                            ir::RustIRImplicitReturnStmt {
-                                expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
-                                    label,
-                                    stmts: then,
-                                }),
-                            },
-                        )]
-                    } else {
-                        then
-                    };
+                    let then =
+                        self.translate_if_expr_block(&else_.block.stmts, typ.as_ref(), label)?;
 
                     Some(ir::RustIRElse { then })
                 }
             }
+        } else if typ.is_some() {
+            // Without an else the value is `none` when the condition is false
+            Some(ir::RustIRElse {
+                then: vec![ir::RustIRStmt::ImplicitReturn(
+                    ir::RustIRImplicitReturnStmt {
+                        expr: Self::none_expr(),
+                        span: None,
+                    },
+                )],
+            })
         } else {
             None
         };
@@ -445,17 +538,89 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
         }));
     }
 
-    fn visit_loop_expr(&mut self, expr: Arc<Mutex<LoopExpr<FeType>>>) -> Result<ir::RustIRExpr> {
+    fn visit_match_expr(&mut self, expr: Arc<Mutex<MatchExpr<FeType>>>) -> Result<ir::RustIRExpr> {
         let expr = &mut *expr.try_lock().unwrap();
 
         let label = self.map_label(expr.node_id().to_string(), &expr.label);
+        let typ = expr.resolved_type.clone();
+
+        let value = Box::new(expr.value.0.try_lock().unwrap().accept(self)?);
+
+        let mut arms = vec![];
+        for arm in &mut expr.arms {
+            let mut guards = vec![];
+            let pattern = self.translate_pattern(&mut arm.pattern, &mut guards)?;
+
+            if let Some(guard) = &arm.guard {
+                guards.push(guard.condition.0.try_lock().unwrap().accept(self)?);
+            }
+
+            let guard = guards.into_iter().reduce(|lhs, rhs| {
+                ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs: Box::new(lhs),
+                    op: ir::RustIRBinaryOp::And,
+                    rhs: Box::new(rhs),
+                })
+            });
+
+            let stmts = match &arm.body {
+                MatchArmBody::Ternary(ternary) => {
+                    let expr = self.translate_value(&ternary.then_expr, typ.as_ref())?;
+
+                    vec![ir::RustIRStmt::ImplicitReturn(
+                        ir::RustIRImplicitReturnStmt { expr, span: None },
+                    )]
+                }
+                MatchArmBody::Block(block) => {
+                    let mut stmts = self.translate_stmts(&block.stmts)?;
+
+                    if label.is_some() {
+                        // Leave the loop when the arm finishes without a `then`
+                        let is_terminal = block
+                            .stmts
+                            .last()
+                            .is_some_and(|stmt| stmt.try_lock().unwrap().is_terminal());
+
+                        if !is_terminal {
+                            stmts.push(ir::RustIRStmt::Break(ir::RustIRBreakStmt {
+                                label: label.clone(),
+                                expr: None,
+                                span: None,
+                            }));
+                        }
+
+                        vec![ir::RustIRStmt::ImplicitReturn(
+                            ir::RustIRImplicitReturnStmt {
+                                expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
+                                    label: label.clone(),
+                                    stmts,
+                                }),
+                                span: None,
+                            },
+                        )]
+                    } else {
+                        stmts
+                    }
+                }
+            };
 
-        let mut stmts = vec![];
-        for stmt in &expr.block.stmts {
-            let stmt = stmt.try_lock().unwrap().accept(self)?;
-            stmts.extend(stmt);
+            arms.push(ir::RustIRMatchArm {
+                pattern,
+                guard,
+                stmts,
+            });
         }
 
+        return Ok(ir::RustIRExpr::Match(ir::RustIRMatchExpr { value, arms }));
+    }
+
+    fn visit_loop_expr(&mut self, expr: Arc<Mutex<LoopExpr<FeType>>>) -> Result<ir::RustIRExpr> {
+        let expr = &mut *expr.try_lock().unwrap();
+
+        let label = self.map_label(expr.node_id().to_string(), &expr.label);
+
+        let stmts = self.translate_stmts(&expr.block.stmts)?;
+
         return Ok(ir::RustIRExpr::Loop(ir::RustIRLoopExpr { label, stmts }));
     }
 
@@ -464,6 +629,209 @@ impl ExprVisitor<FeType, Result<ir::RustIRExpr>> for RustSyntaxCompiler {
 
         let _label = self.map_label(expr.node_id().to_string(), &expr.label);
 
-        todo!()
+        return Err(self
+            .error("While expressions are not supported yet", expr.span())
+            .into());
+    }
+
+    fn visit_for_expr(&mut self, expr: Arc<Mutex<ForExpr<FeType>>>) -> Result<ir::RustIRExpr> {
+        let expr = &mut *expr.try_lock().unwrap();
+
+        let label = self.for_expr_label(expr.node_id().to_string());
+
+        let iter = self.translate_for_iter(&expr.iter)?;
+
+        let stmts = self.translate_stmts(&expr.block.stmts)?;
+
+        let else_expr = expr.else_.else_expr.0.try_lock().unwrap().accept(self)?;
+
+        return Ok(ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
+            label: Some(label.clone()),
+            stmts: vec![
+                ir::RustIRStmt::For(ir::RustIRForStmt {
+                    label: None,
+                    name: expr.name.lexeme.clone(),
+                    iter,
+                    stmts,
+                    span: None,
+                }),
+                ir::RustIRStmt::Break(ir::RustIRBreakStmt {
+                    label: Some(label),
+                    expr: Some(else_expr),
+                    span: None,
+                }),
+            ],
+        }));
+    }
+}
+
+impl RustSyntaxCompiler {
+    /// The names and types of the fields a struct literal like `Point { x: 1, y: 2 }` sets.
+    fn construct_field_types(expr: &ConstructExpr<FeType>) -> Vec<(Arc<str>, FeType)> {
+        match expr.resolved_type.actual_type() {
+            FeType::Instance(instance) => {
+                return instance
+                    .fields
+                    .values()
+                    .map(|field| (field.name.clone(), field.typ.clone()))
+                    .collect();
+            }
+
+            FeType::EnumInstance(fe_enum) => {
+                let ConstructTarget::StaticPath(path) = &expr.target else {
+                    return vec![];
+                };
+
+                let variant = fe_enum
+                    .variants
+                    .iter()
+                    .find(|variant| variant.name == path.name.lexeme);
+
+                let Some(FeEnumVariantFields::Struct(fields)) = variant.map(|v| &v.fields) else {
+                    return vec![];
+                };
+
+                return fields
+                    .iter()
+                    .map(|field| (field.name.clone(), field.typ.clone()))
+                    .collect();
+            }
+
+            _ => return vec![],
+        }
+    }
+
+    /// The statements of an `if` branch block, which give `none` when the `if`'s
+    /// value is optional and the block finishes without one.
+    fn translate_if_expr_block(
+        &mut self,
+        stmts: &[Arc<Mutex<Stmt<FeType>>>],
+        typ: Option<&FeType>,
+        label: Option<Arc<str>>,
+    ) -> Result<Vec<ir::RustIRStmt>> {
+        let mut stmts_ir = self.translate_stmts(stmts)?;
+
+        let is_terminal = stmts
+            .last()
+            .is_some_and(|stmt| stmt.try_lock().unwrap().is_terminal());
+
+        if !is_terminal && matches!(typ, Some(FeType::Optional(_) | FeType::EmptyOptional)) {
+            match &label {
+                Some(_) => stmts_ir.push(ir::RustIRStmt::Break(ir::RustIRBreakStmt {
+                    label: label.clone(),
+                    expr: Some(Self::none_expr()),
+                    span: None,
+                })),
+                None => stmts_ir.push(ir::RustIRStmt::ImplicitReturn(
+                    ir::RustIRImplicitReturnStmt {
+                        expr: Self::none_expr(),
+                        span: None,
+                    },
+                )),
+            }
+        }
+
+        if label.is_none() {
+            return Ok(stmts_ir);
+        }
+
+        return Ok(vec![ir::RustIRStmt::ImplicitReturn(
+            ir::RustIRImplicitReturnStmt {
+                expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
+                    label,
+                    stmts: stmts_ir,
+                }),
+                span: None,
+            },
+        )]);
+    }
+
+    /// Rust can't match a `String` against a string literal, or a float against a
+    /// decimal one, so those are bound to a name and compared in the arm's guard.
+    fn translate_pattern(
+        &mut self,
+        pattern: &mut Pattern<FeType>,
+        guards: &mut Vec<ir::RustIRExpr>,
+    ) -> Result<ir::RustIRPattern> {
+        match pattern {
+            Pattern::Wildcard(_) => return Ok(ir::RustIRPattern::Wildcard),
+
+            Pattern::Binding(binding) => {
+                return Ok(ir::RustIRPattern::Binding(binding.name.lexeme.clone()));
+            }
+
+            Pattern::Literal(literal) => {
+                let value = literal.value.0.try_lock().unwrap();
+
+                let compared = match &*value {
+                    Expr::PlainStringLiteral(string) => {
+                        Some(ir::RustIRExpr::StringLiteral(ir::RustIRStringLiteralExpr {
+                            literal: string.try_lock().unwrap().literal.lexeme.clone(),
+                        }))
+                    }
+
+                    _ => match value.resolved_type().as_ref().map(FeType::actual_type) {
+                        Some(FeType::Number(Some(NumberDetails::Decimal(..)))) => {
+                            Some(value.accept(self)?)
+                        }
+                        _ => None,
+                    },
+                };
+
+                let Some(compared) = compared else {
+                    return Ok(ir::RustIRPattern::Literal(value.accept(self)?));
+                };
+
+                let name: Arc<str> = format!("__fe_literal_{}", guards.len()).into();
+
+                guards.push(ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+                    lhs: Box::new(ir::RustIRExpr::Ident(ir::RustIRIdentExpr {
+                        ident: name.clone(),
+                    })),
+                    op: ir::RustIRBinaryOp::Eq,
+                    rhs: Box::new(compared),
+                }));
+
+                return Ok(ir::RustIRPattern::Binding(name));
+            }
+
+            Pattern::Struct(pattern) => {
+                let path = Self::translate_static_path(&mut pattern.path);
+
+                let mut fields = vec![];
+                for field in &mut pattern.fields {
+                    fields.push(ir::RustIRStructPatternField {
+                        name: field.name.lexeme.clone(),
+                        pattern: self.translate_pattern(&mut field.pattern, guards)?,
+                    });
+                }
+
+                return Ok(ir::RustIRPattern::Struct(ir::RustIRStructPattern {
+                    path,
+                    fields,
+                    has_rest: pattern.rest_token.is_some(),
+                }));
+            }
+
+            Pattern::Variant(pattern) => {
+                let path = Self::translate_static_path(&mut pattern.path);
+
+                let Some(fields) = &mut pattern.fields else {
+                    return Ok(ir::RustIRPattern::Path(path));
+                };
+
+                let mut translated = vec![];
+                for field in &mut fields.fields {
+                    translated.push(self.translate_pattern(&mut field.pattern, guards)?);
+                }
+
+                return Ok(ir::RustIRPattern::TupleStruct(
+                    ir::RustIRTupleStructPattern {
+                        path,
+                        fields: translated,
+                    },
+                ));
+            }
+        }
     }
 }
@@ -6,15 +6,17 @@ mod r#use;
 use super::*;
 
 use crate::config::Config;
+use crate::diagnostics::{Diagnostic, ErrorCode};
 use crate::r#type::*;
 use crate::syntax::*;
 
 use crate::ir;
-use crate::token::Token;
+use crate::token::{Span, Token};
 use crate::utils::invert;
 
 use crate::result::Result;
 
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 pub struct RustSyntaxCompiler {
@@ -23,6 +25,12 @@ pub struct RustSyntaxCompiler {
 
     entry: Arc<Mutex<FeSyntaxPackage<FeType>>>,
     out: ir::RustIR,
+
+    current_file: PathBuf,
+
+    /// What the function being compiled returns, so returned values can be
+    /// wrapped to match it
+    current_return_type: Option<FeType>,
 }
 
 impl SyntaxCompiler<ir::RustIR> for RustSyntaxCompiler {
@@ -40,6 +48,9 @@ impl RustSyntaxCompiler {
             cfg,
             entry,
             out: ir::RustIR { files: vec![] },
+
+            current_file: PathBuf::new(),
+            current_return_type: None,
         };
     }
 
@@ -68,6 +79,7 @@ impl RustSyntaxCompiler {
                         if is_main { "main" } else { &file.name.0 }
                     )
                     .into(),
+                    source: file.path.clone(),
                     mods: vec![],
                     uses: vec![],
                     decls: vec![],
@@ -90,6 +102,7 @@ impl RustSyntaxCompiler {
                 self.out.files.push(ir::RustIRFile {
                     path: format!("{}{}.rs", parent_dir, if is_main { "main" } else { "mod" })
                         .into(),
+                    source: dir.entry_file.path.clone(),
                     mods: vec![],
                     uses: vec![],
                     decls: vec![],
@@ -111,6 +124,8 @@ impl RustSyntaxCompiler {
     }
 
     fn compile_file(&mut self, file: &mut FeSyntaxFile<FeType>) -> Result {
+        self.current_file = file.path.clone();
+
         let mut syntax = file.syntax.try_lock().unwrap();
 
         {
@@ -161,19 +176,55 @@ impl RustSyntaxCompiler {
         let mut block_ir = ir::RustIRBlockExpr { stmts: vec![] };
 
         match body {
-            FnDeclBody::Short(_short) => todo!(),
+            FnDeclBody::Short(_short) => {
+                return Err(
+                    Diagnostic::error("Short function bodies are not supported yet")
+                        .with_code(ErrorCode::UNSUPPORTED)
+                        .with_file(self.current_file.clone())
+                        .into(),
+                );
+            }
             FnDeclBody::Block(block) => {
-                for stmt in &mut block.stmts {
-                    let stmt_ir = stmt.try_lock().unwrap().accept(self)?;
-
-                    block_ir.stmts.extend(stmt_ir);
-                }
+                block_ir.stmts = self.translate_stmts(&block.stmts)?;
             }
         }
 
         return Ok(block_ir);
     }
 
+    /// Compiles a statement, tagging the generated statements with its span so
+    /// code generation can map them back to the Ferrum source.
+    fn translate_stmt(&mut self, stmt: &Arc<Mutex<Stmt<FeType>>>) -> Result<Vec<ir::RustIRStmt>> {
+        let stmt = &mut *stmt.try_lock().unwrap();
+
+        let mut stmts_ir = stmt.accept(self)?;
+
+        for stmt_ir in &mut stmts_ir {
+            stmt_ir.span_mut().get_or_insert_with(|| stmt.span());
+        }
+
+        return Ok(stmts_ir);
+    }
+
+    /// Compiles a block's statements, leaving out any after a terminal one since
+    /// they can never run.
+    fn translate_stmts(
+        &mut self,
+        stmts: &[Arc<Mutex<Stmt<FeType>>>],
+    ) -> Result<Vec<ir::RustIRStmt>> {
+        let mut stmts_ir = vec![];
+
+        for stmt in stmts {
+            stmts_ir.extend(self.translate_stmt(stmt)?);
+
+            if stmt.try_lock().unwrap().is_terminal() {
+                break;
+            }
+        }
+
+        return Ok(stmts_ir);
+    }
+
     fn translate_struct_field(&self, field: &mut StructDeclField<FeType>) -> ir::RustIRStructField {
         return ir::RustIRStructField {
             field_mod: field.field_mod.as_ref().map(|field| match field {
@@ -191,21 +242,105 @@ impl RustSyntaxCompiler {
             RefType::Mut { .. } => ir::RustIRRefType::Mut,
         });
 
-        return ir::RustIRStaticType {
-            ref_type,
+        let of = ir::RustIRStaticType {
+            ref_type: None,
             static_path: Self::translate_static_path(&mut typ.static_path),
+            generic_args: typ
+                .generic_args
+                .iter_mut()
+                .flat_map(|generic_args| &mut generic_args.args)
+                .map(|arg| self.translate_static_type(&mut arg.static_type))
+                .collect(),
         };
+
+        // `&Int?` is a reference to an `Option<i64>`
+        if typ.question_token.is_some() {
+            return ir::RustIRStaticType {
+                ref_type,
+                static_path: ir::RustIRStaticPath {
+                    root: None,
+                    name: "Option".into(),
+                },
+                generic_args: vec![of],
+            };
+        }
+
+        return ir::RustIRStaticType { ref_type, ..of };
+    }
+
+    /// Compiles `value` for a place that holds a `to`, wrapping it in a `Some`
+    /// when it's implicitly cast to an optional.
+    fn translate_value(
+        &mut self,
+        value: &NestedExpr<FeType>,
+        to: Option<&FeType>,
+    ) -> Result<ir::RustIRExpr> {
+        let value = value.0.try_lock().unwrap();
+
+        let expr = value.accept(self)?;
+
+        let (Some(from), Some(to)) = (value.resolved_type(), to) else {
+            return Ok(expr);
+        };
+
+        return Ok(Self::wrap_optional(expr, &from, to));
+    }
+
+    /// The range a `for` loop goes over, sized like the name it binds each value to.
+    fn translate_for_iter(&mut self, iter: &NestedExpr<FeType>) -> Result<ir::RustIRExpr> {
+        let iter = iter.0.try_lock().unwrap();
+
+        let mut expr = iter.accept(self)?;
+
+        if let Some(typ) = iter.resolved_type() {
+            Self::suffix_range_start(&mut expr, &typ, &typ.clone().with_default_number_size());
+        }
+
+        return Ok(expr);
+    }
+
+    /// `expr` wrapped in a `Some`, if its type `from` is only an optional `to` implicitly.
+    fn wrap_optional(expr: ir::RustIRExpr, from: &FeType, to: &FeType) -> ir::RustIRExpr {
+        match (from.actual_type(), to.actual_type()) {
+            (FeType::Optional(_) | FeType::EmptyOptional | FeType::Error, _) => return expr,
+
+            (_, FeType::Optional(_)) => {
+                return ir::RustIRExpr::Call(ir::RustIRCallExpr {
+                    callee: Box::new(ir::RustIRExpr::Ident(ir::RustIRIdentExpr {
+                        ident: "Some".into(),
+                    })),
+                    args: vec![expr],
+                });
+            }
+
+            _ => return expr,
+        }
+    }
+
+    fn none_expr() -> ir::RustIRExpr {
+        return ir::RustIRExpr::Ident(ir::RustIRIdentExpr {
+            ident: "None".into(),
+        });
     }
 
     fn translate_static_path(path: &mut StaticPath<FeType>) -> ir::RustIRStaticPath {
-        if path.root.is_none()
-            && path.name.lexeme.as_ref() == INT_TYPE_NAME
-            && matches!(path.resolved_type, FeType::Number(_))
-        {
-            return ir::RustIRStaticPath {
-                root: None,
-                name: "i64".into(),
+        if path.root.is_none() {
+            let number_type = match &path.resolved_type {
+                FeType::Number(Some(NumberDetails::Integer(Some(size), _))) => {
+                    Some(Self::integer_type_name(size))
+                }
+                FeType::Number(Some(NumberDetails::Decimal(Some(size), _))) => {
+                    Some(Self::decimal_type_name(size))
+                }
+                _ => None,
             };
+
+            if let Some(name) = number_type {
+                return ir::RustIRStaticPath {
+                    root: None,
+                    name: name.into(),
+                };
+            }
         }
 
         if path.root.is_none()
@@ -227,6 +362,181 @@ impl RustSyntaxCompiler {
         };
     }
 
+    /// The literal an operation folds to, when its value and the value of each of
+    /// its operands are known at compile time.
+    ///
+    /// Operands without a known value are kept, since they could have side effects,
+    /// like the call in `check() and false`.
+    fn folded_literal(typ: &FeType, operands: &[&NestedExpr<FeType>]) -> Option<ir::RustIRExpr> {
+        let operands_known = operands.iter().all(|operand| {
+            let operand = operand.0.try_lock().unwrap();
+
+            return operand
+                .resolved_type()
+                .is_some_and(|typ| Self::known_literal(&typ).is_some());
+        });
+
+        if !operands_known {
+            return None;
+        }
+
+        return Self::known_literal(typ);
+    }
+
+    /// An integer known at compile time, written as a decimal when that's what `typ`
+    /// is. Rust doesn't turn integer literals into decimals by itself.
+    fn decimal_literal(typ: &FeType, value: &NestedExpr<FeType>) -> Option<ir::RustIRExpr> {
+        let FeType::Number(Some(NumberDetails::Decimal(..))) = typ.actual_type() else {
+            return None;
+        };
+
+        let value_type = value.0.try_lock().unwrap().resolved_type()?;
+        let FeType::Number(Some(NumberDetails::Integer(None, Some(value)))) =
+            value_type.actual_type()
+        else {
+            return None;
+        };
+
+        return Self::known_literal(&FeType::Number(Some(NumberDetails::Decimal(
+            None,
+            Some(*value as f64),
+        ))));
+    }
+
+    fn known_literal(typ: &FeType) -> Option<ir::RustIRExpr> {
+        match typ.actual_type() {
+            FeType::Bool(Some(value)) => {
+                return Some(ir::RustIRExpr::BoolLiteral(ir::RustIRBoolLiteralExpr {
+                    literal: *value,
+                }));
+            }
+            // Suffixed with its size, or Rust would infer an `i32` for it
+            FeType::Number(Some(NumberDetails::Integer(size, Some(value)))) => {
+                let suffix = size.as_ref().map_or("", Self::integer_type_name);
+
+                return Some(ir::RustIRExpr::NumberLiteral(ir::RustIRNumberLiteralExpr {
+                    literal: format!("{value}{suffix}").into(),
+                }));
+            }
+            // Debug formatting keeps the `.0` of whole numbers, so they stay decimals
+            FeType::Number(Some(NumberDetails::Decimal(size, Some(value)))) => {
+                let literal = match size {
+                    Some(DecimalSize::F32) => format!("{:?}f32", *value as f32),
+                    Some(DecimalSize::F64) => format!("{value:?}f64"),
+                    None => format!("{value:?}"),
+                };
+
+                return Some(ir::RustIRExpr::NumberLiteral(ir::RustIRNumberLiteralExpr {
+                    literal: literal.into(),
+                }));
+            }
+            _ => return None,
+        }
+    }
+
+    /// The type to declare a variable without an explicit type with, when its value
+    /// is a number literal that took the default size of `binding_type`. Rust would
+    /// infer an `i32` for it instead.
+    fn default_number_type(
+        value_type: &FeType,
+        binding_type: &FeType,
+    ) -> Option<ir::RustIRStaticType> {
+        if let (FeType::Optional(value_of), FeType::Optional(binding_of)) =
+            (value_type.actual_type(), binding_type.actual_type())
+        {
+            return Some(ir::RustIRStaticType {
+                ref_type: None,
+                static_path: ir::RustIRStaticPath {
+                    root: None,
+                    name: "Option".into(),
+                },
+                generic_args: vec![Self::default_number_type(value_of, binding_of)?],
+            });
+        }
+
+        let name = Self::default_number_suffix(value_type, binding_type)?;
+
+        return Some(ir::RustIRStaticType {
+            ref_type: None,
+            static_path: ir::RustIRStaticPath {
+                root: None,
+                name: name.into(),
+            },
+            generic_args: vec![],
+        });
+    }
+
+    /// Gives a range of number literals the size they took by default in
+    /// `binding_type`, with a suffix on its start like `0i64..10`.
+    fn suffix_range_start(expr: &mut ir::RustIRExpr, value_type: &FeType, binding_type: &FeType) {
+        let (FeType::Range(value_of), FeType::Range(binding_of)) =
+            (value_type.actual_type(), binding_type.actual_type())
+        else {
+            return;
+        };
+
+        let Some(suffix) = Self::default_number_suffix(value_of, binding_of) else {
+            return;
+        };
+
+        let ir::RustIRExpr::Binary(ir::RustIRBinaryExpr {
+            lhs,
+            op: ir::RustIRBinaryOp::Range | ir::RustIRBinaryOp::RangeInclusive,
+            ..
+        }) = expr
+        else {
+            return;
+        };
+
+        let mut start = &mut **lhs;
+
+        loop {
+            match start {
+                ir::RustIRExpr::NumberLiteral(literal) => {
+                    literal.literal = format!("{}{suffix}", literal.literal).into();
+                    return;
+                }
+                ir::RustIRExpr::Unary(unary) => start = &mut unary.value,
+                ir::RustIRExpr::Binary(binary) => start = &mut binary.lhs,
+                _ => return,
+            }
+        }
+    }
+
+    fn default_number_suffix(value_type: &FeType, binding_type: &FeType) -> Option<&'static str> {
+        match (value_type.actual_type(), binding_type.actual_type()) {
+            (
+                FeType::Number(Some(NumberDetails::Integer(None, _))),
+                FeType::Number(Some(NumberDetails::Integer(Some(size), _))),
+            ) => return Some(Self::integer_type_name(size)),
+            (
+                FeType::Number(Some(NumberDetails::Decimal(None, _))),
+                FeType::Number(Some(NumberDetails::Decimal(Some(size), _))),
+            ) => return Some(Self::decimal_type_name(size)),
+            _ => return None,
+        }
+    }
+
+    fn integer_type_name(size: &IntegerSize) -> &'static str {
+        match size {
+            IntegerSize::I8 => return "i8",
+            IntegerSize::I16 => return "i16",
+            IntegerSize::I32 => return "i32",
+            IntegerSize::I64 => return "i64",
+            IntegerSize::U8 => return "u8",
+            IntegerSize::U16 => return "u16",
+            IntegerSize::U32 => return "u32",
+            IntegerSize::U64 => return "u64",
+        }
+    }
+
+    fn decimal_type_name(size: &DecimalSize) -> &'static str {
+        match size {
+            DecimalSize::F32 => return "f32",
+            DecimalSize::F64 => return "f64",
+        }
+    }
+
     fn translate_use_mod(&self, use_mod: &UseMod) -> ir::RustIRUseMod {
         match use_mod {
             UseMod::Pub(_) => ir::RustIRUseMod::Pub,
@@ -234,6 +544,7 @@ impl RustSyntaxCompiler {
     }
 
     fn translate_use_static_path(
+        &self,
         path: &mut UseStaticPath<FeType>,
     ) -> Result<Option<ir::RustIRUseStaticPath>> {
         match &path.pre {
@@ -245,13 +556,15 @@ impl RustSyntaxCompiler {
         }
 
         fn _translate_use_static_path(
+            compiler: &RustSyntaxCompiler,
             path: &mut UseStaticPath<FeType>,
         ) -> Result<Option<ir::RustIRUseStaticPath>> {
             let next = match &mut path.details {
                 Either::B(_) => None,
 
                 Either::A(UseStaticPathNext::Single(ref mut single)) => {
-                    let Some(next_path) = _translate_use_static_path(&mut single.path)? else {
+                    let Some(next_path) = _translate_use_static_path(compiler, &mut single.path)?
+                    else {
                         return Ok(None);
                     };
 
@@ -262,7 +575,14 @@ impl RustSyntaxCompiler {
                     ))
                 }
 
-                Either::A(UseStaticPathNext::Many(_many)) => todo!(),
+                Either::A(UseStaticPathNext::Many(many)) => {
+                    return Err(compiler
+                        .error(
+                            "Importing multiple paths at once is not supported yet",
+                            many.open_brace.span.to(&many.close_brace.span),
+                        )
+                        .into());
+                }
             };
 
             let path_ir = ir::RustIRUseStaticPath {
@@ -278,7 +598,14 @@ impl RustSyntaxCompiler {
             return Ok(Some(path_ir));
         }
 
-        return _translate_use_static_path(path);
+        return _translate_use_static_path(self, path);
+    }
+
+    fn error(&self, message: impl Into<Arc<str>>, span: Span) -> Diagnostic {
+        return Diagnostic::error(message)
+            .with_code(ErrorCode::UNSUPPORTED)
+            .with_file(self.current_file.clone())
+            .with_span(span);
     }
 
     fn map_label(&self, id: String, label: &Option<Arc<Token>>) -> Option<Arc<str>> {
@@ -286,4 +613,10 @@ impl RustSyntaxCompiler {
             .as_ref()
             .map(|l| format!("'label_{}_{}", id, &l.lexeme[1..]).into());
     }
+
+    /// Rust can't break out of a `for` with a value, so `for` expressions are wrapped
+    /// in a `loop` with this label, and every break out of them targets it instead.
+    fn for_expr_label(&self, id: String) -> Arc<str> {
+        return format!("'label_{id}_for").into();
+    }
 }
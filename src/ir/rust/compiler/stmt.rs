@@ -9,7 +9,10 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
 
         let expr = stmt.expr.try_lock().unwrap().accept(self)?;
 
-        return Ok(vec![ir::RustIRStmt::Expr(ir::RustIRExprStmt { expr })]);
+        return Ok(vec![ir::RustIRStmt::Expr(ir::RustIRExprStmt {
+            expr,
+            span: None,
+        })]);
     }
 
     fn visit_var_decl_stmt(
@@ -18,8 +21,24 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
     ) -> Result<Vec<ir::RustIRStmt>> {
         let mut stmt = stmt.try_lock().unwrap();
 
-        let value = invert(stmt.value.as_mut().map(|value| {
-            let value = value.value.0.try_lock().unwrap().accept(self);
+        let decimal_literal = match (&stmt.explicit_type, &stmt.value) {
+            (Some(explicit_type), Some(value)) => {
+                Self::decimal_literal(&explicit_type.static_type.resolved_type, &value.value)
+            }
+            _ => None,
+        };
+
+        let explicit_resolved_type = stmt
+            .explicit_type
+            .as_ref()
+            .map(|explicit_type| explicit_type.static_type.resolved_type.clone());
+
+        let mut value = invert(stmt.value.as_mut().map(|value| {
+            if let Some(expr) = decimal_literal {
+                return Ok(ir::RustIRLetValue { expr });
+            }
+
+            let value = self.translate_value(&value.value, explicit_resolved_type.as_ref());
 
             // '?' doesn't work here without explicit type annotation
             // I guess rustc can't guarantee Result::Error here without explicit return Err(...)
@@ -31,6 +50,28 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
             Ok(ir::RustIRLetValue { expr })
         }))?;
 
+        let mut explicit_type = stmt.explicit_type.as_mut().map(|explicit_type| {
+            return ir::RustIRLetExplicitType {
+                static_type: self.translate_static_type(&mut explicit_type.static_type),
+            };
+        });
+
+        if let (None, Some(value), Some(value_type)) = (
+            &explicit_type,
+            &mut value,
+            stmt.value
+                .as_ref()
+                .and_then(|value| value.value.0.try_lock().unwrap().resolved_type()),
+        ) {
+            let VarDeclTarget::Ident(ident) = &stmt.target;
+            let binding_type = ident.try_lock().unwrap().resolved_type.clone();
+
+            explicit_type = Self::default_number_type(&value_type, &binding_type)
+                .map(|static_type| ir::RustIRLetExplicitType { static_type });
+
+            Self::suffix_range_start(&mut value.expr, &value_type, &binding_type);
+        }
+
         match &stmt.target {
             VarDeclTarget::Ident(ident) => {
                 return Ok(vec![ir::RustIRStmt::Let(ir::RustIRLetStmt {
@@ -39,8 +80,9 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
                         VarDeclMut::Mut(_) => true,
                     },
                     name: ident.try_lock().unwrap().ident.lexeme.clone(),
-                    explicit_type: None,
+                    explicit_type,
                     value,
+                    span: None,
                 })])
             }
         }
@@ -52,13 +94,20 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
     ) -> Result<Vec<ir::RustIRStmt>> {
         let stmt = &mut *stmt.try_lock().unwrap();
 
-        let lhs = stmt.target.0.try_lock().unwrap().accept(self)?;
-        let rhs = stmt.value.0.try_lock().unwrap().accept(self)?;
+        let (lhs, target_type) = {
+            let target = stmt.target.0.try_lock().unwrap();
+            (target.accept(self)?, target.resolved_type())
+        };
+
+        let rhs = self.translate_value(&stmt.value, target_type.as_ref())?;
 
         let op = match &stmt.op {
             AssignOp::Eq(_) => ir::RustIRAssignOp::Eq,
             AssignOp::PlusEq(_) => ir::RustIRAssignOp::PlusEq,
             AssignOp::MinusEq(_) => ir::RustIRAssignOp::MinusEq,
+            AssignOp::MultiplyEq(_) => ir::RustIRAssignOp::MultiplyEq,
+            AssignOp::DivideEq(_) => ir::RustIRAssignOp::DivideEq,
+            AssignOp::ModuloEq(_) => ir::RustIRAssignOp::ModuloEq,
         };
 
         return Ok(vec![ir::RustIRStmt::Expr(ir::RustIRExprStmt {
@@ -67,6 +116,7 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
                 op,
                 rhs: Box::new(rhs),
             }),
+            span: None,
         })]);
     }
 
@@ -76,13 +126,18 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
     ) -> Result<Vec<ir::RustIRStmt>> {
         let mut stmt = stmt.try_lock().unwrap();
 
+        let return_type = self.current_return_type.clone();
+
         let expr = if let Some(value) = &mut stmt.value {
-            Some(value.0.try_lock().unwrap().accept(self)?)
+            Some(self.translate_value(value, return_type.as_ref())?)
         } else {
             None
         };
 
-        return Ok(vec![ir::RustIRStmt::Return(ir::RustIRReturnStmt { expr })]);
+        return Ok(vec![ir::RustIRStmt::Return(ir::RustIRReturnStmt {
+            expr,
+            span: None,
+        })]);
     }
 
     fn visit_if_stmt(&mut self, stmt: Arc<Mutex<IfStmt<FeType>>>) -> Result<Vec<ir::RustIRStmt>> {
@@ -90,23 +145,13 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
 
         let condition = Box::new(stmt.condition.0.try_lock().unwrap().accept(self)?);
 
-        let mut then = vec![];
-        for stmt in &mut stmt.then.stmts {
-            let stmts = stmt.try_lock().unwrap().accept(self)?;
-
-            then.extend(stmts);
-        }
+        let then = self.translate_stmts(&stmt.then.stmts)?;
 
         let mut else_ifs = vec![];
         for else_if in &mut stmt.else_ifs {
             let condition = Box::new(else_if.condition.0.try_lock().unwrap().accept(self)?);
 
-            let mut then = vec![];
-            for stmt in &mut else_if.then.stmts {
-                let stmts = stmt.try_lock().unwrap().accept(self)?;
-
-                then.extend(stmts);
-            }
+            let then = self.translate_stmts(&else_if.then.stmts)?;
 
             let else_if = ir::RustIRElseIf { condition, then };
 
@@ -114,12 +159,7 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
         }
 
         let else_ = if let Some(else_) = &mut stmt.else_ {
-            let mut then = vec![];
-            for stmt in &mut else_.then.stmts {
-                let stmts = stmt.try_lock().unwrap().accept(self)?;
-
-                then.extend(stmts);
-            }
+            let then = self.translate_stmts(&else_.then.stmts)?;
 
             Some(ir::RustIRElse { then })
         } else {
@@ -134,7 +174,7 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
         });
 
         return Ok(vec![ir::RustIRStmt::ImplicitReturn(
-            ir::RustIRImplicitReturnStmt { expr },
+            ir::RustIRImplicitReturnStmt { expr, span: None },
         )]);
     }
 
@@ -142,19 +182,16 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
         &mut self,
         stmt: Arc<Mutex<LoopStmt<FeType>>>,
     ) -> Result<Vec<ir::RustIRStmt>> {
-        let mut stmt = stmt.try_lock().unwrap();
+        let stmt = stmt.try_lock().unwrap();
 
         let label = self.map_label(stmt.node_id().to_string(), &stmt.label);
 
-        let mut stmts = vec![];
-        for stmt in &mut stmt.block.stmts {
-            let ir_stmts = stmt.try_lock().unwrap().accept(self)?;
-            stmts.extend(ir_stmts);
-        }
+        let stmts = self.translate_stmts(&stmt.block.stmts)?;
 
         return Ok(vec![ir::RustIRStmt::ImplicitReturn(
             ir::RustIRImplicitReturnStmt {
                 expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr { label, stmts }),
+                span: None,
             },
         )]);
     }
@@ -163,28 +200,44 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
         &mut self,
         stmt: Arc<Mutex<WhileStmt<FeType>>>,
     ) -> Result<Vec<ir::RustIRStmt>> {
-        let mut stmt = stmt.try_lock().unwrap();
+        let stmt = stmt.try_lock().unwrap();
 
         let label = self.map_label(stmt.node_id().to_string(), &stmt.label);
 
-        let condition = stmt.condition.0.try_lock().unwrap().accept(self)?;
+        let stmts = self.translate_stmts(&stmt.block.stmts)?;
 
-        let mut stmts = vec![];
-        for stmt in &mut stmt.block.stmts {
-            let ir_stmts = stmt.try_lock().unwrap().accept(self)?;
-            stmts.extend(ir_stmts);
+        // rustc warns about `while true`, which constant conditions are folded to
+        let resolved_type = stmt.condition.0.try_lock().unwrap().resolved_type();
+        if let Some(FeType::Bool(Some(true))) = resolved_type.as_ref().map(FeType::actual_type) {
+            return Ok(vec![ir::RustIRStmt::ImplicitReturn(
+                ir::RustIRImplicitReturnStmt {
+                    expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr { label, stmts }),
+                    span: None,
+                },
+            )]);
         }
 
+        let condition = stmt.condition.0.try_lock().unwrap().accept(self)?;
+
         if stmt.label.is_some() {
             return Ok(vec![ir::RustIRStmt::ImplicitReturn(
                 ir::RustIRImplicitReturnStmt {
                     expr: ir::RustIRExpr::Loop(ir::RustIRLoopExpr {
                         label: label.clone(),
                         stmts: vec![
-                            ir::RustIRStmt::While(ir::RustIRWhileStmt { condition, stmts }),
-                            ir::RustIRStmt::Break(ir::RustIRBreakStmt { label, expr: None }),
+                            ir::RustIRStmt::While(ir::RustIRWhileStmt {
+                                condition,
+                                stmts,
+                                span: None,
+                            }),
+                            ir::RustIRStmt::Break(ir::RustIRBreakStmt {
+                                label,
+                                expr: None,
+                                span: None,
+                            }),
                         ],
                     }),
+                    span: None,
                 },
             )]);
         }
@@ -192,6 +245,25 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
         return Ok(vec![ir::RustIRStmt::While(ir::RustIRWhileStmt {
             condition,
             stmts,
+            span: None,
+        })]);
+    }
+
+    fn visit_for_stmt(&mut self, stmt: Arc<Mutex<ForStmt<FeType>>>) -> Result<Vec<ir::RustIRStmt>> {
+        let stmt = stmt.try_lock().unwrap();
+
+        let label = self.map_label(stmt.node_id().to_string(), &stmt.label);
+
+        let iter = self.translate_for_iter(&stmt.iter)?;
+
+        let stmts = self.translate_stmts(&stmt.block.stmts)?;
+
+        return Ok(vec![ir::RustIRStmt::For(ir::RustIRForStmt {
+            label,
+            name: stmt.name.lexeme.clone(),
+            iter,
+            stmts,
+            span: None,
         })]);
     }
 
@@ -201,23 +273,42 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
     ) -> Result<Vec<ir::RustIRStmt>> {
         let stmt = &mut *stmt.try_lock().unwrap();
 
-        let label = self.map_label(
-            stmt.handler
-                .as_ref()
-                .map(|h| h.node_id().to_string())
-                .unwrap_or(String::new()),
-            &stmt.label,
-        );
+        let label = match &stmt.handler {
+            Some(handler @ BreakHandler::ForExpr(_)) => {
+                Some(self.for_expr_label(handler.node_id().to_string()))
+            }
 
-        let expr = if let Some(value) = &mut stmt.value {
-            Some(value.0.try_lock().unwrap().accept(self)?)
-        } else {
-            None
+            handler => self.map_label(
+                handler
+                    .as_ref()
+                    .map(|h| h.node_id().to_string())
+                    .unwrap_or_default(),
+                &stmt.label,
+            ),
+        };
+
+        let loop_type = match &stmt.handler {
+            Some(BreakHandler::LoopExpr(loop_expr)) => loop_expr
+                .try_lock()
+                .unwrap()
+                .resolved_type
+                .clone()
+                .flatten(),
+            _ => None,
+        };
+
+        let expr = match (&mut stmt.value, &loop_type) {
+            (Some(value), _) => Some(self.translate_value(value, loop_type.as_ref())?),
+
+            // The loop's value is optional when it can be broken out of without one
+            (None, Some(_)) => Some(Self::none_expr()),
+            (None, None) => None,
         };
 
         return Ok(vec![ir::RustIRStmt::Break(ir::RustIRBreakStmt {
             label,
             expr,
+            span: None,
         })]);
     }
 
@@ -227,13 +318,26 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
     ) -> Result<Vec<ir::RustIRStmt>> {
         let stmt = &mut *stmt.try_lock().unwrap();
 
-        let expr = stmt.value.0.try_lock().unwrap().accept(self)?;
+        let handler_type = match &stmt.handler {
+            Some(ThenHandler::IfExpr(_, if_expr)) => {
+                if_expr.try_lock().unwrap().resolved_type.clone().flatten()
+            }
+            Some(ThenHandler::MatchExpr(match_expr)) => match_expr
+                .try_lock()
+                .unwrap()
+                .resolved_type
+                .clone()
+                .flatten(),
+            Some(ThenHandler::IfStmt(..)) | None => None,
+        };
+
+        let expr = self.translate_value(&stmt.value, handler_type.as_ref())?;
 
         let label = self.map_label(
             stmt.handler
                 .as_ref()
                 .map(|h| h.node_id().to_string())
-                .unwrap_or(String::new()),
+                .unwrap_or_default(),
             &stmt.label,
         );
 
@@ -241,6 +345,7 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
             return Ok(vec![ir::RustIRStmt::Break(ir::RustIRBreakStmt {
                 label,
                 expr: Some(expr),
+                span: None,
             })]);
         }
 
@@ -267,12 +372,27 @@ impl StmtVisitor<FeType, Result<Vec<ir::RustIRStmt>>> for RustSyntaxCompiler {
                 return Ok(vec![ir::RustIRStmt::Break(ir::RustIRBreakStmt {
                     label,
                     expr: Some(expr),
+                    span: None,
+                })]);
+            }
+        }
+
+        if let Some(ThenHandler::MatchExpr(match_expr)) = &stmt.handler {
+            let match_expr = &*match_expr.try_lock().unwrap();
+
+            let label = self.map_label(match_expr.node_id().to_string(), &match_expr.label);
+
+            if label.is_some() {
+                return Ok(vec![ir::RustIRStmt::Break(ir::RustIRBreakStmt {
+                    label,
+                    expr: Some(expr),
+                    span: None,
                 })]);
             }
         }
 
         return Ok(vec![ir::RustIRStmt::ImplicitReturn(
-            ir::RustIRImplicitReturnStmt { expr },
+            ir::RustIRImplicitReturnStmt { expr, span: None },
         )]);
     }
 }
@@ -9,7 +9,7 @@ impl UseVisitor<FeType, Result> for RustSyntaxCompiler {
             .as_ref()
             .map(|use_mod| self.translate_use_mod(use_mod));
 
-        let path = Self::translate_use_static_path(&mut use_decl.path)?;
+        let path = self.translate_use_static_path(&mut use_decl.path)?;
 
         if let Some(path) = path {
             let file_idx = self.out.files.len() - 1;
@@ -15,13 +15,17 @@ pub struct RustIR {
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRFile {
     pub path: PathBuf,
+    pub source: PathBuf,
     pub mods: Vec<RustIRMod>,
     pub uses: Vec<RustIRUse>,
     pub decls: Vec<RustIRDecl>,
 }
 
+/// An attribute on a declaration, e.g. `#[derive(PartialEq)]`
 #[derive(Debug, Clone, PartialEq)]
-pub struct RustIRMacro {}
+pub enum RustIRMacro {
+    Derive(Vec<Arc<str>>),
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRMod {
@@ -4,6 +4,8 @@ use super::*;
 pub enum RustIRDecl {
     Fn(RustIRFnDecl),
     Struct(RustIRStructDecl),
+    Enum(RustIREnumDecl),
+    Impl(RustIRImplDecl),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -18,13 +20,24 @@ pub struct RustIRFnDecl {
     pub is_async: bool,
     pub generics: Option<RustIRFnGenerics>,
     pub name: Arc<str>,
+    pub self_param: Option<RustIRSelfParam>,
     pub params: Vec<RustIRFnParam>,
     pub return_type: Option<RustIRStaticType>,
     pub body: RustIRBlockExpr,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct RustIRFnGenerics {}
+pub struct RustIRFnGenerics {
+    pub params: Vec<Arc<str>>,
+}
+
+/// `self`, `mut self`, `&self` or `&mut self`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRSelfParam {
+    pub ref_type: Option<RustIRRefType>,
+    pub is_mut: bool,
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRFnParam {
@@ -40,10 +53,13 @@ pub struct RustIRStructDecl {
     pub name: Arc<str>,
     pub generics: Option<RustIRStructGenerics>,
     pub fields: Vec<RustIRStructField>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct RustIRStructGenerics {}
+pub struct RustIRStructGenerics {
+    pub params: Vec<Arc<str>>,
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRStructField {
@@ -58,10 +74,48 @@ pub enum RustIRStructFieldMod {
     Pub,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIREnumDecl {
+    pub macros: Vec<RustIRMacro>,
+    pub decl_mod: Option<RustIRDeclMod>,
+    pub name: Arc<str>,
+    pub variants: Vec<RustIREnumVariant>,
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIREnumVariant {
+    pub name: Arc<str>,
+    pub fields: RustIREnumVariantFields,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RustIREnumVariantFields {
+    Unit,
+    Tuple(Vec<RustIRStaticType>),
+    Struct(Vec<RustIRStructField>),
+}
+
+/// `impl<T> Name<T>`, with the struct's type parameters
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRImplDecl {
+    pub generics: Option<RustIRImplGenerics>,
+    pub name: Arc<str>,
+    pub methods: Vec<RustIRFnDecl>,
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRImplGenerics {
+    pub params: Vec<Arc<str>>,
+}
+
 // Visitor pattern
 pub trait RustIRDeclVisitor<R = ()> {
     fn visit_fn_decl(&mut self, decl: &mut RustIRFnDecl) -> R;
     fn visit_struct_decl(&mut self, decl: &mut RustIRStructDecl) -> R;
+    fn visit_enum_decl(&mut self, decl: &mut RustIREnumDecl) -> R;
+    fn visit_impl_decl(&mut self, decl: &mut RustIRImplDecl) -> R;
 }
 
 pub trait RustIRDeclAccept<R, V: RustIRDeclVisitor<R>> {
@@ -73,6 +127,8 @@ impl<R, V: RustIRDeclVisitor<R>> RustIRDeclAccept<R, V> for RustIRDecl {
         return match self {
             Self::Fn(decl) => decl.accept(visitor),
             Self::Struct(decl) => decl.accept(visitor),
+            Self::Enum(decl) => decl.accept(visitor),
+            Self::Impl(decl) => decl.accept(visitor),
         };
     }
 }
@@ -88,3 +144,15 @@ impl<R, V: RustIRDeclVisitor<R>> RustIRDeclAccept<R, V> for RustIRStructDecl {
         return visitor.visit_struct_decl(self);
     }
 }
+
+impl<R, V: RustIRDeclVisitor<R>> RustIRDeclAccept<R, V> for RustIREnumDecl {
+    fn accept(&mut self, visitor: &mut V) -> R {
+        return visitor.visit_enum_decl(self);
+    }
+}
+
+impl<R, V: RustIRDeclVisitor<R>> RustIRDeclAccept<R, V> for RustIRImplDecl {
+    fn accept(&mut self, visitor: &mut V) -> R {
+        return visitor.visit_impl_decl(self);
+    }
+}
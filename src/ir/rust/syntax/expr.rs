@@ -15,6 +15,7 @@ pub enum RustIRExpr {
     Assign(RustIRAssignExpr),
     If(RustIRIfExpr),
     Loop(RustIRLoopExpr),
+    Match(RustIRMatchExpr),
     Construct(RustIRConstructExpr),
     Get(RustIRGetExpr),
 }
@@ -71,6 +72,7 @@ pub struct RustIRUnaryExpr {
 pub enum RustIRUnaryOp {
     Ref(RustIRRefType),
     Not,
+    Negate,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -84,10 +86,43 @@ pub struct RustIRBinaryExpr {
 pub enum RustIRBinaryOp {
     Add,
     Subtract,
+    Multiply,
+    Divide,
+    Modulo,
     Less,
     LessEq,
     Greater,
     GreaterEq,
+    Eq,
+    NotEq,
+    And,
+    Or,
+    Range,
+    RangeInclusive,
+}
+
+impl RustIRBinaryOp {
+    /// How tightly the operator binds in Rust, higher binds tighter.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::Range | Self::RangeInclusive => return 0,
+            Self::Or => return 1,
+            Self::And => return 2,
+            Self::Less
+            | Self::LessEq
+            | Self::Greater
+            | Self::GreaterEq
+            | Self::Eq
+            | Self::NotEq => return 3,
+            Self::Add | Self::Subtract => return 4,
+            Self::Multiply | Self::Divide | Self::Modulo => return 5,
+        }
+    }
+
+    /// Comparisons can't be chained in Rust, e.g. `a < b < c`.
+    pub fn is_comparison(&self) -> bool {
+        return self.precedence() == 3;
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -102,6 +137,9 @@ pub enum RustIRAssignOp {
     Eq,
     PlusEq,
     MinusEq,
+    MultiplyEq,
+    DivideEq,
+    ModuloEq,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -129,6 +167,48 @@ pub struct RustIRLoopExpr {
     pub stmts: Vec<RustIRStmt>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRMatchExpr {
+    pub value: Box<RustIRExpr>,
+    pub arms: Vec<RustIRMatchArm>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRMatchArm {
+    pub pattern: RustIRPattern,
+    pub guard: Option<RustIRExpr>,
+    pub stmts: Vec<RustIRStmt>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RustIRPattern {
+    Wildcard,
+    Literal(RustIRExpr),
+    Binding(Arc<str>),
+    Struct(RustIRStructPattern),
+    TupleStruct(RustIRTupleStructPattern),
+    Path(RustIRStaticPath),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRStructPattern {
+    pub path: RustIRStaticPath,
+    pub fields: Vec<RustIRStructPatternField>,
+    pub has_rest: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRStructPatternField {
+    pub name: Arc<str>,
+    pub pattern: RustIRPattern,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRTupleStructPattern {
+    pub path: RustIRStaticPath,
+    pub fields: Vec<RustIRPattern>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRConstructExpr {
     pub target: RustIRConstructTarget,
@@ -169,6 +249,7 @@ pub trait RustIRExprVisitor<R = ()> {
     fn visit_assign_expr(&mut self, expr: &mut RustIRAssignExpr) -> R;
     fn visit_if_expr(&mut self, expr: &mut RustIRIfExpr) -> R;
     fn visit_loop_expr(&mut self, stmt: &mut RustIRLoopExpr) -> R;
+    fn visit_match_expr(&mut self, expr: &mut RustIRMatchExpr) -> R;
     fn visit_construct_expr(&mut self, expr: &mut RustIRConstructExpr) -> R;
     fn visit_get_expr(&mut self, expr: &mut RustIRGetExpr) -> R;
 }
@@ -193,6 +274,7 @@ impl<R, V: RustIRExprVisitor<R>> RustIRExprAccept<R, V> for RustIRExpr {
             Self::Assign(expr) => expr.accept(visitor),
             Self::If(expr) => expr.accept(visitor),
             Self::Loop(expr) => expr.accept(visitor),
+            Self::Match(expr) => expr.accept(visitor),
             Self::Construct(expr) => expr.accept(visitor),
             Self::Get(expr) => expr.accept(visitor),
         };
@@ -277,6 +359,12 @@ impl<R, V: RustIRExprVisitor<R>> RustIRExprAccept<R, V> for RustIRLoopExpr {
     }
 }
 
+impl<R, V: RustIRExprVisitor<R>> RustIRExprAccept<R, V> for RustIRMatchExpr {
+    fn accept(&mut self, visitor: &mut V) -> R {
+        return visitor.visit_match_expr(self);
+    }
+}
+
 impl<R, V: RustIRExprVisitor<R>> RustIRExprAccept<R, V> for RustIRConstructExpr {
     fn accept(&mut self, visitor: &mut V) -> R {
         return visitor.visit_construct_expr(self);
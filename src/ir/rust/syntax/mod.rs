@@ -15,4 +15,6 @@ pub use r#use::*;
 mod r#static;
 pub use r#static::*;
 
+use crate::token::Span;
+
 use std::sync::Arc;
@@ -4,6 +4,7 @@ use super::*;
 pub struct RustIRStaticType {
     pub ref_type: Option<RustIRRefType>,
     pub static_path: RustIRStaticPath,
+    pub generic_args: Vec<RustIRStaticType>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
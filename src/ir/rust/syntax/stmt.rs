@@ -7,17 +7,35 @@ pub enum RustIRStmt {
     Let(RustIRLetStmt),
     Return(RustIRReturnStmt),
     While(RustIRWhileStmt),
+    For(RustIRForStmt),
     Break(RustIRBreakStmt),
 }
 
+impl RustIRStmt {
+    /// The span of the Ferrum code this statement was compiled from, if any.
+    pub fn span_mut(&mut self) -> &mut Option<Span> {
+        match self {
+            Self::ImplicitReturn(stmt) => return &mut stmt.span,
+            Self::Expr(stmt) => return &mut stmt.span,
+            Self::Let(stmt) => return &mut stmt.span,
+            Self::Return(stmt) => return &mut stmt.span,
+            Self::While(stmt) => return &mut stmt.span,
+            Self::For(stmt) => return &mut stmt.span,
+            Self::Break(stmt) => return &mut stmt.span,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRImplicitReturnStmt {
     pub expr: RustIRExpr,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRExprStmt {
     pub expr: RustIRExpr,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -26,11 +44,12 @@ pub struct RustIRLetStmt {
     pub name: Arc<str>,
     pub explicit_type: Option<RustIRLetExplicitType>,
     pub value: Option<RustIRLetValue>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRLetExplicitType {
-    // TODO
+    pub static_type: RustIRStaticType,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -41,18 +60,30 @@ pub struct RustIRLetValue {
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRReturnStmt {
     pub expr: Option<RustIRExpr>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRWhileStmt {
     pub condition: RustIRExpr,
     pub stmts: Vec<RustIRStmt>,
+    pub span: Option<Span>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustIRForStmt {
+    pub label: Option<Arc<str>>,
+    pub name: Arc<str>,
+    pub iter: RustIRExpr,
+    pub stmts: Vec<RustIRStmt>,
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RustIRBreakStmt {
     pub label: Option<Arc<str>>,
     pub expr: Option<RustIRExpr>,
+    pub span: Option<Span>,
 }
 
 // Visitor pattern
@@ -62,6 +93,7 @@ pub trait RustIRStmtVisitor<R = ()> {
     fn visit_let_stmt(&mut self, stmt: &mut RustIRLetStmt) -> R;
     fn visit_return_stmt(&mut self, stmt: &mut RustIRReturnStmt) -> R;
     fn visit_while_stmt(&mut self, stmt: &mut RustIRWhileStmt) -> R;
+    fn visit_for_stmt(&mut self, stmt: &mut RustIRForStmt) -> R;
     fn visit_break_stmt(&mut self, stmt: &mut RustIRBreakStmt) -> R;
 }
 
@@ -77,6 +109,7 @@ impl<R, V: RustIRStmtVisitor<R>> RustIRStmtAccept<R, V> for RustIRStmt {
             Self::Let(stmt) => stmt.accept(visitor),
             Self::Return(stmt) => stmt.accept(visitor),
             Self::While(stmt) => stmt.accept(visitor),
+            Self::For(stmt) => stmt.accept(visitor),
             Self::Break(stmt) => stmt.accept(visitor),
         };
     }
@@ -106,6 +139,12 @@ impl<R, V: RustIRStmtVisitor<R>> RustIRStmtAccept<R, V> for RustIRWhileStmt {
     }
 }
 
+impl<R, V: RustIRStmtVisitor<R>> RustIRStmtAccept<R, V> for RustIRForStmt {
+    fn accept(&mut self, visitor: &mut V) -> R {
+        return visitor.visit_for_stmt(self);
+    }
+}
+
 impl<R, V: RustIRStmtVisitor<R>> RustIRStmtAccept<R, V> for RustIRReturnStmt {
     fn accept(&mut self, visitor: &mut V) -> R {
         return visitor.visit_return_stmt(self);
@@ -1,10 +1,12 @@
 use crate::config::Config;
+use crate::diagnostics::{Diagnostic, ErrorCode};
 use crate::result::Result;
 use crate::source::*;
 use crate::token::*;
 use crate::utils::*;
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 use lazy_static;
@@ -12,30 +14,32 @@ use lazy_static;
 lazy_static::lazy_static! {
     static ref KEYWORDS: HashMap<String, TokenType> = {
         let mut keywords = HashMap::new();
-        // keywords.insert("and".to_string(), TokenType::And);
+        keywords.insert("and".to_string(), TokenType::And);
         // keywords.insert("as".to_string(), TokenType::As);
         keywords.insert("break".to_string(), TokenType::Break);
         keywords.insert("const".to_string(), TokenType::Const);
         // keywords.insert("CRASH!".to_string(), TokenType::Crash);
         keywords.insert("else".to_string(), TokenType::Else);
+        keywords.insert("enum".to_string(), TokenType::Enum);
         keywords.insert("false".to_string(), TokenType::False);
         keywords.insert("fn".to_string(), TokenType::Fn);
-        // keywords.insert("for".to_string(), TokenType::For);
+        keywords.insert("for".to_string(), TokenType::For);
         keywords.insert("if".to_string(), TokenType::If);
-        // keywords.insert("impl".to_string(), TokenType::Impl);
-        // keywords.insert("in".to_string(), TokenType::In);
+        keywords.insert("impl".to_string(), TokenType::Impl);
+        keywords.insert("in".to_string(), TokenType::In);
         keywords.insert("loop".to_string(), TokenType::Loop);
-        // keywords.insert("match".to_string(), TokenType::Match);
+        keywords.insert("match".to_string(), TokenType::Match);
         keywords.insert("mut".to_string(), TokenType::Mut);
+        keywords.insert("none".to_string(), TokenType::NoneLiteral);
         // keywords.insert("norm".to_string(), TokenType::Norm);
         keywords.insert("not".to_string(), TokenType::Not);
-        // keywords.insert("or".to_string(), TokenType::Or);
+        keywords.insert("or".to_string(), TokenType::Or);
         keywords.insert("pub".to_string(), TokenType::Pub);
         // keywords.insert("pure".to_string(), TokenType::Pure);
         keywords.insert("return".to_string(), TokenType::Return);
         // keywords.insert("risk".to_string(), TokenType::Risk);
         // keywords.insert("safe".to_string(), TokenType::Safe);
-        // keywords.insert("self".to_string(), TokenType::SelfVal);
+        keywords.insert("self".to_string(), TokenType::SelfVal);
         // keywords.insert("Self".to_string(), TokenType::SelfType);
         keywords.insert("struct".to_string(), TokenType::Struct);
         keywords.insert("then".to_string(), TokenType::Then);
@@ -86,6 +90,7 @@ impl FeLexer {
             match (src_pkg, &mut *out) {
                 (FeSourcePackage::File(source_file), FeTokenPackage::File(token_file)) => {
                     FeSourceScanner::scan_source(
+                        source_file.path.clone(),
                         source_file.content.clone(),
                         token_file.tokens.clone(),
                     )?;
@@ -93,6 +98,7 @@ impl FeLexer {
 
                 (FeSourcePackage::Dir(source_dir), FeTokenPackage::Dir(token_dir)) => {
                     FeSourceScanner::scan_source(
+                        source_dir.entry_file.path.clone(),
                         source_dir.entry_file.content.clone(),
                         token_dir.entry_file.tokens.clone(),
                     )?;
@@ -124,6 +130,7 @@ impl FeLexer {
 
 #[derive(Debug, Clone)]
 struct FeSourceScanner {
+    path: PathBuf,
     source: Arc<str>,
 
     out: Arc<Mutex<Vec<Arc<Token>>>>,
@@ -135,19 +142,21 @@ struct FeSourceScanner {
 
 impl FeSourceScanner {
     fn scan_source(
+        path: PathBuf,
         source: Arc<str>,
         tokens: Arc<Mutex<Vec<Arc<Token>>>>,
     ) -> Result<Arc<Mutex<Vec<Arc<Token>>>>> {
-        return Self::new(source, tokens).scan();
+        return Self::new(path, source, tokens).scan();
     }
 
-    fn new(source: Arc<str>, tokens: Arc<Mutex<Vec<Arc<Token>>>>) -> Self {
+    fn new(path: PathBuf, source: Arc<str>, tokens: Arc<Mutex<Vec<Arc<Token>>>>) -> Self {
         let mut span = Span::zero();
         span.start.line = 1;
         span.start.column = 1;
         span.end = span.start.clone();
 
         return Self {
+            path,
             source,
 
             out: tokens,
@@ -160,15 +169,17 @@ impl FeSourceScanner {
 
     fn scan(mut self) -> Result<Arc<Mutex<Vec<Arc<Token>>>>> {
         while !self.is_end() {
-            self.scan_token();
+            self.scan_token()?;
             self.span.start = self.span.end.clone();
         }
 
         return Ok(self.out);
     }
 
-    fn scan_token(&mut self) {
-        let Some(c) = self.current() else { return };
+    fn scan_token(&mut self) -> Result {
+        let Some(c) = self.current() else {
+            return Ok(());
+        };
 
         let token_type = match c {
             '/' if self.peek_next() == Some('/') => {
@@ -179,10 +190,10 @@ impl FeSourceScanner {
                 None
             }
 
-            '"' => Some(self.string(false)),
-            '}' if self.format_string_nest > 0 => Some(self.string(true)),
+            '"' => Some(self.string(false)?),
+            '}' if self.format_string_nest > 0 => Some(self.string(true)?),
 
-            '\'' => Some(self.label_or_char()),
+            '\'' => Some(self.label_or_char()?),
 
             ' ' | '\r' | '\t' => None,
 
@@ -200,8 +211,23 @@ impl FeSourceScanner {
 
             '\n' => Some(TokenType::Newline),
 
-            '=' => Some(TokenType::Equal),
+            '=' => {
+                if self.peek_next() == Some('=') {
+                    self.advance_col();
+                    Some(TokenType::EqualEqual)
+                } else {
+                    Some(TokenType::Equal)
+                }
+            }
+
+            '!' if self.peek_next() == Some('=') => {
+                self.advance_col();
+                Some(TokenType::BangEqual)
+            }
+
             '&' => Some(TokenType::Amp),
+            '@' => Some(TokenType::At),
+            '?' => Some(TokenType::Question),
 
             '<' => {
                 if self.peek_next() == Some('=') {
@@ -239,6 +265,33 @@ impl FeSourceScanner {
                 }
             }
 
+            '*' => {
+                if self.peek_next() == Some('=') {
+                    self.advance_col();
+                    Some(TokenType::AsteriskEqual)
+                } else {
+                    Some(TokenType::Asterisk)
+                }
+            }
+
+            '/' => {
+                if self.peek_next() == Some('=') {
+                    self.advance_col();
+                    Some(TokenType::SlashEqual)
+                } else {
+                    Some(TokenType::Slash)
+                }
+            }
+
+            '%' => {
+                if self.peek_next() == Some('=') {
+                    self.advance_col();
+                    Some(TokenType::PercentEqual)
+                } else {
+                    Some(TokenType::Percent)
+                }
+            }
+
             ':' => {
                 if self.peek_next() == Some(':') {
                     self.advance_col();
@@ -252,6 +305,15 @@ impl FeSourceScanner {
                 if self.peek_next() == Some('/') {
                     self.advance_col();
                     Some(TokenType::DotSlash)
+                } else if self.peek_next() == Some('.') {
+                    self.advance_col();
+
+                    if self.peek_next() == Some('=') {
+                        self.advance_col();
+                        Some(TokenType::DotDotEqual)
+                    } else {
+                        Some(TokenType::DotDot)
+                    }
                 } else {
                     Some(TokenType::Dot)
                 }
@@ -266,10 +328,16 @@ impl FeSourceScanner {
                 }
             }
 
-            c if self.is_digit(c) => Some(self.number()),
-            c if self.is_letter(c) => Some(self.identifier()),
+            c if self.is_digit(c) => Some(self.number()?),
+            c if self.is_letter(c) => Some(self.identifier()?),
 
-            c => todo!("TODO: Support [{c}]\n{}", &self.source[self.cursor..]),
+            c => {
+                return Err(self
+                    .error(format!("Unexpected character '{c}'"))
+                    .with_code(ErrorCode::INVALID_TOKEN)
+                    .with_label(self.span.clone(), "character not recognized")
+                    .into());
+            }
         };
 
         match token_type {
@@ -285,6 +353,8 @@ impl FeSourceScanner {
 
             None => self.advance_col(),
         }
+
+        return Ok(());
     }
 
     fn current(&self) -> Option<char> {
@@ -299,7 +369,7 @@ impl FeSourceScanner {
         return self.source.char_at(self.cursor + offset);
     }
 
-    fn string(&mut self, is_continuing_fmt_str: bool) -> TokenType {
+    fn string(&mut self, is_continuing_fmt_str: bool) -> Result<TokenType> {
         let mut is_starting_fmt_str = false;
 
         while !self.is_end() {
@@ -326,48 +396,52 @@ impl FeSourceScanner {
         }
 
         if self.is_end() {
-            todo!();
-            // self.error_ctx
-            //     .error(self.span.clone(), "Unterminated string.");
+            return Err(self
+                .error("Unterminated string")
+                .with_code(ErrorCode::INVALID_TOKEN)
+                .with_label(self.span.clone(), "string is never closed")
+                .into());
         }
 
         match (is_continuing_fmt_str, is_starting_fmt_str) {
-            (false, false) => return TokenType::PlainString,
+            (false, false) => return Ok(TokenType::PlainString),
             (false, true) => {
                 self.format_string_nest += 1;
-                return TokenType::OpenFmtString;
+                return Ok(TokenType::OpenFmtString);
             }
-            (true, true) => return TokenType::MidFmtString,
+            (true, true) => return Ok(TokenType::MidFmtString),
             (true, false) => {
                 self.format_string_nest -= 1;
-                return TokenType::CloseFmtString;
+                return Ok(TokenType::CloseFmtString);
             }
-            //
-            // _ => todo!(),
         }
     }
 
-    fn label_or_char(&mut self) -> TokenType {
+    fn label_or_char(&mut self) -> Result<TokenType> {
         if self.peek_next() == Some('\\') {
             self.advance_col();
 
             if self.peek_next() != Some('\'') {
                 self.advance_col();
-                return TokenType::Char;
+                return Ok(TokenType::Char);
             }
 
-            todo!();
+            return Err(self
+                .error("Escaped quote character literals are not supported yet")
+                .with_code(ErrorCode::UNSUPPORTED)
+                .with_span(self.span.clone())
+                .into());
         }
 
         let Some(c) = self.peek_next() else {
-            return TokenType::Label;
+            return Ok(TokenType::Label);
         };
 
         if (!c.is_whitespace() || c == ' ') && self.peek_offset(2) == Some('\'') {
             self.advance_col();
             self.advance_col();
 
-            return TokenType::Char;
+            return Ok(TokenType::Char);
         }
 
         if self.is_letter(c) {
@@ -382,42 +456,105 @@ impl FeSourceScanner {
             }
         }
 
-        return TokenType::Label;
+        return Ok(TokenType::Label);
     }
 
-    fn number(&mut self) -> TokenType {
-        while let Some(c) = self.peek_next() {
-            if !self.is_digit(c) {
-                break;
-            }
+    fn number(&mut self) -> Result<TokenType> {
+        let radix = match (self.current(), self.peek_next()) {
+            (Some('0'), Some('x')) => Some((16, "hexadecimal")),
+            (Some('0'), Some('o')) => Some((8, "octal")),
+            (Some('0'), Some('b')) => Some((2, "binary")),
+            _ => None,
+        };
 
+        if let Some((radix, radix_name)) = radix {
+            // Consume the prefix
             self.advance_col();
+
+            let mut has_digits = false;
+
+            while let Some(c) = self.peek_next() {
+                if c != '_' && !c.is_ascii_alphanumeric() {
+                    break;
+                }
+
+                self.advance_col();
+
+                if c == '_' {
+                    continue;
+                }
+
+                if !c.is_digit(radix) {
+                    return Err(self
+                        .error(format!("Invalid digit '{c}' in {radix_name} literal"))
+                        .with_code(ErrorCode::INVALID_TOKEN)
+                        .with_label(self.span.clone(), "invalid digit")
+                        .into());
+                }
+
+                has_digits = true;
+            }
+
+            if !has_digits {
+                return Err(self
+                    .error(format!("Expected {radix_name} digits after the prefix"))
+                    .with_code(ErrorCode::INVALID_TOKEN)
+                    .with_label(self.span.clone(), "missing digits")
+                    .into());
+            }
+
+            return Ok(TokenType::IntegerNumber);
         }
 
+        self.digits();
+
+        let mut token_type = TokenType::IntegerNumber;
+
         // Look for a fractional part
         if self.peek_next() == Some('.') {
             if let Some(next) = self.peek_offset(2) {
                 if self.is_digit(next) {
                     // Consume the "."
                     self.advance_col();
+                    self.digits();
+
+                    token_type = TokenType::DecimalNumber;
+                }
+            }
+        }
 
-                    while let Some(peek) = self.peek_next() {
-                        if !self.is_digit(peek) {
-                            break;
-                        }
+        // Look for an exponent, e.g. `1e9` or `2.5e-3`
+        if matches!(self.peek_next(), Some('e' | 'E')) {
+            let sign_len = usize::from(matches!(self.peek_offset(2), Some('+' | '-')));
 
+            if let Some(next) = self.peek_offset(2 + sign_len) {
+                if self.is_digit(next) {
+                    // Consume the "e" and its sign
+                    for _ in 0..=sign_len {
                         self.advance_col();
                     }
+                    self.digits();
 
-                    return TokenType::DecimalNumber;
+                    token_type = TokenType::DecimalNumber;
                 }
             }
         }
 
-        return TokenType::IntegerNumber;
+        return Ok(token_type);
     }
 
-    fn identifier(&mut self) -> TokenType {
+    /// Consumes the digits after the current character, along with any `_` separating them.
+    fn digits(&mut self) {
+        while let Some(c) = self.peek_next() {
+            if !self.is_digit(c) && c != '_' {
+                break;
+            }
+
+            self.advance_col();
+        }
+    }
+
+    fn identifier(&mut self) -> Result<TokenType> {
         while let Some(peek) = self.peek_next() {
             if !self.is_letter_or_digit(peek) {
                 break;
@@ -431,11 +568,15 @@ impl FeSourceScanner {
         if text == "CRASH" && self.peek_next() == Some('!') {
             self.advance_col();
 
-            todo!();
-            // return TokenType::Crash;
+            // TODO: return TokenType::Crash;
+            return Err(self
+                .error("'CRASH!' is not supported yet")
+                .with_code(ErrorCode::UNSUPPORTED)
+                .with_span(self.span.clone())
+                .into());
         }
 
-        return KEYWORDS.get(text).cloned().unwrap_or(TokenType::Ident);
+        return Ok(KEYWORDS.get(text).cloned().unwrap_or(TokenType::Ident));
     }
 
     fn add_token(&mut self, token_type: TokenType) {
@@ -464,6 +605,10 @@ impl FeSourceScanner {
         self.span.end.column = 1;
     }
 
+    fn error(&self, message: impl Into<Arc<str>>) -> Diagnostic {
+        return Diagnostic::error(message).with_file(self.path.clone());
+    }
+
     fn is_letter(&self, c: char) -> bool {
         return c.is_ascii_alphabetic() || c == '_';
     }
@@ -559,5 +704,43 @@ pub fn main()
             Semicolon, Newline,
             Semicolon, Newline,
         ]),
+
+        test_numbers: ("-1 0xFF 0o17 0b1010_1010 1_000 1.5 1.5e3 2E-2 1e 0..2", vec![
+            Minus, IntegerNumber,
+            IntegerNumber,
+            IntegerNumber,
+            IntegerNumber,
+            IntegerNumber,
+            DecimalNumber,
+            DecimalNumber,
+            DecimalNumber,
+            IntegerNumber, Ident,
+            IntegerNumber, DotDot, IntegerNumber,
+        ]),
+
+        test_optional: ("const x: Int? = none", vec![
+            Const, Ident, Colon, Ident, Question, Equal, NoneLiteral,
+        ]),
+    }
+
+    #[test]
+    fn test_unexpected_character() {
+        let err = FeLexer::scan_package(
+            Default::default(),
+            Arc::new(Mutex::new(FeSourcePackage::File(FeSourceFile {
+                name: SourcePackageName("".into()),
+                path: "main.fe".into(),
+                content: "pub fn main()\n    $\n;".into(),
+            }))),
+        )
+        .unwrap_err();
+
+        let diagnostic = err.downcast_ref::<Diagnostic>().unwrap();
+
+        assert!(diagnostic.is_error());
+        assert_eq!(diagnostic.file, Some(PathBuf::from("main.fe")));
+
+        let span = &diagnostic.primary.as_ref().unwrap().span;
+        assert_eq!((span.start.line, span.start.column), (2, 5));
     }
 }
@@ -2,10 +2,12 @@
 
 pub mod code_gen;
 pub mod config;
+pub mod diagnostics;
 pub mod executor;
 pub mod helpers;
 pub mod ir;
 pub mod lexer;
+pub mod lint;
 pub mod log;
 pub mod parser;
 pub mod project_gen;
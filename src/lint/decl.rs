@@ -0,0 +1,86 @@
+use super::*;
+
+impl DeclVisitor<FeType> for FeLinter {
+    fn visit_function_decl(&mut self, decl: Arc<Mutex<FnDecl<FeType>>>) {
+        let decl = &*decl.try_lock().unwrap();
+
+        self.lint_fn(decl, &decl.attrs);
+    }
+
+    fn visit_struct_decl(&mut self, decl: Arc<Mutex<StructDecl<FeType>>>) {
+        let decl = &*decl.try_lock().unwrap();
+
+        self.enter_decl(&decl.attrs);
+
+        for field in &decl.fields {
+            self.use_static_type(&field.static_type_ref);
+        }
+    }
+
+    fn visit_enum_decl(&mut self, decl: Arc<Mutex<EnumDecl<FeType>>>) {
+        let decl = &*decl.try_lock().unwrap();
+
+        self.enter_decl(&decl.attrs);
+
+        for variant in &decl.variants {
+            match &variant.fields {
+                EnumDeclVariantFields::Unit => {}
+
+                EnumDeclVariantFields::Tuple { fields, .. } => {
+                    for field in fields {
+                        self.use_static_type(&field.static_type_ref);
+                    }
+                }
+
+                EnumDeclVariantFields::Struct { fields, .. } => {
+                    for field in fields {
+                        self.use_static_type(&field.static_type_ref);
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_impl_decl(&mut self, decl: Arc<Mutex<ImplDecl<FeType>>>) {
+        let decl = &*decl.try_lock().unwrap();
+
+        self.use_static_path(&decl.target);
+
+        for method in &decl.methods {
+            let method = &*method.try_lock().unwrap();
+
+            // A method's own attributes apply on top of the impl block's
+            let attrs = decl
+                .attrs
+                .iter()
+                .chain(&method.attrs)
+                .cloned()
+                .collect::<Vec<DeclAttr>>();
+
+            self.lint_fn(method, &attrs);
+        }
+    }
+}
+
+impl FeLinter {
+    fn lint_fn(&mut self, decl: &FnDecl<FeType>, attrs: &[DeclAttr]) {
+        self.enter_decl(attrs);
+        self.begin_scope();
+
+        for param in &decl.params {
+            self.use_static_type(&param.static_type_ref);
+            self.declare(param.name.clone(), None);
+        }
+
+        if let Some(return_type) = &decl.return_type {
+            self.use_static_type(&return_type.static_type);
+        }
+
+        match &decl.body {
+            FnDeclBody::Short(_) => {}
+            FnDeclBody::Block(block) => self.block(block),
+        }
+
+        self.end_scope();
+    }
+}
@@ -0,0 +1,236 @@
+use super::*;
+
+impl ExprVisitor<FeType> for FeLinter {
+    fn visit_bool_literal_expr(&mut self, _expr: Arc<Mutex<BoolLiteralExpr<FeType>>>) {}
+
+    fn visit_none_literal_expr(&mut self, _expr: Arc<Mutex<NoneLiteralExpr<FeType>>>) {}
+
+    fn visit_number_literal_expr(&mut self, _expr: Arc<Mutex<NumberLiteralExpr<FeType>>>) {}
+
+    fn visit_plain_string_literal_expr(
+        &mut self,
+        _expr: Arc<Mutex<PlainStringLiteralExpr<FeType>>>,
+    ) {
+    }
+
+    fn visit_fmt_string_literal_expr(&mut self, expr: Arc<Mutex<FmtStringLiteralExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        for part in &expr.rest {
+            part.expr.0.try_lock().unwrap().accept(self);
+        }
+    }
+
+    fn visit_ident_expr(&mut self, expr: Arc<Mutex<IdentExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.use_name(&expr.ident.lexeme);
+    }
+
+    fn visit_call_expr(&mut self, expr: Arc<Mutex<CallExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.callee.0.try_lock().unwrap().accept(self);
+
+        for arg in &expr.args {
+            arg.value.0.try_lock().unwrap().accept(self);
+        }
+    }
+
+    fn visit_unary_expr(&mut self, expr: Arc<Mutex<UnaryExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        let value = expr.value.0.try_lock().unwrap();
+
+        // Taking a `&mut` needs a `mut` variable, just like assigning to it
+        if let UnaryOp::Ref(RefType::Mut { .. }) = &expr.op {
+            if let Some(name) = root_ident(&value) {
+                self.reassign(&name);
+            }
+        }
+
+        value.accept(self);
+    }
+
+    fn visit_binary_expr(&mut self, expr: Arc<Mutex<BinaryExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.lhs.0.try_lock().unwrap().accept(self);
+        expr.rhs.0.try_lock().unwrap().accept(self);
+    }
+
+    fn visit_logical_expr(&mut self, expr: Arc<Mutex<LogicalExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.lhs.0.try_lock().unwrap().accept(self);
+        expr.rhs.0.try_lock().unwrap().accept(self);
+    }
+
+    fn visit_static_ref_expr(&mut self, expr: Arc<Mutex<StaticRefExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.use_static_path(&expr.static_path);
+    }
+
+    fn visit_construct_expr(&mut self, expr: Arc<Mutex<ConstructExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        match &expr.target {
+            ConstructTarget::Ident(ident) => self.use_name(&ident.try_lock().unwrap().ident.lexeme),
+            ConstructTarget::StaticPath(path) => self.use_static_path(path),
+        }
+
+        for arg in &expr.args {
+            match arg {
+                ConstructArg::Field(field) => field.value.0.try_lock().unwrap().accept(self),
+            }
+        }
+    }
+
+    fn visit_get_expr(&mut self, expr: Arc<Mutex<GetExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        let target = expr.target.0.try_lock().unwrap();
+
+        // Calling a `&mut self` method needs a `mut` variable, just like assigning to it
+        let method = target.resolved_type().and_then(|typ| {
+            let instance = typ.instance()?;
+
+            if instance.fields.contains_key(&expr.name.lexeme) {
+                return None;
+            }
+
+            return instance.methods.get(&expr.name.lexeme);
+        });
+
+        if let Some(FeMethod {
+            receiver: Some(FeReceiver::Ref(FeRefType::Mut)),
+            ..
+        }) = method
+        {
+            if let Some(name) = root_ident(&target) {
+                self.reassign(&name);
+            }
+        }
+
+        target.accept(self);
+    }
+
+    fn visit_if_expr(&mut self, expr: Arc<Mutex<IfExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.condition.0.try_lock().unwrap().accept(self);
+        self.check_if_condition(&expr.condition);
+
+        match &expr.then {
+            IfExprThen::Ternary(then) => then.then_expr.0.try_lock().unwrap().accept(self),
+            IfExprThen::Block(then) => self.labeled_block(&then.label, LabelKind::If, &then.block),
+        }
+
+        for else_if in &expr.else_ifs {
+            match else_if {
+                IfExprElseIf::Ternary(else_if) => {
+                    else_if.condition.0.try_lock().unwrap().accept(self);
+                    self.check_if_condition(&else_if.condition);
+                    else_if.expr.0.try_lock().unwrap().accept(self);
+                }
+                IfExprElseIf::Block(else_if) => {
+                    else_if.condition.0.try_lock().unwrap().accept(self);
+                    self.check_if_condition(&else_if.condition);
+                    self.labeled_block(&else_if.label, LabelKind::If, &else_if.block);
+                }
+            }
+        }
+
+        match &expr.else_ {
+            Some(IfExprElse::Ternary(else_)) => else_.else_expr.0.try_lock().unwrap().accept(self),
+            Some(IfExprElse::Block(else_)) => {
+                self.labeled_block(&else_.label, LabelKind::If, &else_.block)
+            }
+            None => {}
+        }
+    }
+
+    fn visit_match_expr(&mut self, expr: Arc<Mutex<MatchExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.value.0.try_lock().unwrap().accept(self);
+
+        self.begin_label(&expr.label, LabelKind::If);
+
+        for arm in &expr.arms {
+            self.begin_scope();
+
+            self.use_pattern(&arm.pattern);
+            for name in arm.pattern.bindings() {
+                self.declare(name, None);
+            }
+
+            if let Some(guard) = &arm.guard {
+                guard.condition.0.try_lock().unwrap().accept(self);
+            }
+
+            match &arm.body {
+                MatchArmBody::Ternary(ternary) => {
+                    ternary.then_expr.0.try_lock().unwrap().accept(self)
+                }
+                MatchArmBody::Block(block) => self.block(block),
+            }
+
+            self.end_scope();
+        }
+
+        self.end_label(&expr.label);
+    }
+
+    fn visit_loop_expr(&mut self, expr: Arc<Mutex<LoopExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.labeled_block(&expr.label, LabelKind::Loop, &expr.block);
+    }
+
+    fn visit_while_expr(&mut self, expr: Arc<Mutex<WhileExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.condition.0.try_lock().unwrap().accept(self);
+        self.check_while_condition(
+            &expr.while_token,
+            &expr.label,
+            &expr.condition,
+            expr.else_.is_some(),
+        );
+        self.labeled_block(&expr.label, LabelKind::Loop, &expr.block);
+
+        match &expr.then {
+            Some(WhileExprThen::Ternary(then)) => then.then_expr.0.try_lock().unwrap().accept(self),
+            Some(WhileExprThen::Block(then)) => self.block(&then.block),
+            None => {}
+        }
+
+        match &expr.else_ {
+            Some(WhileExprElse::Ternary(else_)) => {
+                else_.else_expr.0.try_lock().unwrap().accept(self)
+            }
+            Some(WhileExprElse::Block(else_)) => self.block(&else_.block),
+            None => {}
+        }
+    }
+
+    fn visit_for_expr(&mut self, expr: Arc<Mutex<ForExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        expr.iter.0.try_lock().unwrap().accept(self);
+        self.for_block(&expr.label, &expr.name, &expr.block);
+
+        expr.else_.else_expr.0.try_lock().unwrap().accept(self);
+    }
+}
+
+/// The variable an expression like `a.b.c` reads from, `a`.
+pub(super) fn root_ident(expr: &Expr<FeType>) -> Option<Arc<str>> {
+    match expr {
+        Expr::Ident(ident) => return Some(ident.try_lock().unwrap().ident.lexeme.clone()),
+        Expr::Get(get) => return root_ident(&get.try_lock().unwrap().target.0.try_lock().unwrap()),
+        _ => return None,
+    }
+}
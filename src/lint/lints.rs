@@ -0,0 +1,139 @@
+use crate::diagnostics::{ErrorCode, Severity};
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// A check for code that compiles, but is probably a mistake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedVariables,
+    UnusedImports,
+    UnusedLabels,
+    UnusedMut,
+    ShadowedNames,
+    UnreachableCode,
+    ConstantConditions,
+    WhileTrue,
+}
+
+impl Lint {
+    pub const ALL: &'static [Lint] = &[
+        Self::UnusedVariables,
+        Self::UnusedImports,
+        Self::UnusedLabels,
+        Self::UnusedMut,
+        Self::ShadowedNames,
+        Self::UnreachableCode,
+        Self::ConstantConditions,
+        Self::WhileTrue,
+    ];
+
+    /// The name used in `@allow(...)` attributes and on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnusedVariables => return "unused_variables",
+            Self::UnusedImports => return "unused_imports",
+            Self::UnusedLabels => return "unused_labels",
+            Self::UnusedMut => return "unused_mut",
+            Self::ShadowedNames => return "shadowed_names",
+            Self::UnreachableCode => return "unreachable_code",
+            Self::ConstantConditions => return "constant_conditions",
+            Self::WhileTrue => return "while_true",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        return Self::ALL.iter().find(|lint| lint.name() == name).copied();
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UnusedVariables => return ErrorCode::UNUSED_VARIABLE,
+            Self::UnusedImports => return ErrorCode::UNUSED_IMPORT,
+            Self::UnusedLabels => return ErrorCode::UNUSED_LABEL,
+            Self::UnusedMut => return ErrorCode::UNUSED_MUT,
+            Self::ShadowedNames => return ErrorCode::SHADOWED_NAME,
+            Self::UnreachableCode => return ErrorCode::UNREACHABLE_CODE,
+            Self::ConstantConditions => return ErrorCode::CONSTANT_CONDITION,
+            Self::WhileTrue => return ErrorCode::WHILE_TRUE,
+        }
+    }
+
+    pub fn default_level(&self) -> LintLevel {
+        return LintLevel::Warn;
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.name());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// Not reported.
+    Allow,
+
+    /// Reported as a warning, compilation continues.
+    Warn,
+
+    /// Reported as an error, compilation stops.
+    Deny,
+}
+
+impl LintLevel {
+    /// The level set by an attribute, e.g. `deny` in `@deny(unused_mut)`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => return Some(Self::Allow),
+            "warn" => return Some(Self::Warn),
+            "deny" => return Some(Self::Deny),
+            _ => return None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Allow => return "allow",
+            Self::Warn => return "warn",
+            Self::Deny => return "deny",
+        }
+    }
+
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::Allow => return None,
+            Self::Warn => return Some(Severity::Warning),
+            Self::Deny => return Some(Severity::Error),
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.name());
+    }
+}
+
+/// The level of every lint, falling back to [`Lint::default_level`] for any not set.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LintLevels(HashMap<Lint, LintLevel>);
+
+impl LintLevels {
+    pub fn get(&self, lint: Lint) -> LintLevel {
+        return self
+            .0
+            .get(&lint)
+            .copied()
+            .unwrap_or_else(|| lint.default_level());
+    }
+
+    pub fn set(&mut self, lint: Lint, level: LintLevel) {
+        self.0.insert(lint, level);
+    }
+
+    pub fn is_default(&self, lint: Lint) -> bool {
+        return !self.0.contains_key(&lint);
+    }
+}
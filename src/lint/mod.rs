@@ -0,0 +1,691 @@
+mod decl;
+mod expr;
+mod lints;
+mod stmt;
+mod r#use;
+
+pub use lints::*;
+
+use crate::config::Config;
+use crate::diagnostics::{Diagnostic, DiagnosticSuggestion, Diagnostics, ErrorCode};
+use crate::r#type::{FeMethod, FeReceiver, FeRefType, FeType};
+use crate::syntax::*;
+
+use crate::result::Result;
+use crate::token::{Span, Token};
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// Checks a resolved package for code that compiles but is probably a mistake.
+///
+/// Each [`Lint`] is reported at the level set for the project in [`Config::lint_levels`],
+/// unless the declaration it's found in overrides it with `@allow(...)`, `@warn(...)`
+/// or `@deny(...)`.
+pub struct FeLinter {
+    cfg: Arc<Config>,
+
+    current_file: PathBuf,
+    levels: LintLevels,
+    diagnostics: Vec<Diagnostic>,
+
+    /// Names brought into the current file with a (non `pub`) `use`
+    imports: Vec<Binding>,
+
+    /// Variables and params, innermost scope last
+    scopes: Vec<Vec<Binding>>,
+
+    /// Labels of the enclosing blocks, innermost last
+    labels: Vec<LabelBinding>,
+}
+
+#[derive(Debug, Clone)]
+struct Binding {
+    name: Arc<Token>,
+    mut_token: Option<Arc<Token>>,
+    is_used: bool,
+    is_reassigned: bool,
+}
+
+impl Binding {
+    fn new(name: Arc<Token>, mut_token: Option<Arc<Token>>) -> Self {
+        return Self {
+            name,
+            mut_token,
+            is_used: false,
+            is_reassigned: false,
+        };
+    }
+
+    fn is_exempt(&self) -> bool {
+        return self.name.lexeme.starts_with('_');
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LabelKind {
+    /// Targeted by `break'label`
+    Loop,
+
+    /// Targeted by `then'label`
+    If,
+}
+
+#[derive(Debug, Clone)]
+struct LabelBinding {
+    label: Arc<Token>,
+    kind: LabelKind,
+    is_used: bool,
+}
+
+impl FeLinter {
+    /// Returns the warnings found, or every diagnostic if any lint is denied.
+    pub fn lint_package(
+        cfg: Arc<Config>,
+        pkg: Arc<Mutex<FeSyntaxPackage<FeType>>>,
+    ) -> Result<Vec<Diagnostic>> {
+        let mut this = Self {
+            levels: cfg.lint_levels.clone(),
+            cfg,
+
+            current_file: PathBuf::new(),
+            diagnostics: vec![],
+
+            imports: vec![],
+            scopes: vec![],
+            labels: vec![],
+        };
+
+        this.lint_pkg(&pkg.try_lock().unwrap());
+
+        let mut diagnostics = this.diagnostics;
+        diagnostics.sort_by_key(|d| {
+            let start = d
+                .primary
+                .as_ref()
+                .map(|p| (p.span.start.line, p.span.start.column));
+            return (d.file.clone(), start);
+        });
+
+        if diagnostics.iter().any(Diagnostic::is_error) {
+            return Err(Diagnostics(diagnostics).into());
+        }
+
+        return Ok(diagnostics);
+    }
+
+    fn lint_pkg(&mut self, pkg: &FeSyntaxPackage<FeType>) {
+        match pkg {
+            FeSyntaxPackage::File(file) => self.lint_file(file),
+            FeSyntaxPackage::Dir(dir) => {
+                self.lint_file(&dir.entry_file);
+
+                for pkg in dir.local_packages.values() {
+                    self.lint_pkg(&pkg.try_lock().unwrap());
+                }
+            }
+        }
+    }
+
+    fn lint_file(&mut self, file: &FeSyntaxFile<FeType>) {
+        self.current_file = file.path.clone();
+        self.imports.clear();
+
+        let syntax = file.syntax.try_lock().unwrap();
+
+        for use_decl in &syntax.uses {
+            use_decl.accept(self);
+        }
+
+        for decl in &syntax.decls {
+            decl.try_lock().unwrap().accept(self);
+        }
+
+        self.levels = self.cfg.lint_levels.clone();
+
+        for import in std::mem::take(&mut self.imports) {
+            if import.is_used || import.is_exempt() {
+                continue;
+            }
+
+            self.report(
+                Lint::UnusedImports,
+                format!("Unused import '{}'", import.name.lexeme),
+                |d| d.with_span(import.name.span.clone()),
+            );
+        }
+    }
+
+    /// Applies the `@allow(...)`, `@warn(...)` and `@deny(...)` attributes of a
+    /// declaration on top of the project's levels.
+    fn enter_decl(&mut self, attrs: &[DeclAttr]) {
+        self.levels = self.cfg.lint_levels.clone();
+
+        for attr in attrs {
+            let Some(level) = LintLevel::from_name(&attr.name.lexeme) else {
+                self.diagnostics.push(
+                    Diagnostic::error(format!("Unknown attribute '@{}'", attr.name.lexeme))
+                        .with_code(ErrorCode::UNKNOWN_ATTRIBUTE)
+                        .with_file(self.current_file.clone())
+                        .with_span(attr.name.span.clone())
+                        .with_note("Supported attributes are '@allow', '@warn' and '@deny'"),
+                );
+
+                continue;
+            };
+
+            for arg in &attr.args {
+                let Some(lint) = Lint::from_name(&arg.name.lexeme) else {
+                    self.diagnostics.push(
+                        Diagnostic::warning(format!("Unknown lint '{}'", arg.name.lexeme))
+                            .with_code(ErrorCode::UNKNOWN_LINT)
+                            .with_file(self.current_file.clone())
+                            .with_span(arg.name.span.clone()),
+                    );
+
+                    continue;
+                };
+
+                self.levels.set(lint, level);
+            }
+        }
+    }
+
+    fn begin_scope(&mut self) {
+        self.scopes.push(vec![]);
+    }
+
+    fn end_scope(&mut self) {
+        let Some(scope) = self.scopes.pop() else {
+            return;
+        };
+
+        for binding in scope {
+            if !binding.is_used && !binding.is_exempt() {
+                self.report(
+                    Lint::UnusedVariables,
+                    format!("Unused variable '{}'", binding.name.lexeme),
+                    |d| {
+                        d.with_span(binding.name.span.clone()).with_suggestion(
+                            DiagnosticSuggestion::new(
+                                "if this is intentional, prefix it with an underscore",
+                                binding.name.span.clone(),
+                                format!("_{}", binding.name.lexeme),
+                            )
+                            .maybe_incorrect(),
+                        )
+                    },
+                );
+            }
+
+            if let (Some(mut_token), false) = (&binding.mut_token, binding.is_reassigned) {
+                self.report(
+                    Lint::UnusedMut,
+                    format!(
+                        "Variable '{}' is declared 'mut' but never reassigned",
+                        binding.name.lexeme
+                    ),
+                    |d| {
+                        d.with_span(mut_token.span.clone()).with_suggestion(
+                            DiagnosticSuggestion::new(
+                                "declare it with 'const'",
+                                mut_token.span.clone(),
+                                "const",
+                            ),
+                        )
+                    },
+                );
+            }
+        }
+    }
+
+    /// Adds a variable or param to the innermost scope.
+    fn declare(&mut self, name: Arc<Token>, mut_token: Option<Arc<Token>>) {
+        let shadowed = self
+            .scopes
+            .iter()
+            .flatten()
+            .rev()
+            .find(|binding| binding.name.lexeme == name.lexeme)
+            .map(|binding| binding.name.span.clone());
+
+        if let (Some(shadowed), false) = (shadowed, name.lexeme.starts_with('_')) {
+            self.report(
+                Lint::ShadowedNames,
+                format!("'{}' shadows an earlier variable", name.lexeme),
+                |d| {
+                    d.with_span(name.span.clone())
+                        .with_secondary(shadowed, "previously declared here")
+                },
+            );
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(Binding::new(name, mut_token));
+        }
+    }
+
+    /// Finds the variable a name refers to, falling back to the file's imports.
+    fn lookup(&mut self, name: &str) -> Option<&mut Binding> {
+        let is_local = self
+            .scopes
+            .iter()
+            .flatten()
+            .any(|binding| &*binding.name.lexeme == name);
+
+        if !is_local {
+            return self.lookup_import(name);
+        }
+
+        return self
+            .scopes
+            .iter_mut()
+            .flatten()
+            .rev()
+            .find(|binding| &*binding.name.lexeme == name);
+    }
+
+    fn lookup_import(&mut self, name: &str) -> Option<&mut Binding> {
+        return self
+            .imports
+            .iter_mut()
+            .rev()
+            .find(|import| &*import.name.lexeme == name);
+    }
+
+    fn use_name(&mut self, name: &str) {
+        if let Some(binding) = self.lookup(name) {
+            binding.is_used = true;
+        }
+    }
+
+    fn use_static_path(&mut self, path: &StaticPath<FeType>) {
+        let mut root = path;
+        while let Some(next) = &root.root {
+            root = next;
+        }
+
+        // `::name` skips local variables and goes straight to the file's scope
+        let binding = if root.double_colon_token.is_some() {
+            self.lookup_import(&root.name.lexeme)
+        } else {
+            self.lookup(&root.name.lexeme)
+        };
+
+        if let Some(binding) = binding {
+            binding.is_used = true;
+        }
+    }
+
+    fn use_static_type(&mut self, static_type: &StaticType<FeType>) {
+        self.use_static_path(&static_type.static_path);
+    }
+
+    fn reassign(&mut self, name: &str) {
+        if let Some(binding) = self.lookup(name) {
+            binding.is_reassigned = true;
+        }
+    }
+
+    fn begin_label(&mut self, label: &Option<Arc<Token>>, kind: LabelKind) {
+        if let Some(label) = label {
+            self.labels.push(LabelBinding {
+                label: label.clone(),
+                kind,
+                is_used: false,
+            });
+        }
+    }
+
+    fn end_label(&mut self, label: &Option<Arc<Token>>) {
+        if label.is_none() {
+            return;
+        }
+
+        let Some(binding) = self.labels.pop() else {
+            return;
+        };
+
+        if binding.is_used {
+            return;
+        }
+
+        self.report(
+            Lint::UnusedLabels,
+            format!("Unused label {}", binding.label.lexeme),
+            |d| d.with_span(binding.label.span.clone()),
+        );
+    }
+
+    fn use_label(&mut self, label: &Option<Arc<Token>>, kind: LabelKind) {
+        let Some(label) = label else {
+            return;
+        };
+
+        let found = self
+            .labels
+            .iter_mut()
+            .rev()
+            .find(|binding| binding.kind == kind && binding.label.lexeme == label.lexeme);
+
+        if let Some(found) = found {
+            found.is_used = true;
+        }
+    }
+
+    fn block<S: PartialEq>(&mut self, block: &CodeBlock<FeType, S>) {
+        self.begin_scope();
+
+        let mut terminal: Option<Span> = None;
+        let mut reported_unreachable = false;
+
+        for stmt in &block.stmts {
+            let stmt = &mut *stmt.try_lock().unwrap();
+
+            if let (Some(terminal), false) = (&terminal, reported_unreachable) {
+                reported_unreachable = true;
+
+                self.report(Lint::UnreachableCode, "Unreachable code", |d| {
+                    d.with_span(stmt.span()).with_secondary(
+                        terminal.clone(),
+                        "any code following this statement is unreachable",
+                    )
+                });
+            }
+
+            stmt.accept(self);
+
+            if terminal.is_none() && stmt.is_terminal() {
+                terminal = Some(stmt.span());
+            }
+        }
+
+        self.end_scope();
+    }
+
+    fn labeled_block<S: PartialEq>(
+        &mut self,
+        label: &Option<Arc<Token>>,
+        kind: LabelKind,
+        block: &CodeBlock<FeType, S>,
+    ) {
+        self.begin_label(label, kind);
+        self.block(block);
+        self.end_label(label);
+    }
+
+    /// A `for` loop's block, in a scope holding the name each value is bound to.
+    fn for_block<S: PartialEq>(
+        &mut self,
+        label: &Option<Arc<Token>>,
+        name: &Arc<Token>,
+        block: &CodeBlock<FeType, S>,
+    ) {
+        self.begin_label(label, LabelKind::Loop);
+        self.begin_scope();
+
+        self.declare(name.clone(), None);
+        self.block(block);
+
+        self.end_scope();
+        self.end_label(label);
+    }
+
+    /// Marks the names a pattern's paths start with as used.
+    fn use_pattern(&mut self, pattern: &Pattern<FeType>) {
+        match pattern {
+            Pattern::Wildcard(_) | Pattern::Literal(_) | Pattern::Binding(_) => {}
+            Pattern::Struct(pattern) => {
+                self.use_static_path(&pattern.path);
+
+                for field in &pattern.fields {
+                    self.use_pattern(&field.pattern);
+                }
+            }
+            Pattern::Variant(pattern) => {
+                self.use_static_path(&pattern.path);
+
+                for field in pattern.fields.iter().flat_map(|fields| &fields.fields) {
+                    self.use_pattern(&field.pattern);
+                }
+            }
+        }
+    }
+
+    /// The value of a condition known at compile time, like `true` or `1 > 2`.
+    fn known_condition(condition: &NestedExpr<FeType>) -> Option<bool> {
+        let resolved_type = condition.0.try_lock().unwrap().resolved_type()?;
+
+        match resolved_type.actual_type() {
+            FeType::Bool(Some(value)) => return Some(*value),
+            _ => return None,
+        }
+    }
+
+    fn check_if_condition(&mut self, condition: &NestedExpr<FeType>) {
+        let Some(value) = Self::known_condition(condition) else {
+            return;
+        };
+
+        let span = condition.0.try_lock().unwrap().span();
+
+        self.report(
+            Lint::ConstantConditions,
+            format!("This condition is always {value}"),
+            |d| d.with_span(span),
+        );
+    }
+
+    /// Reports a `while` that never runs, or one that's really a `loop`.
+    fn check_while_condition(
+        &mut self,
+        while_token: &Arc<Token>,
+        label: &Option<Arc<Token>>,
+        condition: &NestedExpr<FeType>,
+        has_else: bool,
+    ) {
+        let Some(value) = Self::known_condition(condition) else {
+            return;
+        };
+
+        let span = condition.0.try_lock().unwrap().span();
+
+        if !value {
+            self.report(
+                Lint::ConstantConditions,
+                "This condition is always false",
+                |d| d.with_span(span).with_note("the loop never runs"),
+            );
+
+            return;
+        }
+
+        let header = while_token.span.to(&span);
+        let label = label.as_ref().map(|l| l.lexeme.clone()).unwrap_or_default();
+
+        // The `else` of a `while` that never ends is dropped along with the condition
+        let mut suggestion =
+            DiagnosticSuggestion::new("use 'loop'", header.clone(), format!("loop{label}"));
+        if has_else {
+            suggestion = suggestion.maybe_incorrect();
+        }
+
+        self.report(Lint::WhileTrue, "Denote infinite loops with 'loop'", |d| {
+            d.with_span(header).with_suggestion(suggestion)
+        });
+    }
+
+    /// Reports a lint at its current level, unless it's allowed.
+    fn report(
+        &mut self,
+        lint: Lint,
+        message: impl Into<Arc<str>>,
+        build: impl FnOnce(Diagnostic) -> Diagnostic,
+    ) {
+        let Some(severity) = self.levels.get(lint).severity() else {
+            return;
+        };
+
+        let mut diagnostic = Diagnostic::new(severity, message)
+            .with_code(lint.code())
+            .with_file(self.current_file.clone());
+
+        if self.levels.is_default(lint) {
+            diagnostic = diagnostic.with_note(format!(
+                "'@{}({lint})' is on by default",
+                lint.default_level()
+            ));
+        }
+
+        self.diagnostics.push(build(diagnostic));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::lexer::FeLexer;
+    use crate::parser::FeSyntaxParser;
+    use crate::source::{FeSourceFile, FeSourcePackage, SourcePackageName};
+    use crate::type_resolver::FeTypeResolver;
+
+    fn lint(cfg: Config, code: &str) -> Result<Vec<Diagnostic>> {
+        let cfg = Arc::new(cfg);
+
+        let source = FeSourcePackage::File(FeSourceFile {
+            name: SourcePackageName("_main".into()),
+            path: "./_main.fe".into(),
+            content: code.into(),
+        });
+
+        let tokens = FeLexer::scan_package(cfg.clone(), Arc::new(Mutex::new(source)))?;
+        let pkg = FeSyntaxParser::parse_package(cfg.clone(), Arc::new(Mutex::new(tokens)))?;
+        let pkg = FeTypeResolver::resolve_package(cfg.clone(), pkg)?;
+
+        return FeLinter::lint_package(cfg, Arc::new(Mutex::new(pkg)));
+    }
+
+    fn codes(diagnostics: &[Diagnostic]) -> Vec<(&str, usize)> {
+        return diagnostics
+            .iter()
+            .map(|d| {
+                let line = d.primary.as_ref().unwrap().span.start.line;
+                return (d.code.as_deref().unwrap(), line);
+            })
+            .collect();
+    }
+
+    const CODE: &str = "use ::fe::print
+
+fn run(count: Int, _ignored: Int)
+    mut total = 0
+    mut steps = 0
+    steps += 1
+
+    while'outer true
+        const count = 1
+    ;
+;
+";
+
+    #[test]
+    fn test_lints() -> Result {
+        let diagnostics = lint(Config::default(), CODE)?;
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec![
+                ("FE0041", 1), // print
+                ("FE0040", 3), // count
+                ("FE0043", 4), // mut total
+                ("FE0040", 4), // total
+                ("FE0040", 5), // steps
+                ("FE0050", 8), // while true
+                ("FE0042", 8), // 'outer
+                ("FE0044", 9), // shadowing count
+                ("FE0040", 9),
+            ]
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_lint_levels() -> Result {
+        let cfg = Config::builder()
+            .lint_level(Lint::UnusedVariables, LintLevel::Allow)
+            .lint_level(Lint::UnusedImports, LintLevel::Deny)
+            .build();
+
+        let code =
+            format!("@allow(unused_mut, shadowed_names, while_true)\n@deny(unused_labels)\n{CODE}");
+        let code = code.replace("use ::fe::print\n", "");
+
+        let err = lint(cfg, &format!("use ::fe::print\n{code}")).unwrap_err();
+        let Some(Diagnostics(diagnostics)) = Diagnostics::from_error(&err) else {
+            panic!("expected diagnostics, found {err}");
+        };
+
+        assert_eq!(codes(&diagnostics), vec![("FE0041", 1), ("FE0042", 10)]);
+        assert!(diagnostics.iter().all(Diagnostic::is_error));
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_control_flow_lints() -> Result {
+        let code = "use ::fe::print
+
+fn run(count: Int)
+    if 1 > 2
+        print(\"never\")
+    else if count > 0
+        return
+        print(\"{count}\")
+        print(\"{count}\")
+    ;
+
+    while false
+        print(\"never\")
+    ;
+;
+";
+
+        let diagnostics = lint(Config::default(), code)?;
+
+        assert_eq!(
+            codes(&diagnostics),
+            vec![
+                ("FE0049", 4),  // 1 > 2
+                ("FE0006", 8),  // only the first unreachable statement
+                ("FE0049", 12), // while false
+            ]
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_field_writes_use_the_variable() -> Result {
+        let code = "struct Point {
+    x: Int,
+}
+
+fn bump(p: &mut Point)
+    p.x += 1
+;
+
+fn reset(unused: Int)
+    mut q = Point { x: 0 }
+    q.x = 1
+;
+";
+
+        let diagnostics = lint(Config::default(), code)?;
+
+        assert_eq!(codes(&diagnostics), vec![("FE0040", 9)]); // unused
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,140 @@
+use super::*;
+
+use super::expr::root_ident;
+
+impl StmtVisitor<FeType> for FeLinter {
+    fn visit_expr_stmt(&mut self, stmt: Arc<Mutex<ExprStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        stmt.expr.try_lock().unwrap().accept(self);
+    }
+
+    fn visit_var_decl_stmt(&mut self, stmt: Arc<Mutex<VarDeclStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        if let Some(explicit_type) = &stmt.explicit_type {
+            self.use_static_type(&explicit_type.static_type);
+        }
+
+        // The value can't see the variable it's assigned to
+        if let Some(value) = &stmt.value {
+            value.value.0.try_lock().unwrap().accept(self);
+        }
+
+        let mut_token = match &stmt.var_mut {
+            VarDeclMut::Const(_) => None,
+            VarDeclMut::Mut(token) => Some(token.clone()),
+        };
+
+        match &stmt.target {
+            VarDeclTarget::Ident(ident) => {
+                let name = ident.try_lock().unwrap().ident.clone();
+
+                self.declare(name, mut_token);
+            }
+        }
+    }
+
+    fn visit_assign_stmt(&mut self, stmt: Arc<Mutex<AssignStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        stmt.value.0.try_lock().unwrap().accept(self);
+
+        // Writing to a variable (or one of its fields) isn't reading it
+        self.assign_target(&stmt.target.0.try_lock().unwrap());
+    }
+
+    fn visit_return_stmt(&mut self, stmt: Arc<Mutex<ReturnStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        if let Some(value) = &stmt.value {
+            value.0.try_lock().unwrap().accept(self);
+        }
+    }
+
+    fn visit_if_stmt(&mut self, stmt: Arc<Mutex<IfStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        stmt.condition.0.try_lock().unwrap().accept(self);
+        self.check_if_condition(&stmt.condition);
+        self.block(&stmt.then);
+
+        for else_if in &stmt.else_ifs {
+            else_if.condition.0.try_lock().unwrap().accept(self);
+            self.check_if_condition(&else_if.condition);
+            self.block(&else_if.then);
+        }
+
+        if let Some(else_) = &stmt.else_ {
+            self.block(&else_.then);
+        }
+    }
+
+    fn visit_loop_stmt(&mut self, stmt: Arc<Mutex<LoopStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        self.labeled_block(&stmt.label, LabelKind::Loop, &stmt.block);
+    }
+
+    fn visit_while_stmt(&mut self, stmt: Arc<Mutex<WhileStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        stmt.condition.0.try_lock().unwrap().accept(self);
+        self.check_while_condition(
+            &stmt.while_token,
+            &stmt.label,
+            &stmt.condition,
+            stmt.else_.is_some(),
+        );
+        self.labeled_block(&stmt.label, LabelKind::Loop, &stmt.block);
+
+        if let Some(else_) = &stmt.else_ {
+            self.block(&else_.block);
+        }
+    }
+
+    fn visit_for_stmt(&mut self, stmt: Arc<Mutex<ForStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        stmt.iter.0.try_lock().unwrap().accept(self);
+        self.for_block(&stmt.label, &stmt.name, &stmt.block);
+    }
+
+    fn visit_break_stmt(&mut self, stmt: Arc<Mutex<BreakStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        self.use_label(&stmt.label, LabelKind::Loop);
+
+        if let Some(value) = &stmt.value {
+            value.0.try_lock().unwrap().accept(self);
+        }
+    }
+
+    fn visit_then_stmt(&mut self, stmt: Arc<Mutex<ThenStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        self.use_label(&stmt.label, LabelKind::If);
+
+        stmt.value.0.try_lock().unwrap().accept(self);
+    }
+}
+
+impl FeLinter {
+    fn assign_target(&mut self, target: &Expr<FeType>) {
+        match target {
+            Expr::Ident(ident) => self.reassign(&ident.try_lock().unwrap().ident.lexeme),
+            Expr::Get(get) => {
+                let target = get.try_lock().unwrap().target.clone();
+                let target = target.0.try_lock().unwrap();
+
+                // Writing to a field uses the variable it's in, like the `p` of `p.x += 1`
+                if let Some(name) = root_ident(&target) {
+                    self.use_name(&name);
+                }
+
+                self.assign_target(&target);
+            }
+            _ => target.accept(self),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use super::*;
+
+impl UseVisitor<FeType> for FeLinter {
+    fn visit_use(&mut self, use_decl: Arc<Mutex<Use<FeType>>>) {
+        let use_decl = &*use_decl.try_lock().unwrap();
+
+        // A path continuing from a name in the file's scope uses it, e.g. `fe` in `use fe::print`
+        if let (None | Some(UseStaticPathPre::DoubleColon(_)), Either::A(_)) =
+            (&use_decl.path.pre, &use_decl.path.details)
+        {
+            if let Some(import) = self.lookup_import(&use_decl.path.name.lexeme) {
+                import.is_used = true;
+            }
+        }
+
+        // Re-exports are used by other packages
+        if let Some(UseMod::Pub(_)) = use_decl.use_mod {
+            return;
+        }
+
+        let mut names = vec![];
+        imported_names(&use_decl.path, &mut names);
+
+        self.imports
+            .extend(names.into_iter().map(|name| Binding::new(name, None)));
+    }
+}
+
+/// The names a `use` adds to the file's scope, the last segment of each path.
+fn imported_names(path: &UseStaticPath<FeType>, names: &mut Vec<Arc<Token>>) {
+    match &path.details {
+        Either::A(UseStaticPathNext::Single(next)) => imported_names(&next.path, names),
+        Either::A(UseStaticPathNext::Many(nexts)) => {
+            for next in &nexts.nexts {
+                imported_names(&next.path, names);
+            }
+        }
+        Either::B(_) => names.push(path.name.clone()),
+    }
+}
@@ -1,13 +1,12 @@
 use crate::config::Config;
-use crate::log;
+use crate::diagnostics::{Diagnostic, Diagnostics, ErrorCode};
 use crate::result::Result;
 use crate::syntax::*;
 use crate::token::*;
 
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-use thiserror::Error;
-
 #[derive(Debug, Clone)]
 pub struct FeSyntaxParser {
     #[allow(unused)]
@@ -44,26 +43,42 @@ impl FeSyntaxParser {
         };
     }
 
-    pub fn parse(mut self) -> Result<FeSyntaxPackage> {
+    pub fn parse(self) -> Result<FeSyntaxPackage> {
+        let (pkg, errors) = self.parse_with_errors()?;
+
+        if !errors.is_empty() {
+            return Err(Diagnostics(errors).into());
+        }
+
+        return Ok(pkg);
+    }
+
+    /// Parses every file in the package, recovering from syntax errors where possible.
+    ///
+    /// Returns the (possibly partial) syntax package along with every syntax error found.
+    pub fn parse_with_errors(mut self) -> Result<(FeSyntaxPackage, Vec<Diagnostic>)> {
         fn _parse<'a>(
             token_pkg: &FeTokenPackage,
             syntax_pkg: &'a mut FeSyntaxPackage,
             node_id_gen: NodeIdGen,
+            errors: &mut Vec<Diagnostic>,
         ) -> Result<&'a mut FeSyntaxPackage> {
             match (token_pkg, &mut *syntax_pkg) {
                 (FeTokenPackage::File(token_file), FeSyntaxPackage::File(syntax_file)) => {
-                    FeTokenSyntaxParser::parse_syntax(
+                    errors.extend(FeTokenSyntaxParser::parse_syntax(
+                        token_file.path.clone(),
                         token_file.tokens.try_lock().unwrap().clone(),
                         syntax_file.syntax.clone(),
                         node_id_gen,
-                    )?;
+                    )?);
                 }
                 (FeTokenPackage::Dir(token_dir), FeSyntaxPackage::Dir(syntax_dir)) => {
-                    FeTokenSyntaxParser::parse_syntax(
+                    errors.extend(FeTokenSyntaxParser::parse_syntax(
+                        token_dir.entry_file.path.clone(),
                         token_dir.entry_file.tokens.try_lock().unwrap().clone(),
                         syntax_dir.entry_file.syntax.clone(),
                         node_id_gen.clone(),
-                    )?;
+                    )?);
 
                     for (name, token_pkg) in token_dir.local_packages.iter() {
                         let syntax_pkg = syntax_dir
@@ -75,6 +90,7 @@ impl FeSyntaxParser {
                             &token_pkg.try_lock().unwrap(),
                             &mut syntax_pkg.try_lock().unwrap(),
                             node_id_gen.clone(),
+                            errors,
                         )?;
                     }
                 }
@@ -85,29 +101,27 @@ impl FeSyntaxParser {
             return Ok(syntax_pkg);
         }
 
+        let mut errors = vec![];
+
         _parse(
             &self.token_pkg.try_lock().unwrap(),
             &mut self.out,
             self.node_id_gen.clone(),
+            &mut errors,
         )?;
 
-        return Ok(self.out);
+        return Ok((self.out, errors));
     }
 }
 
 struct FeTokenSyntaxParser {
+    path: PathBuf,
     tokens: Vec<Arc<Token>>,
     out: Arc<Mutex<SyntaxTree>>,
     node_id_gen: NodeIdGen,
 
     current_idx: usize,
-}
-
-// TODO: Improve error reporting
-#[derive(Error, Debug)]
-pub enum ParserError {
-    #[error("Error: {message}")]
-    Error { message: String },
+    errors: Vec<Diagnostic>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -119,46 +133,61 @@ pub enum WithNewlines {
 
 impl FeTokenSyntaxParser {
     fn parse_syntax(
+        path: PathBuf,
         tokens: Vec<Arc<Token>>,
         syntax_tree: Arc<Mutex<SyntaxTree>>,
         node_id_gen: NodeIdGen,
-    ) -> Result {
-        return Self::new(tokens, syntax_tree, node_id_gen).parse();
+    ) -> Result<Vec<Diagnostic>> {
+        return Self::new(path, tokens, syntax_tree, node_id_gen).parse();
     }
 
     fn new(
+        path: PathBuf,
         tokens: Vec<Arc<Token>>,
         syntax_tree: Arc<Mutex<SyntaxTree>>,
         node_id_gen: NodeIdGen,
     ) -> Self {
         return Self {
+            path,
             tokens,
             out: syntax_tree,
             node_id_gen,
 
             current_idx: 0,
+            errors: vec![],
         };
     }
 
-    fn parse(mut self) -> Result {
+    fn parse(mut self) -> Result<Vec<Diagnostic>> {
         while !self.is_at_end() {
             if self.allow_many_newlines() > 0 {
                 continue;
             }
 
-            match self.use_declaration() {
-                Ok(None) => break,
+            let start_idx = self.current_idx;
 
-                Ok(Some(use_decl)) => {
-                    self.out.try_lock().unwrap().uses.push(use_decl);
+            let res = self.use_declaration().and_then(|use_decl| {
+                let Some(use_decl) = use_decl else {
+                    return Ok(None);
+                };
 
-                    if !self.is_at_end() {
-                        self.consume(&TokenType::Newline, "Expect newline after use")?;
-                    }
+                self.out.try_lock().unwrap().uses.push(use_decl);
+
+                if !self.is_at_end() {
+                    self.consume(&TokenType::Newline, "Expect newline after use")?;
                 }
 
-                // TODO: Improve compiling around errors and error reporting
-                Err(e) => return Err(e),
+                return Ok(Some(()));
+            });
+
+            match res {
+                Ok(None) => break,
+                Ok(Some(())) => {}
+
+                Err(e) => {
+                    self.recover(e)?;
+                    self.synchronize_declaration(start_idx);
+                }
             }
         }
 
@@ -167,21 +196,25 @@ impl FeTokenSyntaxParser {
                 continue;
             }
 
-            match self.declaration() {
-                Ok(decl) => {
-                    self.out.try_lock().unwrap().decls.push(decl);
+            let start_idx = self.current_idx;
 
-                    if !self.is_at_end() {
-                        self.consume(&TokenType::Newline, "Expect newline after declaration")?;
-                    }
+            let res = self.declaration().and_then(|decl| {
+                self.out.try_lock().unwrap().decls.push(decl);
+
+                if !self.is_at_end() {
+                    self.consume(&TokenType::Newline, "Expect newline after declaration")?;
                 }
 
-                // TODO: Improve compiling around errors and error reporting
-                Err(e) => return Err(e),
+                return Ok(());
+            });
+
+            if let Err(e) = res {
+                self.recover(e)?;
+                self.synchronize_declaration(start_idx);
             }
         }
 
-        return Ok(());
+        return Ok(self.errors);
     }
 
     fn use_declaration(&mut self) -> Result<Option<Arc<Mutex<Use>>>> {
@@ -247,55 +280,131 @@ impl FeTokenSyntaxParser {
     }
 
     fn declaration(&mut self) -> Result<Arc<Mutex<Decl>>> {
+        let attrs = self.decl_attrs()?;
+
         let mut decl_mod = None;
 
         if let Some(token) = self.match_any(&[TokenType::Pub], WithNewlines::Many) {
             decl_mod = Some(DeclMod::Pub(token));
         }
 
-        {
-            let fn_mod = match self.peek().as_ref().map(|t| (t.clone(), &t.token_type)) {
-                Some((token, TokenType::Pure)) => Some(FnMod::Pure(token)),
-                Some((token, TokenType::Safe)) => Some(FnMod::Safe(token)),
-                Some((token, TokenType::Norm)) => Some(FnMod::Norm(token)),
-                Some((token, TokenType::Risk)) => Some(FnMod::Risk(token)),
-                _ => None,
-            };
+        if let Some((fn_mod, fn_token)) = self.fn_start()? {
+            return Ok(Arc::new(Mutex::new(Decl::Fn(Arc::new(Mutex::new(
+                self.fn_decl(attrs, decl_mod, fn_mod, fn_token, false)?,
+            ))))));
+        }
 
-            let fn_token = if fn_mod.is_some() {
-                let _ = self.advance();
+        if let Some(token) = self.match_any(&[TokenType::Struct], WithNewlines::Many) {
+            return Ok(Arc::new(Mutex::new(Decl::Struct(Arc::new(Mutex::new(
+                self.struct_decl(attrs, decl_mod, token)?,
+            ))))));
+        }
 
-                Some(self.consume(&TokenType::Fn, "Expect 'fn' after fn modifier")?)
-            } else {
-                self.match_any(&[TokenType::Fn], WithNewlines::Many)
-            };
+        if let Some(token) = self.match_any(&[TokenType::Enum], WithNewlines::Many) {
+            return Ok(Arc::new(Mutex::new(Decl::Enum(Arc::new(Mutex::new(
+                self.enum_decl(attrs, decl_mod, token)?,
+            ))))));
+        }
 
-            if let Some(fn_token) = fn_token {
-                return Ok(Arc::new(Mutex::new(Decl::Fn(Arc::new(Mutex::new(
-                    self.fn_decl(decl_mod, fn_mod, fn_token)?,
-                ))))));
+        if let Some(token) = self.match_any(&[TokenType::Impl], WithNewlines::Many) {
+            if let Some(DeclMod::Pub(pub_token)) = decl_mod {
+                return Err(self
+                    .error("'impl' can't be 'pub'".to_string(), pub_token)
+                    .with_note("Mark the methods inside it 'pub' instead")
+                    .into());
             }
-        }
 
-        if let Some(token) = self.match_any(&[TokenType::Struct], WithNewlines::Many) {
-            return Ok(Arc::new(Mutex::new(Decl::Struct(Arc::new(Mutex::new(
-                self.struct_decl(decl_mod, token)?,
+            return Ok(Arc::new(Mutex::new(Decl::Impl(Arc::new(Mutex::new(
+                self.impl_decl(attrs, token)?,
             ))))));
         }
 
-        todo!()
+        let t = self.peek().ok_or_else(|| self.eof_err())?;
+
+        return Err(self
+            .error(
+                format!(
+                    "Expected a declaration. Found '{}'",
+                    t.lexeme.escape_debug()
+                ),
+                t,
+            )
+            .into());
+    }
+
+    fn decl_attrs(&mut self) -> Result<Vec<DeclAttr>> {
+        let mut attrs = vec![];
+
+        while let Some(at_token) = self.match_any(&[TokenType::At], WithNewlines::Many) {
+            let name = self.consume(&TokenType::Ident, "Expect attribute name after '@'")?;
+
+            let open_paren_token =
+                self.consume(&TokenType::OpenParen, "Expect '(' after attribute name")?;
+
+            let mut args = vec![];
+            while let Some(name) = self.match_any(&[TokenType::Ident], WithNewlines::Many) {
+                let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+                let is_done = comma_token.is_none();
+
+                args.push(DeclAttrArg { name, comma_token });
+
+                if is_done {
+                    break;
+                }
+            }
+
+            self.allow_many_newlines();
+
+            let close_paren_token = self.consume(
+                &TokenType::CloseParen,
+                "Expect ')' after attribute arguments",
+            )?;
+
+            attrs.push(DeclAttr {
+                at_token,
+                name,
+                open_paren_token,
+                args,
+                close_paren_token,
+            });
+        }
+
+        return Ok(attrs);
+    }
+
+    /// The optional modifier and the 'fn' that start a function.
+    fn fn_start(&mut self) -> Result<Option<(Option<FnMod>, Arc<Token>)>> {
+        let fn_mod = match self.peek().as_ref().map(|t| (t.clone(), &t.token_type)) {
+            Some((token, TokenType::Pure)) => Some(FnMod::Pure(token)),
+            Some((token, TokenType::Safe)) => Some(FnMod::Safe(token)),
+            Some((token, TokenType::Norm)) => Some(FnMod::Norm(token)),
+            Some((token, TokenType::Risk)) => Some(FnMod::Risk(token)),
+            _ => None,
+        };
+
+        let fn_token = if fn_mod.is_some() {
+            let _ = self.advance();
+
+            Some(self.consume(&TokenType::Fn, "Expect 'fn' after fn modifier")?)
+        } else {
+            self.match_any(&[TokenType::Fn], WithNewlines::Many)
+        };
+
+        return Ok(fn_token.map(|fn_token| (fn_mod, fn_token)));
     }
 
     fn fn_decl(
         &mut self,
+        attrs: Vec<DeclAttr>,
         decl_mod: Option<DeclMod>,
         fn_mod: Option<FnMod>,
         fn_token: Arc<Token>,
+        is_method: bool,
     ) -> Result<FnDecl> {
-        // TODO: Generics?
-
         let name = self.consume(&TokenType::Ident, "Expect function name")?;
 
+        let generics = self.decl_generics()?;
+
         let open_paren_token =
             self.consume(&TokenType::OpenParen, "Expect '(' after function name")?;
 
@@ -303,7 +412,26 @@ impl FeTokenSyntaxParser {
 
         let pre_comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
 
-        while self.check(&TokenType::Ident) {
+        let self_param = self.fn_self_param()?;
+
+        if let Some(self_param) = &self_param {
+            if !is_method {
+                return Err(self
+                    .error(
+                        "Only methods can take 'self'".to_string(),
+                        self_param.self_token.clone(),
+                    )
+                    .with_note("Methods are declared inside an 'impl' block")
+                    .into());
+            }
+        }
+
+        let has_more_params = match &self_param {
+            Some(self_param) => self_param.comma_token.is_some(),
+            None => true,
+        };
+
+        while has_more_params && self.check(&TokenType::Ident) {
             // TODO: Do I care about this ??
             if params.len() >= 255 {
                 let t = self.peek().ok_or_else(|| self.eof_err())?;
@@ -340,7 +468,7 @@ impl FeTokenSyntaxParser {
                     }
                 }
 
-                Err(e) => todo!("{e}"),
+                Err(e) => return Err(e),
                 // Err(e) => self.synchronize_field(e)?,
             }
         }
@@ -371,13 +499,15 @@ impl FeTokenSyntaxParser {
 
         return Ok(FnDecl {
             id: self.node_id_gen.next(),
+            attrs,
             decl_mod,
             fn_mod,
             fn_token,
-            generics: None,
+            generics,
             name,
             open_paren_token,
             pre_comma_token,
+            self_param,
             params,
             close_paren_token,
             return_type,
@@ -386,15 +516,64 @@ impl FeTokenSyntaxParser {
         });
     }
 
+    fn fn_self_param(&mut self) -> Result<Option<FnDeclSelfParam>> {
+        let start_idx = self.current_idx;
+
+        let ref_type =
+            if let Some(ref_token) = self.match_any(&[TokenType::Amp], WithNewlines::None) {
+                if let Some(mut_token) = self.match_any(&[TokenType::Mut], WithNewlines::None) {
+                    Some(RefType::Mut {
+                        ref_token,
+                        mut_token,
+                    })
+                } else {
+                    let const_token = self.match_any(&[TokenType::Const], WithNewlines::None);
+
+                    Some(RefType::Shared {
+                        ref_token,
+                        const_token,
+                    })
+                }
+            } else {
+                None
+            };
+
+        let mut_token = if ref_type.is_none() {
+            self.match_any(&[TokenType::Mut], WithNewlines::None)
+        } else {
+            None
+        };
+
+        let Some(self_token) = self.match_any(&[TokenType::SelfVal], WithNewlines::None) else {
+            if self.current_idx != start_idx {
+                let t = self.peek().ok_or_else(|| self.eof_err())?;
+
+                return Err(self.error("Expect 'self'".to_string(), t).into());
+            }
+
+            return Ok(None);
+        };
+
+        let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+
+        return Ok(Some(FnDeclSelfParam {
+            ref_type,
+            mut_token,
+            self_token,
+            comma_token,
+        }));
+    }
+
     fn struct_decl(
         &mut self,
+        attrs: Vec<DeclAttr>,
         decl_mod: Option<DeclMod>,
         struct_token: Arc<Token>,
     ) -> Result<StructDecl> {
-        // TODO: generics
-
         let name = self.consume(&TokenType::Ident, "Expected struct name")?;
 
+        let generics = self.decl_generics()?;
+
         let open_squirly_brace_token = self.consume(
             &TokenType::OpenSquirlyBrace,
             "Expected '{' after struct name",
@@ -402,6 +581,24 @@ impl FeTokenSyntaxParser {
 
         let pre_comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
 
+        let (fields, close_squirly_brace_token) = self.struct_decl_fields()?;
+
+        return Ok(StructDecl {
+            id: self.node_id_gen.next(),
+            attrs,
+            decl_mod,
+            struct_token,
+            name,
+            generics,
+            open_squirly_brace_token,
+            pre_comma_token,
+            fields,
+            close_squirly_brace_token,
+        });
+    }
+
+    /// The fields of a struct after its '{', up to and including the '}'.
+    fn struct_decl_fields(&mut self) -> Result<(Vec<StructDeclField>, Arc<Token>)> {
         let mut fields = vec![];
         let close_squirly_brace_token = loop {
             if let Some(token) = self.match_any(&[TokenType::CloseSquirlyBrace], WithNewlines::Many)
@@ -438,16 +635,168 @@ impl FeTokenSyntaxParser {
             }
         };
 
-        return Ok(StructDecl {
+        return Ok((fields, close_squirly_brace_token));
+    }
+
+    fn enum_decl(
+        &mut self,
+        attrs: Vec<DeclAttr>,
+        decl_mod: Option<DeclMod>,
+        enum_token: Arc<Token>,
+    ) -> Result<EnumDecl> {
+        let name = self.consume(&TokenType::Ident, "Expected enum name")?;
+
+        let open_squirly_brace_token =
+            self.consume(&TokenType::OpenSquirlyBrace, "Expected '{' after enum name")?;
+
+        let pre_comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+
+        let mut variants = vec![];
+        let close_squirly_brace_token = loop {
+            if let Some(token) = self.match_any(&[TokenType::CloseSquirlyBrace], WithNewlines::Many)
+            {
+                break token;
+            }
+
+            self.allow_many_newlines();
+
+            let name = self.consume(&TokenType::Ident, "Expected variant name")?;
+
+            let fields = self.enum_decl_variant_fields()?;
+
+            let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+            let is_done = comma_token.is_none();
+
+            variants.push(EnumDeclVariant {
+                name,
+                fields,
+                comma_token,
+            });
+
+            if is_done {
+                break self.consume(&TokenType::CloseSquirlyBrace, "Expected comma or '}'")?;
+            }
+        };
+
+        return Ok(EnumDecl {
             id: self.node_id_gen.next(),
+            attrs,
             decl_mod,
-            struct_token,
+            enum_token,
             name,
-            generics: None,
             open_squirly_brace_token,
             pre_comma_token,
-            fields,
+            variants,
             close_squirly_brace_token,
+            has_resolved_variants: false,
+        });
+    }
+
+    fn enum_decl_variant_fields(&mut self) -> Result<EnumDeclVariantFields> {
+        if let Some(open_paren_token) = self.match_any(&[TokenType::OpenParen], WithNewlines::None)
+        {
+            let mut fields = vec![];
+            let close_paren_token = loop {
+                if let Some(token) = self.match_any(&[TokenType::CloseParen], WithNewlines::Many) {
+                    break token;
+                }
+
+                self.allow_many_newlines();
+
+                let static_type_ref = self.static_type_ref()?;
+
+                let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+                let is_done = comma_token.is_none();
+
+                fields.push(EnumDeclTupleField {
+                    static_type_ref,
+                    comma_token,
+                });
+
+                if is_done {
+                    break self.consume(&TokenType::CloseParen, "Expected comma or ')'")?;
+                }
+            };
+
+            return Ok(EnumDeclVariantFields::Tuple {
+                open_paren_token,
+                fields,
+                close_paren_token,
+            });
+        }
+
+        if let Some(open_squirly_brace_token) =
+            self.match_any(&[TokenType::OpenSquirlyBrace], WithNewlines::None)
+        {
+            let (fields, close_squirly_brace_token) = self.struct_decl_fields()?;
+
+            for field in &fields {
+                if let Some(StructFieldMod::Pub(pub_token)) = &field.field_mod {
+                    return Err(self
+                        .error(
+                            "Variant fields can't be 'pub'".to_string(),
+                            pub_token.clone(),
+                        )
+                        .with_note("They're visible wherever the enum is")
+                        .into());
+                }
+            }
+
+            return Ok(EnumDeclVariantFields::Struct {
+                open_squirly_brace_token,
+                fields,
+                close_squirly_brace_token,
+            });
+        }
+
+        return Ok(EnumDeclVariantFields::Unit);
+    }
+
+    fn impl_decl(&mut self, attrs: Vec<DeclAttr>, impl_token: Arc<Token>) -> Result<ImplDecl> {
+        let target = self.static_path()?;
+
+        self.consume(&TokenType::Newline, "Expect newline after impl target")?;
+
+        let mut methods = vec![];
+
+        let end_semicolon_token = loop {
+            if let Some(token) = self.match_any(&[TokenType::Semicolon], WithNewlines::Many) {
+                break token;
+            }
+
+            let attrs = self.decl_attrs()?;
+
+            let decl_mod = self
+                .match_any(&[TokenType::Pub], WithNewlines::Many)
+                .map(DeclMod::Pub);
+
+            let Some((fn_mod, fn_token)) = self.fn_start()? else {
+                let t = self.peek().ok_or_else(|| self.eof_err())?;
+
+                return Err(self
+                    .error(
+                        format!(
+                            "Expected a method or ';'. Found '{}'",
+                            t.lexeme.escape_debug()
+                        ),
+                        t,
+                    )
+                    .into());
+            };
+
+            let method = self.fn_decl(attrs, decl_mod, fn_mod, fn_token, true)?;
+            methods.push(Arc::new(Mutex::new(method)));
+
+            self.consume(&TokenType::Newline, "Expect newline after method")?;
+        };
+
+        return Ok(ImplDecl {
+            id: self.node_id_gen.next(),
+            attrs,
+            impl_token,
+            target,
+            methods,
+            end_semicolon_token,
         });
     }
 
@@ -472,15 +821,90 @@ impl FeTokenSyntaxParser {
             None
         };
 
+        let static_path = self.static_path()?;
+
+        let generic_args = if let Some(open_angle_token) =
+            self.match_any(&[TokenType::Less], WithNewlines::None)
+        {
+            let mut args = vec![];
+
+            loop {
+                let static_type = self.static_type_ref()?;
+
+                let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::None);
+                let is_done = comma_token.is_none();
+
+                args.push(StaticGenericArg {
+                    static_type,
+                    comma_token,
+                });
+
+                if is_done || self.check(&TokenType::Greater) {
+                    break;
+                }
+            }
+
+            let close_angle_token =
+                self.consume(&TokenType::Greater, "Expect '>' after type arguments")?;
+
+            Some(StaticGenericArgs {
+                open_angle_token,
+                args,
+                close_angle_token,
+            })
+        } else {
+            None
+        };
+
+        let question_token = self.match_any(&[TokenType::Question], WithNewlines::None);
+
         let type_ref = StaticType {
             ref_type,
-            static_path: self.static_path()?,
+            static_path,
+            generic_args,
+            question_token,
             resolved_type: (),
         };
 
         return Ok(type_ref);
     }
 
+    fn decl_generics(&mut self) -> Result<Option<DeclGenerics>> {
+        let Some(open_angle_token) = self.match_any(&[TokenType::Less], WithNewlines::None) else {
+            return Ok(None);
+        };
+
+        let mut params = vec![];
+
+        while let Some(name) = self.match_any(&[TokenType::Ident], WithNewlines::None) {
+            let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::None);
+            let is_done = comma_token.is_none();
+
+            params.push(DeclGenericParam { name, comma_token });
+
+            if is_done {
+                break;
+            }
+        }
+
+        if params.is_empty() {
+            let t = self.peek().ok_or_else(|| self.eof_err())?;
+
+            return Err(self
+                .error("Expect type parameter name".to_string(), t)
+                .into());
+        }
+
+        let close_angle_token =
+            self.consume(&TokenType::Greater, "Expect '>' after type parameters")?;
+
+        return Ok(Some(DeclGenerics {
+            open_angle_token,
+            params,
+            close_angle_token,
+        }));
+    }
+
     fn static_path(&mut self) -> Result<StaticPath> {
         let double_colon_token = self.match_any(&[TokenType::DoubleColon], WithNewlines::None);
 
@@ -529,20 +953,34 @@ impl FeTokenSyntaxParser {
             }
 
             if self.is_at_end() {
-                todo!("Unexpected end of file! Expected one of: {any_end:?}");
+                return Err(self
+                    .eof_err()
+                    .with_note(format!("Expected one of: {any_end:?}"))
+                    .into());
             }
 
             if self.allow_many_newlines() == 0 {
-                block.push(self.statement()?);
+                let start_idx = self.current_idx;
 
-                if !self.is_at_end() {
-                    self.consume(
-                        &TokenType::Newline,
-                        &format!(
-                            "Expect newline after statement. Found {:#?}",
-                            self.tokens[self.current_idx]
-                        ),
-                    )?;
+                let res = self.statement().and_then(|stmt| {
+                    block.push(stmt);
+
+                    if !self.is_at_end() {
+                        self.consume(
+                            &TokenType::Newline,
+                            format!(
+                                "Expect newline after statement. Found '{}'",
+                                self.tokens[self.current_idx].lexeme.escape_debug()
+                            ),
+                        )?;
+                    }
+
+                    return Ok(());
+                });
+
+                if let Err(e) = res {
+                    self.recover(e)?;
+                    self.synchronize_statement(start_idx);
                 }
             }
         };
@@ -551,11 +989,11 @@ impl FeTokenSyntaxParser {
     }
 
     fn statement(&mut self) -> Result<Arc<Mutex<Stmt>>> {
-        /*
-        if self.match_any(&[token::TokenType::For], WithNewlines::Many) {
-            return Ok(ast::Stmt::For(self.for_statement()?));
+        if let Some(token) = self.match_any(&[TokenType::For], WithNewlines::Many) {
+            return Ok(Arc::new(Mutex::new(Stmt::For(Arc::new(Mutex::new(
+                self.for_statement(token)?,
+            ))))));
         }
-        */
 
         if let Some(token) = self.match_any(&[TokenType::Loop], WithNewlines::Many) {
             return Ok(Arc::new(Mutex::new(Stmt::Loop(Arc::new(Mutex::new(
@@ -595,6 +1033,17 @@ impl FeTokenSyntaxParser {
             ))))));
         }
 
+        if let Some(token) = self.match_any(&[TokenType::Match], WithNewlines::Many) {
+            return Ok(Arc::new(Mutex::new(Stmt::Expr(Arc::new(Mutex::new(
+                ExprStmt {
+                    id: self.node_id_gen.next(),
+                    expr: Arc::new(Mutex::new(Expr::Match(Arc::new(Mutex::new(
+                        self.match_expr(token)?,
+                    ))))),
+                },
+            ))))));
+        }
+
         if let Some(token) = self.match_any(&[TokenType::Return], WithNewlines::Many) {
             return Ok(Arc::new(Mutex::new(Stmt::Return(Arc::new(Mutex::new(
                 self.return_statement(token)?,
@@ -822,10 +1271,91 @@ impl FeTokenSyntaxParser {
         });
     }
 
+    fn for_statement(&mut self, for_token: Arc<Token>) -> Result<ForStmt> {
+        let label = self.match_any(&[TokenType::Label], WithNewlines::None);
+
+        let (name, in_token, iter) = self.for_header()?;
+
+        let block = self.code_block()?;
+
+        return Ok(ForStmt {
+            id: self.node_id_gen.next(),
+            for_token,
+            label,
+            name,
+            in_token,
+            iter,
+            block,
+            resolved_terminal: None,
+        });
+    }
+
+    fn for_expr(&mut self, for_token: Arc<Token>) -> Result<ForExpr> {
+        let label = self.match_any(&[TokenType::Label], WithNewlines::None);
+
+        let (name, in_token, iter) = self.for_header()?;
+
+        let (stmts, end) =
+            self.code_block_with_any_end(&[TokenType::Semicolon, TokenType::Else])?;
+
+        let block = CodeBlock {
+            stmts,
+            end_semicolon_token: (),
+        };
+
+        if end.token_type != TokenType::Else {
+            return Err(self
+                .error(
+                    "Expected 'else' with the value for when the 'for' loop finishes without breaking"
+                        .to_string(),
+                    end,
+                )
+                .into());
+        }
+
+        let else_expr = NestedExpr(self.expression()?);
+
+        return Ok(ForExpr {
+            id: self.node_id_gen.next(),
+            for_token,
+            label,
+            name,
+            in_token,
+            iter,
+            block,
+            else_: ForExprElse {
+                else_token: end,
+                else_expr,
+            },
+            resolved_terminal: None,
+            resolved_type: None,
+        });
+    }
+
+    /// The `x in <expr>` after `for` and its label, up to the newline starting the block.
+    fn for_header(&mut self) -> Result<(Arc<Token>, Arc<Token>, NestedExpr)> {
+        let name = self.consume(&TokenType::Ident, "Expected a name after 'for'")?;
+        let in_token = self.consume(&TokenType::In, "Expected 'in' after the 'for' name")?;
+
+        let iter = NestedExpr(self.expression()?);
+
+        let _ = self.consume(&TokenType::Newline, "Expected newline after 'for' range")?;
+
+        return Ok((name, in_token, iter));
+    }
+
     fn var_decl_statement(&mut self, var_mut: VarDeclMut) -> Result<VarDeclStmt> {
         let target = self.var_decl_target()?;
 
-        // TODO: parse explicit type
+        let explicit_type =
+            if let Some(colon_token) = self.match_any(&[TokenType::Colon], WithNewlines::None) {
+                Some(VarDeclExplicitType {
+                    colon_token,
+                    static_type: self.static_type_ref()?,
+                })
+            } else {
+                None
+            };
 
         let value = if let Some(token) = self.match_any(&[TokenType::Equal], WithNewlines::One) {
             Some(VarDeclValue {
@@ -840,7 +1370,7 @@ impl FeTokenSyntaxParser {
             id: self.node_id_gen.next(),
             var_mut,
             target,
-            explicit_type: None,
+            explicit_type,
             value,
         });
     }
@@ -949,8 +1479,8 @@ impl FeTokenSyntaxParser {
 
         let then = if let Some(then_token) = self.match_any(&[TokenType::Then], WithNewlines::None)
         {
-            if label.is_some() {
-                todo!("Unexpected label!");
+            if let Some(label) = label {
+                return Err(self.unexpected_label_err(label).into());
             }
 
             let then_expr = NestedExpr(self.expression()?);
@@ -994,8 +1524,8 @@ impl FeTokenSyntaxParser {
                     let else_if = if let Some(then_token) =
                         self.match_any(&[TokenType::Then], WithNewlines::None)
                     {
-                        if label.is_some() {
-                            todo!("Unexpected label");
+                        if let Some(label) = label {
+                            return Err(self.unexpected_label_err(label).into());
                         }
 
                         let then_expr = NestedExpr(self.expression()?);
@@ -1031,77 +1561,295 @@ impl FeTokenSyntaxParser {
                         end_token = end;
                         semicolon_token = Some(end_token.clone());
 
-                        if end_token.token_type == TokenType::Else {
-                            should_continue = true;
-                        }
+                        if end_token.token_type == TokenType::Else {
+                            should_continue = true;
+                        }
+
+                        IfExprElseIf::Block(IfExprElseIfBlock {
+                            else_token,
+                            if_token,
+                            label,
+                            condition,
+                            block,
+                        })
+                    };
+
+                    else_ifs.push(else_if);
+
+                    if !should_continue {
+                        break;
+                    }
+                }
+
+                if end_token.token_type == TokenType::Else {
+                    let label = self.match_any(&[TokenType::Label], WithNewlines::None);
+
+                    if self.allow_one_newline() {
+                        let (stmts, end) = self.code_block_with_any_end(&[TokenType::Semicolon])?;
+
+                        let block = CodeBlock {
+                            stmts,
+                            end_semicolon_token: (),
+                        };
+
+                        let else_token = end_token;
+                        end_token = end;
+                        semicolon_token = Some(end_token);
+
+                        else_ = Some(IfExprElse::Block(IfExprElseBlock {
+                            else_token,
+                            label,
+                            block,
+                        }));
+                    } else {
+                        if let Some(label) = label {
+                            return Err(self.unexpected_label_err(label).into());
+                        }
+
+                        let else_expr = NestedExpr(self.expression()?);
+
+                        let else_token = end_token;
+                        semicolon_token = None;
+
+                        else_ = Some(IfExprElse::Ternary(IfExprElseTernary {
+                            else_token,
+                            else_expr,
+                        }));
+                    }
+                }
+            }
+        }
+
+        return Ok(IfExpr {
+            id: self.node_id_gen.next(),
+            if_token,
+            condition,
+            then,
+            else_ifs,
+            else_,
+            semicolon_token,
+            resolved_terminal: None,
+            resolved_type: None,
+        });
+    }
+
+    fn match_expr(&mut self, match_token: Arc<Token>) -> Result<MatchExpr> {
+        let label = self.match_any(&[TokenType::Label], WithNewlines::None);
+
+        let value = NestedExpr(self.expression()?);
+
+        let _ = self.consume(&TokenType::Newline, "Expected newline after match value")?;
+
+        let mut arms = vec![];
+
+        let semicolon_token = loop {
+            if let Some(semicolon_token) =
+                self.match_any(&[TokenType::Semicolon], WithNewlines::Many)
+            {
+                break semicolon_token;
+            }
+
+            if self.is_at_end() {
+                return Err(self
+                    .eof_err()
+                    .with_note("Expected ';' after match arms")
+                    .into());
+            }
+
+            let pattern = self.pattern()?;
+
+            let guard = if let Some(if_token) = self.match_any(&[TokenType::If], WithNewlines::None)
+            {
+                Some(MatchArmGuard {
+                    if_token,
+                    condition: NestedExpr(self.expression()?),
+                })
+            } else {
+                None
+            };
+
+            let body =
+                if let Some(then_token) = self.match_any(&[TokenType::Then], WithNewlines::None) {
+                    MatchArmBody::Ternary(MatchArmTernary {
+                        then_token,
+                        then_expr: NestedExpr(self.expression()?),
+                    })
+                } else {
+                    let _ =
+                        self.consume(&TokenType::Newline, "Expected newline after match pattern")?;
+
+                    MatchArmBody::Block(self.code_block()?)
+                };
+
+            arms.push(MatchArm {
+                pattern,
+                guard,
+                body,
+            });
+
+            if !self.check(&TokenType::Semicolon) {
+                let _ = self.consume(&TokenType::Newline, "Expected newline after match arm")?;
+            }
+        };
+
+        return Ok(MatchExpr {
+            id: self.node_id_gen.next(),
+            match_token,
+            label,
+            value,
+            arms,
+            semicolon_token,
+            resolved_terminal: None,
+            resolved_type: None,
+        });
+    }
+
+    fn pattern(&mut self) -> Result<Pattern> {
+        if self.check(&TokenType::Ident) && self.tokens[self.current_idx].lexeme.as_ref() == "_" {
+            let underscore_token = self.consume(&TokenType::Ident, "Expected '_'")?;
+
+            return Ok(Pattern::Wildcard(WildcardPattern { underscore_token }));
+        }
+
+        if self.check(&TokenType::Minus) {
+            return Ok(Pattern::Literal(LiteralPattern {
+                value: NestedExpr(self.unary()?),
+            }));
+        }
+
+        if self.check(&TokenType::IntegerNumber)
+            || self.check(&TokenType::DecimalNumber)
+            || self.check(&TokenType::PlainString)
+            || self.check(&TokenType::True)
+            || self.check(&TokenType::False)
+            || self.check(&TokenType::NoneLiteral)
+        {
+            return Ok(Pattern::Literal(LiteralPattern {
+                value: NestedExpr(self.primary()?),
+            }));
+        }
+
+        let is_path = self.check(&TokenType::DoubleColon)
+            || (self.check(&TokenType::Ident)
+                && (self.check_offset(1, &TokenType::DoubleColon)
+                    || self.check_offset(1, &TokenType::OpenParen)
+                    || self.check_offset(1, &TokenType::OpenSquirlyBrace)));
+
+        if !is_path {
+            let name = self.consume(&TokenType::Ident, "Expected pattern")?;
+
+            return Ok(Pattern::Binding(BindingPattern {
+                name,
+                resolved_type: (),
+            }));
+        }
+
+        let path = self.static_path()?;
+
+        if let Some(open_paren_token) = self.match_any(&[TokenType::OpenParen], WithNewlines::None)
+        {
+            let mut fields = vec![];
+
+            let close_paren_token = loop {
+                if let Some(token) = self.match_any(&[TokenType::CloseParen], WithNewlines::Many) {
+                    break token;
+                }
+
+                self.allow_many_newlines();
+
+                let pattern = self.pattern()?;
+
+                self.allow_many_newlines();
+                let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+                let is_done = comma_token.is_none();
+
+                fields.push(VariantPatternField {
+                    pattern,
+                    comma_token,
+                });
+
+                if is_done {
+                    break self.consume(&TokenType::CloseParen, "Expected ')'")?;
+                }
+            };
 
-                        IfExprElseIf::Block(IfExprElseIfBlock {
-                            else_token,
-                            if_token,
-                            label,
-                            condition,
-                            block,
-                        })
-                    };
+            return Ok(Pattern::Variant(VariantPattern {
+                path,
+                fields: Some(VariantPatternFields {
+                    open_paren_token,
+                    fields,
+                    close_paren_token,
+                }),
+            }));
+        }
 
-                    else_ifs.push(else_if);
+        if let Some(open_squirly_brace_token) =
+            self.match_any(&[TokenType::OpenSquirlyBrace], WithNewlines::None)
+        {
+            let mut fields = vec![];
+            let mut rest_token = None;
 
-                    if !should_continue {
-                        break;
-                    }
+            let close_squirly_brace_token = loop {
+                if let Some(token) =
+                    self.match_any(&[TokenType::CloseSquirlyBrace], WithNewlines::Many)
+                {
+                    break token;
                 }
 
-                if end_token.token_type == TokenType::Else {
-                    let label = self.match_any(&[TokenType::Label], WithNewlines::None);
+                self.allow_many_newlines();
 
-                    if self.allow_one_newline() {
-                        let (stmts, end) = self.code_block_with_any_end(&[TokenType::Semicolon])?;
+                if let Some(token) = self.match_any(&[TokenType::DotDot], WithNewlines::Many) {
+                    rest_token = Some(token);
 
-                        let block = CodeBlock {
-                            stmts,
-                            end_semicolon_token: (),
-                        };
+                    break self.consume(
+                        &TokenType::CloseSquirlyBrace,
+                        "Expected '}' after '..' in pattern",
+                    )?;
+                }
 
-                        let else_token = end_token;
-                        end_token = end;
-                        semicolon_token = Some(end_token);
+                let name = self.consume(&TokenType::Ident, "Expected field name")?;
 
-                        else_ = Some(IfExprElse::Block(IfExprElseBlock {
-                            else_token,
-                            label,
-                            block,
-                        }));
-                    } else {
-                        if label.is_some() {
-                            log::error!(&label);
-                            todo!("Unexpected label!");
+                let (colon_token, pattern) =
+                    match self.match_any(&[TokenType::Colon], WithNewlines::None) {
+                        Some(colon_token) => {
+                            self.allow_many_newlines();
+                            (Some(colon_token), self.pattern()?)
                         }
+                        None => (
+                            None,
+                            Pattern::Binding(BindingPattern {
+                                name: name.clone(),
+                                resolved_type: (),
+                            }),
+                        ),
+                    };
 
-                        let else_expr = NestedExpr(self.expression()?);
+                self.allow_many_newlines();
+                let comma_token = self.match_any(&[TokenType::Comma], WithNewlines::Many);
+                let is_done = comma_token.is_none();
 
-                        let else_token = end_token;
-                        semicolon_token = None;
+                fields.push(StructPatternField {
+                    name,
+                    colon_token,
+                    pattern,
+                    comma_token,
+                });
 
-                        else_ = Some(IfExprElse::Ternary(IfExprElseTernary {
-                            else_token,
-                            else_expr,
-                        }));
-                    }
+                if is_done {
+                    break self.consume(&TokenType::CloseSquirlyBrace, "Expected '}'")?;
                 }
-            }
+            };
+
+            return Ok(Pattern::Struct(StructPattern {
+                path,
+                open_squirly_brace_token,
+                fields,
+                rest_token,
+                close_squirly_brace_token,
+            }));
         }
 
-        return Ok(IfExpr {
-            id: self.node_id_gen.next(),
-            if_token,
-            condition,
-            then,
-            else_ifs,
-            else_,
-            semicolon_token,
-            resolved_terminal: None,
-            resolved_type: None,
-        });
+        return Ok(Pattern::Variant(VariantPattern { path, fields: None }));
     }
 
     fn return_statement(&mut self, return_token: Arc<Token>) -> Result<ReturnStmt> {
@@ -1115,6 +1863,7 @@ impl FeTokenSyntaxParser {
             id: self.node_id_gen.next(),
             return_token,
             value,
+            checked: false,
         });
     }
 
@@ -1160,6 +1909,9 @@ impl FeTokenSyntaxParser {
                 TokenType::Equal,
                 TokenType::PlusEqual,
                 TokenType::MinusEqual,
+                TokenType::AsteriskEqual,
+                TokenType::SlashEqual,
+                TokenType::PercentEqual,
             ],
             WithNewlines::One,
         ) {
@@ -1167,10 +1919,13 @@ impl FeTokenSyntaxParser {
                 TokenType::Equal => AssignOp::Eq(op_token),
                 TokenType::PlusEqual => AssignOp::PlusEq(op_token),
                 TokenType::MinusEqual => AssignOp::MinusEq(op_token),
+                TokenType::AsteriskEqual => AssignOp::MultiplyEq(op_token),
+                TokenType::SlashEqual => AssignOp::DivideEq(op_token),
+                TokenType::PercentEqual => AssignOp::ModuloEq(op_token),
                 _ => {
                     return Err(self
                         .error(
-                            format!("[{}:{}] Expected '=' or '+='", file!(), line!()),
+                            format!("[{}:{}] Expected an assignment operator", file!(), line!()),
                             op_token,
                         )
                         .into())
@@ -1210,12 +1965,24 @@ impl FeTokenSyntaxParser {
             ))))));
         }
 
+        if let Some(token) = self.match_any(&[TokenType::For], WithNewlines::Many) {
+            return Ok(Arc::new(Mutex::new(Expr::For(Arc::new(Mutex::new(
+                self.for_expr(token)?,
+            ))))));
+        }
+
         if let Some(token) = self.match_any(&[TokenType::If], WithNewlines::Many) {
             return Ok(Arc::new(Mutex::new(Expr::If(Arc::new(Mutex::new(
                 self.if_expr(token)?,
             ))))));
         }
 
+        if let Some(token) = self.match_any(&[TokenType::Match], WithNewlines::Many) {
+            return Ok(Arc::new(Mutex::new(Expr::Match(Arc::new(Mutex::new(
+                self.match_expr(token)?,
+            ))))));
+        }
+
         return self.expression();
     }
 
@@ -1224,73 +1991,76 @@ impl FeTokenSyntaxParser {
     }
 
     fn or(&mut self) -> Result<Arc<Mutex<Expr>>> {
-        let expr = self.and()?;
+        let mut expr = self.and()?;
 
-        /*
-        while let Some(operator) = self.match_any(&[TokenType::Or], WithNewlines::One) {
+        while let Some(op_token) = self.match_any(&[TokenType::Or], WithNewlines::One) {
             let right = self.and()?;
 
-            expr = Expr::Logical(LogicalExpr {
-                id: self.node_id_gen.next(),
-                left: Box::new(expr),
-                operator,
-                right: Box::new(right),
-            });
+            expr = Arc::new(Mutex::new(Expr::Logical(Arc::new(Mutex::new(
+                LogicalExpr {
+                    id: self.node_id_gen.next(),
+                    lhs: NestedExpr(expr),
+                    op: LogicalOp::Or(op_token),
+                    rhs: NestedExpr(right),
+                    resolved_type: (),
+                },
+            )))));
         }
-        */
 
         return Ok(expr);
     }
 
     fn and(&mut self) -> Result<Arc<Mutex<Expr>>> {
-        let expr = self.equality()?;
+        let mut expr = self.equality()?;
 
-        /*
-        while let Some(operator) = self.match_any(&[TokenType::And], WithNewlines::One) {
+        while let Some(op_token) = self.match_any(&[TokenType::And], WithNewlines::One) {
             let right = self.equality()?;
 
-            expr = Expr::Logical(LogicalExpr {
-                id: self.node_id_gen.next(),
-                left: Box::new(expr),
-                operator,
-                right: Box::new(right),
-            });
+            expr = Arc::new(Mutex::new(Expr::Logical(Arc::new(Mutex::new(
+                LogicalExpr {
+                    id: self.node_id_gen.next(),
+                    lhs: NestedExpr(expr),
+                    op: LogicalOp::And(op_token),
+                    rhs: NestedExpr(right),
+                    resolved_type: (),
+                },
+            )))));
         }
-        */
 
         return Ok(expr);
     }
 
     fn equality(&mut self) -> Result<Arc<Mutex<Expr>>> {
-        let expr = self.comparison()?;
+        let mut expr = self.comparison()?;
 
-        /*
         while let Some(op_token) = self.match_any(
             &[TokenType::BangEqual, TokenType::EqualEqual],
             WithNewlines::One,
         ) {
             let op = match op_token.token_type {
-                TokenType::BangEqual => (BinaryOp::NotEqual, op_token),
-                TokenType::EqualEqual => (BinaryOp::EqualEqual, op_token),
+                TokenType::BangEqual => BinaryOp::NotEqual(op_token),
+                TokenType::EqualEqual => BinaryOp::Equal(op_token),
 
                 _ => {
-                    return Err(self.error(
-                        format!("[{}:{}] Expected '!=' or '=='", file!(), line!()),
-                        op_token,
-                    ).into())
+                    return Err(self
+                        .error(
+                            format!("[{}:{}] Expected '!=' or '=='", file!(), line!()),
+                            op_token,
+                        )
+                        .into());
                 }
             };
 
             let right = self.comparison()?;
 
-            expr = Expr::Binary(BinaryExpr {
+            expr = Arc::new(Mutex::new(Expr::Binary(Arc::new(Mutex::new(BinaryExpr {
                 id: self.node_id_gen.next(),
-                left: Box::new(expr),
+                lhs: NestedExpr(expr),
                 op,
-                right: Box::new(right),
-            });
+                rhs: NestedExpr(right),
+                resolved_type: (),
+            })))));
         }
-        */
 
         return Ok(expr);
     }
@@ -1340,28 +2110,37 @@ impl FeTokenSyntaxParser {
     fn range(&mut self) -> Result<Arc<Mutex<Expr>>> {
         let expr = self.term()?;
 
-        /*
-        while let Some(op_token) = self.match_any(&[TokenType::DotDot], WithNewlines::One) {
+        // Ranges don't chain, `a..b..c` is an error
+        if let Some(op_token) = self.match_any(
+            &[TokenType::DotDot, TokenType::DotDotEqual],
+            WithNewlines::One,
+        ) {
             let op = match op_token.token_type {
-                TokenType::DotDot => (BinaryOp::Range, op_token),
+                TokenType::DotDot => BinaryOp::Range(op_token),
+                TokenType::DotDotEqual => BinaryOp::RangeInclusive(op_token),
 
                 _ => {
                     return Err(self
-                        .error(format!("[{}:{}] Expected '..'", file!(), line!()), op_token)
-                        .into());
+                        .error(
+                            format!("[{}:{}] Expected '..' or '..='", file!(), line!()),
+                            op_token,
+                        )
+                        .into())
                 }
             };
 
             let right = self.term()?;
 
-            expr = Expr::Binary(BinaryExpr {
-                id: self.node_id_gen.next(),
-                left: Box::new(expr),
-                op,
-                right: Box::new(right),
-            });
+            return Ok(Arc::new(Mutex::new(Expr::Binary(Arc::new(Mutex::new(
+                BinaryExpr {
+                    id: self.node_id_gen.next(),
+                    lhs: NestedExpr(expr),
+                    op,
+                    rhs: NestedExpr(right),
+                    resolved_type: (),
+                },
+            ))))));
         }
-        */
 
         return Ok(expr);
     }
@@ -1401,65 +2180,37 @@ impl FeTokenSyntaxParser {
     }
 
     fn factor(&mut self) -> Result<Arc<Mutex<Expr>>> {
-        let expr = self.modulo()?;
+        let mut expr = self.unary()?;
 
-        /*
-        while let Some(op_token) =
-            self.match_any(&[TokenType::Slash, TokenType::Asterisk], WithNewlines::One)
-        {
+        while let Some(op_token) = self.match_any(
+            &[TokenType::Asterisk, TokenType::Slash, TokenType::Percent],
+            WithNewlines::One,
+        ) {
             let op = match op_token.token_type {
-                TokenType::Slash => (BinaryOp::Divide, op_token),
-                TokenType::Asterisk => (BinaryOp::Times, op_token),
+                TokenType::Asterisk => BinaryOp::Multiply(op_token),
+                TokenType::Slash => BinaryOp::Divide(op_token),
+                TokenType::Percent => BinaryOp::Modulo(op_token),
 
                 _ => {
                     return Err(self
                         .error(
-                            format!("[{}:{}] Expected '/' or '*'", file!(), line!()),
+                            format!("[{}:{}] Expected '*', '/' or '%'", file!(), line!()),
                             op_token,
                         )
                         .into())
                 }
             };
 
-            let right = self.modulo()?;
-
-            expr = Expr::Binary(BinaryExpr {
-                id: self.node_id_gen.next(),
-                left: Box::new(expr),
-                op,
-                right: Box::new(right),
-            });
-        }
-        */
-
-        return Ok(expr);
-    }
-
-    fn modulo(&mut self) -> Result<Arc<Mutex<Expr>>> {
-        let expr = self.unary()?;
-
-        /*
-        while let Some(op_token) = self.match_any(&[TokenType::Percent], WithNewlines::One) {
-            let op = match op_token.token_type {
-                TokenType::Percent => (BinaryOp::Modulo, op_token),
-
-                _ => {
-                    return Err(self
-                        .error(format!("[{}:{}] Expected '%'", file!(), line!()), op_token)
-                        .into())
-                }
-            };
-
             let right = self.unary()?;
 
-            expr = Expr::Binary(BinaryExpr {
+            expr = Arc::new(Mutex::new(Expr::Binary(Arc::new(Mutex::new(BinaryExpr {
                 id: self.node_id_gen.next(),
-                left: Box::new(expr),
+                lhs: NestedExpr(expr),
                 op,
-                right: Box::new(right),
-            });
+                rhs: NestedExpr(right),
+                resolved_type: (),
+            })))));
         }
-        */
 
         return Ok(expr);
     }
@@ -1468,12 +2219,12 @@ impl FeTokenSyntaxParser {
         // TODO: Do we want to stop 'not' and '&' chains?
 
         if let Some(op_token) = self.match_any(
-            &[TokenType::Not, /*TokenType::Minus,*/ TokenType::Amp],
+            &[TokenType::Not, TokenType::Minus, TokenType::Amp],
             WithNewlines::One,
         ) {
             let op = match &op_token.token_type {
                 TokenType::Not => UnaryOp::Not(op_token),
-                // TokenType::Minus => (UnaryOp::Minus, op_token),
+                TokenType::Minus => UnaryOp::Minus(op_token),
                 TokenType::Amp => {
                     if let Some(mut_token) = self.match_any(&[TokenType::Mut], WithNewlines::None) {
                         UnaryOp::Ref(RefType::Mut {
@@ -1493,7 +2244,7 @@ impl FeTokenSyntaxParser {
                 _ => {
                     return Err(self
                         .error(
-                            format!("[{}:{}] Expected '!' or '-'", file!(), line!()),
+                            format!("[{}:{}] Expected 'not', '-' or '&'", file!(), line!()),
                             op_token,
                         )
                         .into())
@@ -1732,7 +2483,7 @@ impl FeTokenSyntaxParser {
                 return Ok(Arc::new(Mutex::new(Expr::NumberLiteral(Arc::new(
                     Mutex::new(NumberLiteralExpr {
                         id: self.node_id_gen.next(),
-                        details: NumberLiteralDetails::Integer(t.lexeme.parse()?),
+                        details: NumberLiteralDetails::Integer(self.integer_literal(&t)?),
                         literal: t,
                         resolved_type: (),
                     }),
@@ -1742,13 +2493,24 @@ impl FeTokenSyntaxParser {
                 return Ok(Arc::new(Mutex::new(Expr::NumberLiteral(Arc::new(
                     Mutex::new(NumberLiteralExpr {
                         id: self.node_id_gen.next(),
-                        details: NumberLiteralDetails::Decimal(t.lexeme.parse()?),
+                        details: NumberLiteralDetails::Decimal(self.decimal_literal(&t)?),
                         literal: t,
                         resolved_type: (),
                     }),
                 )))));
             }
 
+            // Only in scope inside methods, which the type resolver checks
+            Some((t, TokenType::SelfVal)) => {
+                return Ok(Arc::new(Mutex::new(Expr::Ident(Arc::new(Mutex::new(
+                    IdentExpr {
+                        id: self.node_id_gen.next(),
+                        ident: t,
+                        resolved_type: (),
+                    },
+                ))))));
+            }
+
             Some((t, TokenType::True | TokenType::False)) => {
                 return Ok(Arc::new(Mutex::new(Expr::BoolLiteral(Arc::new(
                     Mutex::new(BoolLiteralExpr {
@@ -1759,6 +2521,16 @@ impl FeTokenSyntaxParser {
                 )))));
             }
 
+            Some((t, TokenType::NoneLiteral)) => {
+                return Ok(Arc::new(Mutex::new(Expr::NoneLiteral(Arc::new(
+                    Mutex::new(NoneLiteralExpr {
+                        id: self.node_id_gen.next(),
+                        literal: t,
+                        resolved_type: (),
+                    }),
+                )))));
+            }
+
             /*
             Some(
                 t @ Token {
@@ -1877,9 +2649,8 @@ impl FeTokenSyntaxParser {
                 return Err(self
                     .error(
                         format!(
-                            "[{}:{}] Expected some expression. Found {peek:#?}",
-                            file!(),
-                            line!()
+                            "Expected an expression. Found '{}'",
+                            peek.lexeme.escape_debug()
                         ),
                         peek,
                     )
@@ -1904,17 +2675,207 @@ impl FeTokenSyntaxParser {
         return Err(self.error(err_msg.into(), t).into());
     }
 
-    fn eof_err(&mut self) -> ParserError {
-        let message = format!("[{}:{}] Unexpected end of file.", file!(), line!());
-        // self.error_ctx.error(Span::new(), message.clone());
+    fn eof_err(&mut self) -> Diagnostic {
+        let diagnostic = Diagnostic::error("Unexpected end of file")
+            .with_code(ErrorCode::UNEXPECTED_EOF)
+            .with_file(self.path.clone());
+
+        if let Some(last) = self.tokens.last() {
+            return diagnostic.with_span(last.span.clone());
+        }
+
+        return diagnostic;
+    }
+
+    /// The value of an integer literal, which can have a `0x`, `0o` or `0b` prefix
+    /// and `_` separators.
+    fn integer_literal(&mut self, t: &Arc<Token>) -> Result<u64> {
+        let literal = t.lexeme.replace('_', "");
+
+        let (radix, digits) = match literal.get(..2) {
+            Some("0x") => (16, &literal[2..]),
+            Some("0o") => (8, &literal[2..]),
+            Some("0b") => (2, &literal[2..]),
+            _ => (10, &literal[..]),
+        };
+
+        let Ok(value) = u64::from_str_radix(digits, radix) else {
+            return Err(self
+                .error(
+                    format!("Integer literal {} is too large", t.lexeme),
+                    t.clone(),
+                )
+                .with_note(format!("The largest integer is {}", u64::MAX))
+                .into());
+        };
+
+        return Ok(value);
+    }
+
+    fn decimal_literal(&mut self, t: &Arc<Token>) -> Result<f64> {
+        let value: f64 = t.lexeme.replace('_', "").parse()?;
+
+        if !value.is_finite() {
+            return Err(self
+                .error(
+                    format!("Decimal literal {} is too large", t.lexeme),
+                    t.clone(),
+                )
+                .with_note(format!("The largest decimal is {:e}", f64::MAX))
+                .into());
+        }
+
+        return Ok(value);
+    }
+
+    fn error(&mut self, message: String, t: Arc<Token>) -> Diagnostic {
+        return Diagnostic::error(message)
+            .with_code(ErrorCode::SYNTAX_ERROR)
+            .with_file(self.path.clone())
+            .with_span(t.span.clone());
+    }
+
+    fn unexpected_label_err(&mut self, label: Arc<Token>) -> Diagnostic {
+        return self
+            .error(format!("Unexpected label {}", label.lexeme), label)
+            .with_note("Labels are only allowed on block bodies, not on 'then' expressions");
+    }
+
+    /// Records a syntax error so parsing can continue, or returns any other error.
+    fn recover(&mut self, e: anyhow::Error) -> Result {
+        let diagnostic = e.downcast::<Diagnostic>()?;
+
+        // An unexpected EOF unwinds through every enclosing block, so only report it once
+        if self.errors.last() != Some(&diagnostic) {
+            self.errors.push(diagnostic);
+        }
+
+        return Ok(());
+    }
+
+    /// Skips the rest of a broken statement, up to the newline that ends it.
+    ///
+    /// Lines indented deeper than the statement belong to its blocks, as do the
+    /// `else` and `;` lines at its own indentation, and the arms of a `match`.
+    fn synchronize_statement(&mut self, start_idx: usize) {
+        if self.current_idx == start_idx {
+            let _ = self.advance();
+        } else if self.previous().map(|t| t.token_type == TokenType::Newline) == Some(true) {
+            // The error was found on the following line, which is a fresh statement
+            return;
+        }
+
+        let end_idx = self.statement_end(start_idx);
+
+        while !self.is_at_end() && (self.current_idx < end_idx || !self.check(&TokenType::Newline))
+        {
+            let _ = self.advance();
+        }
+    }
+
+    /// The index of the newline after the last line of the statement at `start_idx`.
+    fn statement_end(&self, start_idx: usize) -> usize {
+        let line_end = |idx: usize| {
+            return (idx..self.tokens.len())
+                .find(|&idx| self.tokens[idx].token_type == TokenType::Newline)
+                .unwrap_or(self.tokens.len());
+        };
+
+        let indent = self.tokens[start_idx].span.start.column;
+        let mut end_idx = line_end(start_idx);
+
+        let is_match = self.tokens[start_idx..end_idx]
+            .iter()
+            .any(|t| t.token_type == TokenType::Match);
+
+        // Match arms with a block body, closed by a `;` at the indentation of the match
+        let mut open_arms = 0;
+
+        // Only a statement with a block of its own ends with a `;` at its indentation
+        let mut has_block = is_match;
 
-        return ParserError::Error { message };
+        loop {
+            let Some(line_start) = (end_idx..self.tokens.len())
+                .find(|&idx| self.tokens[idx].token_type != TokenType::Newline)
+            else {
+                return end_idx;
+            };
+
+            let first = &self.tokens[line_start];
+            let next_end = line_end(line_start);
+
+            if first.span.start.column < indent {
+                return end_idx;
+            }
+
+            if first.span.start.column > indent {
+                has_block = true;
+            }
+
+            if first.span.start.column == indent {
+                match first.token_type {
+                    TokenType::Semicolon if open_arms > 0 => open_arms -= 1,
+                    TokenType::Semicolon if has_block => return next_end,
+                    TokenType::Else => {}
+                    _ if is_match => {
+                        let has_then = self.tokens[line_start..next_end]
+                            .iter()
+                            .any(|t| t.token_type == TokenType::Then);
+
+                        if !has_then {
+                            open_arms += 1;
+                        }
+                    }
+                    _ => return end_idx,
+                }
+            }
+
+            end_idx = next_end;
+        }
     }
 
-    fn error(&mut self, message: String, _t: Arc<Token>) -> ParserError {
-        // self.error_ctx.token_error(t, message.clone());
+    /// Skips ahead to the next line that starts a top-level 'use', 'fn', 'struct',
+    /// 'enum' or 'impl', past those nested deeper than the broken declaration, like
+    /// the methods of an 'impl'.
+    fn synchronize_declaration(&mut self, start_idx: usize) {
+        let indent = self
+            .tokens
+            .get(start_idx)
+            .map_or(0, |t| t.span.start.column);
+
+        if self.current_idx == start_idx {
+            let _ = self.advance();
+        }
+
+        while !self.is_at_end() {
+            let is_line_start = self
+                .previous()
+                .map(|t| t.token_type == TokenType::Newline)
+                .unwrap_or(true);
+
+            let starts_decl = self.peek().is_some_and(|t| {
+                t.span.start.column <= indent
+                    && matches!(
+                        t.token_type,
+                        TokenType::Use
+                            | TokenType::Pub
+                            | TokenType::Fn
+                            | TokenType::Pure
+                            | TokenType::Safe
+                            | TokenType::Norm
+                            | TokenType::Risk
+                            | TokenType::Struct
+                            | TokenType::Enum
+                            | TokenType::Impl
+                    )
+            });
+
+            if is_line_start && starts_decl {
+                return;
+            }
 
-        return ParserError::Error { message };
+            let _ = self.advance();
+        }
     }
 
     fn allow_many_newlines(&mut self) -> usize {
@@ -2015,6 +2976,9 @@ impl FeTokenSyntaxParser {
 mod tests {
     use super::*;
 
+    use crate::lexer::FeLexer;
+    use crate::source::{FeSourceFile, FeSourcePackage, SourcePackageName};
+
     macro_rules! input_matches_output_tests {
         ($($name:ident: $value:expr,)*) => {
         $(
@@ -2131,6 +3095,7 @@ mod tests {
                 }))],
                 decls: vec![Arc::new(Mutex::new(Decl::Fn(Arc::new(Mutex::new(FnDecl {
                     id: NodeId::zero(),
+                    attrs: vec![],
                     decl_mod: Some(DeclMod::Pub(Token::zero(TokenType::Pub, "pub"))),
                     fn_mod: None,
                     fn_token: Token::zero(TokenType::Fn, "fn"),
@@ -2138,6 +3103,7 @@ mod tests {
                     generics: None,
                     open_paren_token: Token::zero(TokenType::OpenParen, "("),
                     pre_comma_token: None,
+                    self_param: None,
                     params: vec![],
                     close_paren_token: Token::zero(TokenType::CloseParen, ")"),
                     return_type: None,
@@ -2176,4 +3142,149 @@ mod tests {
             }
         ),
     }
+
+    #[test]
+    fn test_recovers_from_many_errors() -> Result {
+        let tokens = vec![
+            Token::zero(TokenType::Fn, "fn"),
+            Token::zero(TokenType::Ident, "broken"),
+            Token::zero(TokenType::OpenParen, "("),
+            Token::zero(TokenType::CloseParen, ")"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Const, "const"),
+            Token::zero(TokenType::Ident, "x"),
+            Token::zero(TokenType::Equal, "="),
+            Token::zero(TokenType::CloseParen, ")"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Ident, "print"),
+            Token::zero(TokenType::OpenParen, "("),
+            Token::zero(TokenType::CloseSquareBracket, "]"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Semicolon, ";"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Pub, "pub"),
+            Token::zero(TokenType::Fn, "fn"),
+            Token::zero(TokenType::Ident, "main"),
+            Token::zero(TokenType::OpenParen, "("),
+            Token::zero(TokenType::CloseParen, ")"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Semicolon, ";"),
+            Token::zero(TokenType::Newline, "\n"),
+        ];
+
+        let parser = FeSyntaxParser::new(
+            Default::default(),
+            Arc::new(Mutex::new(FeTokenPackage::File(FeTokenFile {
+                name: TokenPackageName("".into()),
+                path: "".into(),
+                tokens: Arc::new(Mutex::new(tokens)),
+            }))),
+            NodeIdGen::Zero(ZeroNodeIdGen {}),
+        );
+
+        let (ast, errors) = parser.parse_with_errors()?;
+
+        let messages = errors
+            .iter()
+            .map(|e| e.message.as_ref())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Expected an expression. Found ')'",
+                "Expected an expression. Found ']'",
+            ]
+        );
+
+        let FeSyntaxPackage::File(ast) = ast else {
+            panic!();
+        };
+
+        assert_eq!(ast.syntax.try_lock().unwrap().decls.len(), 2);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_rejects_infinite_decimal() -> Result {
+        let tokens = vec![
+            Token::zero(TokenType::Fn, "fn"),
+            Token::zero(TokenType::Ident, "huge"),
+            Token::zero(TokenType::OpenParen, "("),
+            Token::zero(TokenType::CloseParen, ")"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Const, "const"),
+            Token::zero(TokenType::Ident, "h"),
+            Token::zero(TokenType::Equal, "="),
+            Token::zero(TokenType::DecimalNumber, "1e400"),
+            Token::zero(TokenType::Newline, "\n"),
+            Token::zero(TokenType::Semicolon, ";"),
+            Token::zero(TokenType::Newline, "\n"),
+        ];
+
+        let parser = FeSyntaxParser::new(
+            Default::default(),
+            Arc::new(Mutex::new(FeTokenPackage::File(FeTokenFile {
+                name: TokenPackageName("".into()),
+                path: "".into(),
+                tokens: Arc::new(Mutex::new(tokens)),
+            }))),
+            NodeIdGen::Zero(ZeroNodeIdGen {}),
+        );
+
+        let (_, errors) = parser.parse_with_errors()?;
+
+        let messages = errors
+            .iter()
+            .map(|e| e.message.as_ref())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(messages, vec!["Decimal literal 1e400 is too large"]);
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_recovers_inside_match_arms() -> Result {
+        let source = FeSourcePackage::File(FeSourceFile {
+            name: SourcePackageName("_main".into()),
+            path: "./_main.fe".into(),
+            content: "fn pick(n: Int): Int
+    mut x = 0
+    match n
+    0 then x = 1
+    _ then x = 2
+    ;
+    return x
+;
+"
+            .into(),
+        });
+
+        let tokens = FeLexer::scan_package(Default::default(), Arc::new(Mutex::new(source)))?;
+
+        let parser = FeSyntaxParser::new(
+            Default::default(),
+            Arc::new(Mutex::new(tokens)),
+            NodeIdGen::Zero(ZeroNodeIdGen {}),
+        );
+
+        let (_, errors) = parser.parse_with_errors()?;
+
+        let errors = errors
+            .iter()
+            .map(|e| {
+                (
+                    e.message.as_ref(),
+                    e.primary.as_ref().unwrap().span.start.line,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(errors, vec![("Expected newline after match arm", 4)]);
+
+        return Ok(());
+    }
 }
@@ -4,7 +4,10 @@ use crate::result::Result;
 use crate::source::*;
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// The path the main file is reported with, relative to the source directory.
+pub const MAIN_FILE_PATH: &str = "./_main.fe";
 use std::sync::{Arc, Mutex};
 
 pub struct SourceReader {
@@ -20,6 +23,15 @@ impl SourceReader {
         return Self { cfg };
     }
 
+    /// Where a file read by [`Self::read`] is on disk, e.g. for writing fixes back to it.
+    pub fn disk_path(&self, path: &Path) -> PathBuf {
+        if path == Path::new(MAIN_FILE_PATH) {
+            return self.cfg.src_dir.join("_main.fe");
+        }
+
+        return path.to_path_buf();
+    }
+
     pub fn read(&self) -> Result<FeSourcePackage> {
         fn build_dir_pkg(dir: PathBuf, name: SourcePackageName) -> Result<FeSourcePackage> {
             let src_dir_entries = dir.read_dir()?;
@@ -136,7 +148,7 @@ impl SourceReader {
             path: ".".into(),
             entry_file: FeSourceFile {
                 name: SourcePackageName("_main".into()),
-                path: "./_main.fe".into(),
+                path: MAIN_FILE_PATH.into(),
                 content: main_content.into(),
             },
             local_packages,
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 #[derive(Debug, Clone)]
@@ -8,6 +8,24 @@ pub enum FeSourcePackage {
     Dir(FeSourceDir),
 }
 
+impl FeSourcePackage {
+    pub fn find_file(&self, path: &Path) -> Option<FeSourceFile> {
+        match self {
+            Self::File(file) => return (file.path == path).then(|| file.clone()),
+            Self::Dir(dir) => {
+                if dir.entry_file.path == path {
+                    return Some(dir.entry_file.clone());
+                }
+
+                return dir
+                    .local_packages
+                    .values()
+                    .find_map(|pkg| pkg.try_lock().unwrap().find_file(path));
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FeSourceFile {
     pub name: SourcePackageName,
@@ -1,13 +1,15 @@
 use super::*;
 
 use crate::log;
-use crate::token::Token;
+use crate::token::{Span, Token};
 use crate::utils::{fe_from, fe_try_from, from, invert, try_from};
 
 #[derive(Debug, Clone)]
 pub enum Decl<T: ResolvedType = ()> {
     Fn(Arc<Mutex<FnDecl<T>>>),
     Struct(Arc<Mutex<StructDecl<T>>>),
+    Enum(Arc<Mutex<EnumDecl<T>>>),
+    Impl(Arc<Mutex<ImplDecl<T>>>),
 }
 
 impl<T: ResolvedType> PartialEq for Decl<T> {
@@ -27,6 +29,20 @@ impl<T: ResolvedType> PartialEq for Decl<T> {
                 let cloned = { d.try_lock().unwrap().clone() };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::Enum(d) => {
+                let Self::Enum(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
+            Self::Impl(d) => {
+                let Self::Impl(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
         }
     }
 }
@@ -36,6 +52,8 @@ impl<T: ResolvedType> Node<Decl> for Decl<T> {
         match self {
             Self::Fn(decl) => return decl.try_lock().unwrap().node_id(),
             Self::Struct(decl) => return decl.try_lock().unwrap().node_id(),
+            Self::Enum(decl) => return decl.try_lock().unwrap().node_id(),
+            Self::Impl(decl) => return decl.try_lock().unwrap().node_id(),
         }
     }
 
@@ -43,6 +61,19 @@ impl<T: ResolvedType> Node<Decl> for Decl<T> {
         match self {
             Self::Fn(decl) => return decl.try_lock().unwrap().set_node_id(id),
             Self::Struct(decl) => return decl.try_lock().unwrap().set_node_id(id),
+            Self::Enum(decl) => return decl.try_lock().unwrap().set_node_id(id),
+            Self::Impl(decl) => return decl.try_lock().unwrap().set_node_id(id),
+        }
+    }
+}
+
+impl<T: ResolvedType> Spanned for Decl<T> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Fn(decl) => return decl.try_lock().unwrap().span(),
+            Self::Struct(decl) => return decl.try_lock().unwrap().span(),
+            Self::Enum(decl) => return decl.try_lock().unwrap().span(),
+            Self::Impl(decl) => return decl.try_lock().unwrap().span(),
         }
     }
 }
@@ -52,6 +83,8 @@ impl<T: ResolvedType> From<Decl<()>> for Decl<Option<T>> {
         match value {
             Decl::Fn(decl) => return Self::Fn(fe_from(decl)),
             Decl::Struct(decl) => return Self::Struct(fe_from(decl)),
+            Decl::Enum(decl) => return Self::Enum(fe_from(decl)),
+            Decl::Impl(decl) => return Self::Impl(fe_from(decl)),
         }
     }
 }
@@ -61,6 +94,8 @@ impl<T: ResolvedType> Resolvable for Decl<Option<T>> {
         match self {
             Self::Fn(decl) => return decl.try_lock().unwrap().is_resolved(),
             Self::Struct(decl) => return decl.try_lock().unwrap().is_resolved(),
+            Self::Enum(decl) => return decl.try_lock().unwrap().is_resolved(),
+            Self::Impl(decl) => return decl.try_lock().unwrap().is_resolved(),
         }
     }
 }
@@ -72,6 +107,8 @@ impl<T: ResolvedType> TryFrom<Decl<Option<T>>> for Decl<T> {
         match value {
             Decl::Fn(decl) => return Ok(Self::Fn(fe_try_from(decl)?)),
             Decl::Struct(decl) => return Ok(Self::Struct(fe_try_from(decl)?)),
+            Decl::Enum(decl) => return Ok(Self::Enum(fe_try_from(decl)?)),
+            Decl::Impl(decl) => return Ok(Self::Impl(fe_try_from(decl)?)),
         }
     }
 }
@@ -81,16 +118,34 @@ pub enum DeclMod {
     Pub(Arc<Token>),
 }
 
+/// `@name(arg, arg)` on the lines before a declaration, e.g. `@allow(unused_variables)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclAttr {
+    pub at_token: Arc<Token>,
+    pub name: Arc<Token>,
+    pub open_paren_token: Arc<Token>,
+    pub args: Vec<DeclAttrArg>,
+    pub close_paren_token: Arc<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclAttrArg {
+    pub name: Arc<Token>,
+    pub comma_token: Option<Arc<Token>>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FnDecl<T: ResolvedType = ()> {
     pub id: NodeId<Decl>,
+    pub attrs: Vec<DeclAttr>,
     pub decl_mod: Option<DeclMod>,
     pub fn_mod: Option<FnMod>,
     pub fn_token: Arc<Token>,
     pub name: Arc<Token>,
-    pub generics: Option<FnDeclGenerics<T>>,
+    pub generics: Option<DeclGenerics>,
     pub open_paren_token: Arc<Token>,
     pub pre_comma_token: Option<Arc<Token>>,
+    pub self_param: Option<FnDeclSelfParam>,
     pub params: Vec<FnDeclParam<T>>,
     pub close_paren_token: Arc<Token>,
     pub return_type: Option<FnDeclReturnType<T>>,
@@ -112,17 +167,40 @@ impl<T: ResolvedType> Node<Decl> for FnDecl<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for FnDecl<T> {
+    fn span(&self) -> Span {
+        let start = match (&self.decl_mod, &self.fn_mod) {
+            (Some(DeclMod::Pub(token)), _) => token,
+
+            (None, Some(FnMod::Pure(token)))
+            | (None, Some(FnMod::Safe(token)))
+            | (None, Some(FnMod::Norm(token)))
+            | (None, Some(FnMod::Risk(token))) => token,
+
+            (None, None) => &self.fn_token,
+        };
+
+        if let Some(return_type) = &self.return_type {
+            return start.span.to(&return_type.static_type.span());
+        }
+
+        return start.span.to(&self.close_paren_token.span);
+    }
+}
+
 impl<T: ResolvedType> From<FnDecl<()>> for FnDecl<Option<T>> {
     fn from(value: FnDecl<()>) -> Self {
         return Self {
             id: value.id,
+            attrs: value.attrs,
             decl_mod: value.decl_mod,
             fn_mod: value.fn_mod,
             fn_token: value.fn_token,
             name: value.name,
-            generics: value.generics.map(from),
+            generics: value.generics,
             open_paren_token: value.open_paren_token,
             pre_comma_token: value.pre_comma_token,
+            self_param: value.self_param,
             params: value.params.into_iter().map(from).collect(),
             close_paren_token: value.close_paren_token,
             return_type: value.return_type.map(from),
@@ -134,12 +212,6 @@ impl<T: ResolvedType> From<FnDecl<()>> for FnDecl<Option<T>> {
 
 impl<T: ResolvedType> Resolvable for FnDecl<Option<T>> {
     fn is_signature_resolved(&self) -> bool {
-        if let Some(generics) = &self.generics {
-            if !generics.is_resolved() {
-                return log::trace!(false);
-            }
-        }
-
         for param in &self.params {
             if !param.is_resolved() {
                 return log::trace!(false);
@@ -174,13 +246,15 @@ impl<T: ResolvedType> TryFrom<FnDecl<Option<T>>> for FnDecl<T> {
     fn try_from(value: FnDecl<Option<T>>) -> Result<Self, Self::Error> {
         return Ok(Self {
             id: value.id,
+            attrs: value.attrs,
             decl_mod: value.decl_mod,
             fn_mod: value.fn_mod,
             fn_token: value.fn_token,
             name: value.name,
-            generics: invert(value.generics.map(try_from))?,
+            generics: value.generics,
             open_paren_token: value.open_paren_token,
             pre_comma_token: value.pre_comma_token,
+            self_param: value.self_param,
             params: value
                 .params
                 .into_iter()
@@ -202,36 +276,38 @@ pub enum FnMod {
     Risk(Arc<Token>),
 }
 
+/// The type parameters of a declaration, like the `<T>` in `fn id<T>(x: T): T`
 #[derive(Debug, Clone, PartialEq)]
-pub struct FnDeclGenerics<T: ResolvedType = ()> {
-    pub resolved_type: T,
+pub struct DeclGenerics {
+    pub open_angle_token: Arc<Token>,
+    pub params: Vec<DeclGenericParam>,
+    pub close_angle_token: Arc<Token>,
 }
 
-impl<T: ResolvedType> From<FnDeclGenerics<()>> for FnDeclGenerics<Option<T>> {
-    fn from(_: FnDeclGenerics<()>) -> Self {
-        return Self {
-            resolved_type: None,
-        };
+impl DeclGenerics {
+    pub fn names(&self) -> Vec<Arc<str>> {
+        return self
+            .params
+            .iter()
+            .map(|param| param.name.lexeme.clone())
+            .collect();
     }
 }
 
-impl<T: ResolvedType> Resolvable for FnDeclGenerics<Option<T>> {
-    fn is_resolved(&self) -> bool {
-        return self.resolved_type.is_some();
-    }
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclGenericParam {
+    pub name: Arc<Token>,
+    pub comma_token: Option<Arc<Token>>,
 }
 
-impl<T: ResolvedType> TryFrom<FnDeclGenerics<Option<T>>> for FnDeclGenerics<T> {
-    type Error = FinalizeResolveTypeError;
-
-    fn try_from(value: FnDeclGenerics<Option<T>>) -> Result<Self, Self::Error> {
-        return Ok(Self {
-            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
-                file: file!(),
-                line: line!(),
-            })?,
-        });
-    }
+/// How a method takes the instance it's called on: `self`, `mut self`, `&self`
+/// or `&mut self`
+#[derive(Debug, Clone, PartialEq)]
+pub struct FnDeclSelfParam {
+    pub ref_type: Option<RefType>,
+    pub mut_token: Option<Arc<Token>>,
+    pub self_token: Arc<Token>,
+    pub comma_token: Option<Arc<Token>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -455,10 +531,11 @@ impl<T: ResolvedType, S: PartialEq> TryFrom<CodeBlock<Option<T>, S>> for CodeBlo
 #[derive(Debug, Clone, PartialEq)]
 pub struct StructDecl<T: ResolvedType = ()> {
     pub id: NodeId<Decl>,
+    pub attrs: Vec<DeclAttr>,
     pub decl_mod: Option<DeclMod>,
     pub struct_token: Arc<Token>,
     pub name: Arc<Token>,
-    pub generics: Option<StructDeclGenerics<T>>,
+    pub generics: Option<DeclGenerics>,
     pub open_squirly_brace_token: Arc<Token>,
     pub pre_comma_token: Option<Arc<Token>>,
     pub fields: Vec<StructDeclField<T>>,
@@ -475,14 +552,26 @@ impl<T: ResolvedType> Node<Decl> for StructDecl<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for StructDecl<T> {
+    fn span(&self) -> Span {
+        let start = match &self.decl_mod {
+            Some(DeclMod::Pub(token)) => token,
+            None => &self.struct_token,
+        };
+
+        return start.span.to(&self.close_squirly_brace_token.span);
+    }
+}
+
 impl<T: ResolvedType> From<StructDecl<()>> for StructDecl<Option<T>> {
     fn from(value: StructDecl<()>) -> Self {
         return Self {
             id: value.id,
+            attrs: value.attrs,
             decl_mod: value.decl_mod,
             struct_token: value.struct_token,
             name: value.name,
-            generics: value.generics.map(from),
+            generics: value.generics,
             open_squirly_brace_token: value.open_squirly_brace_token,
             pre_comma_token: value.pre_comma_token,
             fields: value.fields.into_iter().map(from).collect(),
@@ -493,12 +582,6 @@ impl<T: ResolvedType> From<StructDecl<()>> for StructDecl<Option<T>> {
 
 impl<T: ResolvedType> Resolvable for StructDecl<Option<T>> {
     fn is_signature_resolved(&self) -> bool {
-        if let Some(generics) = &self.generics {
-            if !generics.is_resolved() {
-                return log::trace!(false);
-            }
-        }
-
         for field in &self.fields {
             if let Some(StructFieldMod::Pub(_)) = field.field_mod {
                 if !field.is_resolved() {
@@ -511,12 +594,6 @@ impl<T: ResolvedType> Resolvable for StructDecl<Option<T>> {
     }
 
     fn is_resolved(&self) -> bool {
-        if let Some(generics) = &self.generics {
-            if !generics.is_resolved() {
-                return log::trace!(false);
-            }
-        }
-
         for field in &self.fields {
             if !field.is_resolved() {
                 return log::trace!(false);
@@ -533,10 +610,11 @@ impl<T: ResolvedType> TryFrom<StructDecl<Option<T>>> for StructDecl<T> {
     fn try_from(value: StructDecl<Option<T>>) -> Result<Self, Self::Error> {
         return Ok(Self {
             id: value.id,
+            attrs: value.attrs,
             decl_mod: value.decl_mod,
             struct_token: value.struct_token,
             name: value.name,
-            generics: invert(value.generics.map(try_from))?,
+            generics: value.generics,
             open_squirly_brace_token: value.open_squirly_brace_token,
             pre_comma_token: value.pre_comma_token,
             fields: value
@@ -549,38 +627,6 @@ impl<T: ResolvedType> TryFrom<StructDecl<Option<T>>> for StructDecl<T> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct StructDeclGenerics<T: ResolvedType = ()> {
-    pub resolved_type: T,
-}
-
-impl<T: ResolvedType> From<StructDeclGenerics<()>> for StructDeclGenerics<Option<T>> {
-    fn from(_: StructDeclGenerics<()>) -> Self {
-        return Self {
-            resolved_type: None,
-        };
-    }
-}
-
-impl<T: ResolvedType> Resolvable for StructDeclGenerics<Option<T>> {
-    fn is_resolved(&self) -> bool {
-        return self.resolved_type.is_some();
-    }
-}
-
-impl<T: ResolvedType> TryFrom<StructDeclGenerics<Option<T>>> for StructDeclGenerics<T> {
-    type Error = FinalizeResolveTypeError;
-
-    fn try_from(value: StructDeclGenerics<Option<T>>) -> Result<Self, Self::Error> {
-        return Ok(Self {
-            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
-                file: file!(),
-                line: line!(),
-            })?,
-        });
-    }
-}
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct StructDeclField<T: ResolvedType = ()> {
     pub field_mod: Option<StructFieldMod>,
@@ -631,10 +677,401 @@ pub enum StructFieldMod {
     Pub(Arc<Token>),
 }
 
+/// `enum Name { A, B(Type), C { field: Type } }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDecl<T: ResolvedType = ()> {
+    pub id: NodeId<Decl>,
+    pub attrs: Vec<DeclAttr>,
+    pub decl_mod: Option<DeclMod>,
+    pub enum_token: Arc<Token>,
+    pub name: Arc<Token>,
+    pub open_squirly_brace_token: Arc<Token>,
+    pub pre_comma_token: Option<Arc<Token>>,
+    pub variants: Vec<EnumDeclVariant<T>>,
+    pub close_squirly_brace_token: Arc<Token>,
+
+    // like FnDecl's, otherwise an enum of only unit variants is always
+    // resolved and never gets added to scope
+    pub has_resolved_variants: bool,
+}
+
+impl<T: ResolvedType> Node<Decl> for EnumDecl<T> {
+    fn node_id(&self) -> NodeId<Decl> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Decl>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for EnumDecl<T> {
+    fn span(&self) -> Span {
+        let start = match &self.decl_mod {
+            Some(DeclMod::Pub(token)) => token,
+            None => &self.enum_token,
+        };
+
+        return start.span.to(&self.close_squirly_brace_token.span);
+    }
+}
+
+impl<T: ResolvedType> From<EnumDecl<()>> for EnumDecl<Option<T>> {
+    fn from(value: EnumDecl<()>) -> Self {
+        return Self {
+            id: value.id,
+            attrs: value.attrs,
+            decl_mod: value.decl_mod,
+            enum_token: value.enum_token,
+            name: value.name,
+            open_squirly_brace_token: value.open_squirly_brace_token,
+            pre_comma_token: value.pre_comma_token,
+            variants: value.variants.into_iter().map(from).collect(),
+            close_squirly_brace_token: value.close_squirly_brace_token,
+            has_resolved_variants: false,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for EnumDecl<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        for variant in &self.variants {
+            if !variant.is_resolved() {
+                return log::trace!(false);
+            }
+        }
+
+        return self.has_resolved_variants;
+    }
+}
+
+impl<T: ResolvedType> TryFrom<EnumDecl<Option<T>>> for EnumDecl<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: EnumDecl<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            attrs: value.attrs,
+            decl_mod: value.decl_mod,
+            enum_token: value.enum_token,
+            name: value.name,
+            open_squirly_brace_token: value.open_squirly_brace_token,
+            pre_comma_token: value.pre_comma_token,
+            variants: value
+                .variants
+                .into_iter()
+                .map(try_from)
+                .collect::<Result<Vec<EnumDeclVariant<T>>, Self::Error>>()?,
+            close_squirly_brace_token: value.close_squirly_brace_token,
+            has_resolved_variants: value.has_resolved_variants,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDeclVariant<T: ResolvedType = ()> {
+    pub name: Arc<Token>,
+    pub fields: EnumDeclVariantFields<T>,
+    pub comma_token: Option<Arc<Token>>,
+}
+
+impl<T: ResolvedType> From<EnumDeclVariant<()>> for EnumDeclVariant<Option<T>> {
+    fn from(value: EnumDeclVariant<()>) -> Self {
+        return Self {
+            name: value.name,
+            fields: from(value.fields),
+            comma_token: value.comma_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for EnumDeclVariant<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        return self.fields.is_resolved();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<EnumDeclVariant<Option<T>>> for EnumDeclVariant<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: EnumDeclVariant<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            name: value.name,
+            fields: try_from(value.fields)?,
+            comma_token: value.comma_token,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnumDeclVariantFields<T: ResolvedType = ()> {
+    /// `Name`
+    Unit,
+
+    /// `Name(Type, Type)`
+    Tuple {
+        open_paren_token: Arc<Token>,
+        fields: Vec<EnumDeclTupleField<T>>,
+        close_paren_token: Arc<Token>,
+    },
+
+    /// `Name { field: Type }`
+    Struct {
+        open_squirly_brace_token: Arc<Token>,
+        fields: Vec<StructDeclField<T>>,
+        close_squirly_brace_token: Arc<Token>,
+    },
+}
+
+impl<T: ResolvedType> From<EnumDeclVariantFields<()>> for EnumDeclVariantFields<Option<T>> {
+    fn from(value: EnumDeclVariantFields<()>) -> Self {
+        match value {
+            EnumDeclVariantFields::Unit => return Self::Unit,
+
+            EnumDeclVariantFields::Tuple {
+                open_paren_token,
+                fields,
+                close_paren_token,
+            } => {
+                return Self::Tuple {
+                    open_paren_token,
+                    fields: fields.into_iter().map(from).collect(),
+                    close_paren_token,
+                };
+            }
+
+            EnumDeclVariantFields::Struct {
+                open_squirly_brace_token,
+                fields,
+                close_squirly_brace_token,
+            } => {
+                return Self::Struct {
+                    open_squirly_brace_token,
+                    fields: fields.into_iter().map(from).collect(),
+                    close_squirly_brace_token,
+                };
+            }
+        }
+    }
+}
+
+impl<T: ResolvedType> Resolvable for EnumDeclVariantFields<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        match self {
+            Self::Unit => return true,
+
+            Self::Tuple { fields, .. } => {
+                for field in fields {
+                    if !field.static_type_ref.is_resolved() {
+                        return log::trace!(false);
+                    }
+                }
+
+                return true;
+            }
+
+            Self::Struct { fields, .. } => {
+                for field in fields {
+                    if !field.is_resolved() {
+                        return log::trace!(false);
+                    }
+                }
+
+                return true;
+            }
+        }
+    }
+}
+
+impl<T: ResolvedType> TryFrom<EnumDeclVariantFields<Option<T>>> for EnumDeclVariantFields<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: EnumDeclVariantFields<Option<T>>) -> Result<Self, Self::Error> {
+        match value {
+            EnumDeclVariantFields::Unit => return Ok(Self::Unit),
+
+            EnumDeclVariantFields::Tuple {
+                open_paren_token,
+                fields,
+                close_paren_token,
+            } => {
+                return Ok(Self::Tuple {
+                    open_paren_token,
+                    fields: fields
+                        .into_iter()
+                        .map(try_from)
+                        .collect::<Result<Vec<EnumDeclTupleField<T>>, Self::Error>>()?,
+                    close_paren_token,
+                });
+            }
+
+            EnumDeclVariantFields::Struct {
+                open_squirly_brace_token,
+                fields,
+                close_squirly_brace_token,
+            } => {
+                return Ok(Self::Struct {
+                    open_squirly_brace_token,
+                    fields: fields
+                        .into_iter()
+                        .map(try_from)
+                        .collect::<Result<Vec<StructDeclField<T>>, Self::Error>>()?,
+                    close_squirly_brace_token,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumDeclTupleField<T: ResolvedType = ()> {
+    pub static_type_ref: StaticType<T>,
+    pub comma_token: Option<Arc<Token>>,
+}
+
+impl<T: ResolvedType> From<EnumDeclTupleField<()>> for EnumDeclTupleField<Option<T>> {
+    fn from(value: EnumDeclTupleField<()>) -> Self {
+        return Self {
+            static_type_ref: from(value.static_type_ref),
+            comma_token: value.comma_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<EnumDeclTupleField<Option<T>>> for EnumDeclTupleField<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: EnumDeclTupleField<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            static_type_ref: try_from(value.static_type_ref)?,
+            comma_token: value.comma_token,
+        });
+    }
+}
+
+/// `impl Name`, followed by the methods and associated functions of the struct
+/// `Name`. The struct's type parameters are in scope for all of them.
+#[derive(Debug, Clone)]
+pub struct ImplDecl<T: ResolvedType = ()> {
+    pub id: NodeId<Decl>,
+    pub attrs: Vec<DeclAttr>,
+    pub impl_token: Arc<Token>,
+    pub target: StaticPath<T>,
+    pub methods: Vec<Arc<Mutex<FnDecl<T>>>>,
+    pub end_semicolon_token: Arc<Token>,
+}
+
+impl<T: ResolvedType> PartialEq for ImplDecl<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.id != other.id
+            || self.attrs != other.attrs
+            || self.impl_token != other.impl_token
+            || self.target != other.target
+            || self.end_semicolon_token != other.end_semicolon_token
+        {
+            return false;
+        }
+
+        if self.methods.len() != other.methods.len() {
+            return false;
+        }
+
+        for (method, other) in self.methods.iter().zip(&other.methods) {
+            let method = { method.try_lock().unwrap().clone() };
+
+            if method != *other.try_lock().unwrap() {
+                return false;
+            }
+        }
+
+        return true;
+    }
+}
+
+impl<T: ResolvedType> Node<Decl> for ImplDecl<T> {
+    fn node_id(&self) -> NodeId<Decl> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Decl>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for ImplDecl<T> {
+    fn span(&self) -> Span {
+        return self.impl_token.span.to(&self.target.span());
+    }
+}
+
+impl<T: ResolvedType> From<ImplDecl<()>> for ImplDecl<Option<T>> {
+    fn from(value: ImplDecl<()>) -> Self {
+        return Self {
+            id: value.id,
+            attrs: value.attrs,
+            impl_token: value.impl_token,
+            target: from(value.target),
+            methods: value.methods.into_iter().map(fe_from).collect(),
+            end_semicolon_token: value.end_semicolon_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for ImplDecl<Option<T>> {
+    fn is_signature_resolved(&self) -> bool {
+        if !self.target.is_resolved() {
+            return log::trace!(false);
+        }
+
+        for method in &self.methods {
+            if !method.try_lock().unwrap().is_signature_resolved() {
+                return log::trace!(false);
+            }
+        }
+
+        return true;
+    }
+
+    fn is_resolved(&self) -> bool {
+        if !self.target.is_resolved() {
+            return log::trace!(false);
+        }
+
+        for method in &self.methods {
+            if !method.try_lock().unwrap().is_resolved() {
+                return log::trace!(false);
+            }
+        }
+
+        return true;
+    }
+}
+
+impl<T: ResolvedType> TryFrom<ImplDecl<Option<T>>> for ImplDecl<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: ImplDecl<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            attrs: value.attrs,
+            impl_token: value.impl_token,
+            target: try_from(value.target)?,
+            methods: value
+                .methods
+                .into_iter()
+                .map(fe_try_from)
+                .collect::<Result<Vec<Arc<Mutex<FnDecl<T>>>>, Self::Error>>()?,
+            end_semicolon_token: value.end_semicolon_token,
+        });
+    }
+}
+
 // Visitor pattern
 pub trait DeclVisitor<T: ResolvedType, R = ()> {
     fn visit_function_decl(&mut self, decl: Arc<Mutex<FnDecl<T>>>) -> R;
     fn visit_struct_decl(&mut self, decl: Arc<Mutex<StructDecl<T>>>) -> R;
+    fn visit_enum_decl(&mut self, decl: Arc<Mutex<EnumDecl<T>>>) -> R;
+    fn visit_impl_decl(&mut self, decl: Arc<Mutex<ImplDecl<T>>>) -> R;
 }
 
 pub trait DeclAccept<T: ResolvedType, R, V: DeclVisitor<T, R>> {
@@ -646,6 +1083,8 @@ impl<T: ResolvedType, R, V: DeclVisitor<T, R>> DeclAccept<T, R, V> for Decl<T> {
         return match self {
             Self::Fn(decl) => decl.accept(visitor),
             Self::Struct(decl) => decl.accept(visitor),
+            Self::Enum(decl) => decl.accept(visitor),
+            Self::Impl(decl) => decl.accept(visitor),
         };
     }
 }
@@ -661,3 +1100,15 @@ impl<T: ResolvedType, R, V: DeclVisitor<T, R>> DeclAccept<T, R, V> for Arc<Mutex
         return visitor.visit_struct_decl(self.clone());
     }
 }
+
+impl<T: ResolvedType, R, V: DeclVisitor<T, R>> DeclAccept<T, R, V> for Arc<Mutex<EnumDecl<T>>> {
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_enum_decl(self.clone());
+    }
+}
+
+impl<T: ResolvedType, R, V: DeclVisitor<T, R>> DeclAccept<T, R, V> for Arc<Mutex<ImplDecl<T>>> {
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_impl_decl(self.clone());
+    }
+}
@@ -2,12 +2,13 @@ use super::*;
 
 use crate::log;
 use crate::result::Result;
-use crate::token::Token;
+use crate::token::{Span, Token};
 use crate::utils::{fe_from, fe_try_from, from, try_from};
 
 #[derive(Debug, Clone)]
 pub enum Expr<T: ResolvedType = ()> {
     BoolLiteral(Arc<Mutex<BoolLiteralExpr<T>>>),
+    NoneLiteral(Arc<Mutex<NoneLiteralExpr<T>>>),
     NumberLiteral(Arc<Mutex<NumberLiteralExpr<T>>>),
     PlainStringLiteral(Arc<Mutex<PlainStringLiteralExpr<T>>>),
     FmtStringLiteral(Arc<Mutex<FmtStringLiteralExpr<T>>>),
@@ -15,12 +16,15 @@ pub enum Expr<T: ResolvedType = ()> {
     Call(Arc<Mutex<CallExpr<T>>>),
     Unary(Arc<Mutex<UnaryExpr<T>>>),
     Binary(Arc<Mutex<BinaryExpr<T>>>),
+    Logical(Arc<Mutex<LogicalExpr<T>>>),
     StaticRef(Arc<Mutex<StaticRefExpr<T>>>),
     Construct(Arc<Mutex<ConstructExpr<T>>>),
     Get(Arc<Mutex<GetExpr<T>>>),
     If(Arc<Mutex<IfExpr<T>>>),
     Loop(Arc<Mutex<LoopExpr<T>>>),
     While(Arc<Mutex<WhileExpr<T>>>),
+    For(Arc<Mutex<ForExpr<T>>>),
+    Match(Arc<Mutex<MatchExpr<T>>>),
 }
 
 impl<T: ResolvedType> PartialEq for Expr<T> {
@@ -33,6 +37,13 @@ impl<T: ResolvedType> PartialEq for Expr<T> {
                 let cloned = { d.try_lock().unwrap().clone() };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::NoneLiteral(d) => {
+                let Self::NoneLiteral(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
             Self::NumberLiteral(d) => {
                 let Self::NumberLiteral(other) = other else {
                     return false;
@@ -82,6 +93,13 @@ impl<T: ResolvedType> PartialEq for Expr<T> {
                 let cloned = { d.try_lock().unwrap().clone() };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::Logical(d) => {
+                let Self::Logical(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
             Self::StaticRef(d) => {
                 let Self::StaticRef(other) = other else {
                     return false;
@@ -117,6 +135,13 @@ impl<T: ResolvedType> PartialEq for Expr<T> {
                 let cloned = { d.try_lock().unwrap().clone() };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::For(d) => {
+                let Self::For(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
             Self::Loop(d) => {
                 let Self::Loop(other) = other else {
                     return false;
@@ -124,6 +149,13 @@ impl<T: ResolvedType> PartialEq for Expr<T> {
                 let cloned = { d.try_lock().unwrap().clone() };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::Match(d) => {
+                let Self::Match(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
         }
     }
 }
@@ -132,6 +164,7 @@ impl<T: ResolvedType> Expr<T> {
     pub fn resolved_type(&self) -> Option<T> {
         match self {
             Self::BoolLiteral(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
+            Self::NoneLiteral(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::NumberLiteral(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::PlainStringLiteral(v) => {
                 return Some(v.try_lock().unwrap().resolved_type.clone())
@@ -141,12 +174,15 @@ impl<T: ResolvedType> Expr<T> {
             Self::Call(v) => return v.try_lock().unwrap().resolved_type.clone(),
             Self::Unary(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::Binary(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
+            Self::Logical(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::StaticRef(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::Construct(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::Get(v) => return Some(v.try_lock().unwrap().resolved_type.clone()),
             Self::If(v) => return v.try_lock().unwrap().resolved_type.clone(),
             Self::Loop(v) => return v.try_lock().unwrap().resolved_type.clone(),
             Self::While(v) => return v.try_lock().unwrap().resolved_type.clone(),
+            Self::For(v) => return v.try_lock().unwrap().resolved_type.clone(),
+            Self::Match(v) => return v.try_lock().unwrap().resolved_type.clone(),
         }
     }
 }
@@ -155,6 +191,7 @@ impl<T: ResolvedType> Node<Expr> for Expr<T> {
     fn node_id(&self) -> NodeId<Expr> {
         match self {
             Self::BoolLiteral(expr) => return expr.try_lock().unwrap().node_id(),
+            Self::NoneLiteral(expr) => return expr.try_lock().unwrap().node_id(),
             Self::NumberLiteral(expr) => return expr.try_lock().unwrap().node_id(),
             Self::PlainStringLiteral(expr) => return expr.try_lock().unwrap().node_id(),
             Self::FmtStringLiteral(expr) => return expr.try_lock().unwrap().node_id(),
@@ -162,18 +199,22 @@ impl<T: ResolvedType> Node<Expr> for Expr<T> {
             Self::Call(expr) => return expr.try_lock().unwrap().node_id(),
             Self::Unary(expr) => return expr.try_lock().unwrap().node_id(),
             Self::Binary(expr) => return expr.try_lock().unwrap().node_id(),
+            Self::Logical(expr) => return expr.try_lock().unwrap().node_id(),
             Self::StaticRef(expr) => return expr.try_lock().unwrap().node_id(),
             Self::Construct(expr) => return expr.try_lock().unwrap().node_id(),
             Self::Get(expr) => return expr.try_lock().unwrap().node_id(),
             Self::If(expr) => return expr.try_lock().unwrap().node_id(),
             Self::Loop(expr) => return expr.try_lock().unwrap().node_id(),
             Self::While(expr) => return expr.try_lock().unwrap().node_id(),
+            Self::For(expr) => return expr.try_lock().unwrap().node_id(),
+            Self::Match(expr) => return expr.try_lock().unwrap().node_id(),
         }
     }
 
     fn set_node_id(&mut self, id: NodeId<Expr>) {
         match self {
             Self::BoolLiteral(expr) => return expr.try_lock().unwrap().set_node_id(id),
+            Self::NoneLiteral(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::NumberLiteral(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::PlainStringLiteral(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::FmtStringLiteral(expr) => return expr.try_lock().unwrap().set_node_id(id),
@@ -181,12 +222,40 @@ impl<T: ResolvedType> Node<Expr> for Expr<T> {
             Self::Call(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::Unary(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::Binary(expr) => return expr.try_lock().unwrap().set_node_id(id),
+            Self::Logical(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::StaticRef(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::Construct(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::Get(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::If(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::Loop(expr) => return expr.try_lock().unwrap().set_node_id(id),
             Self::While(expr) => return expr.try_lock().unwrap().set_node_id(id),
+            Self::For(expr) => return expr.try_lock().unwrap().set_node_id(id),
+            Self::Match(expr) => return expr.try_lock().unwrap().set_node_id(id),
+        }
+    }
+}
+
+impl<T: ResolvedType> Spanned for Expr<T> {
+    fn span(&self) -> Span {
+        match self {
+            Self::BoolLiteral(expr) => return expr.try_lock().unwrap().span(),
+            Self::NoneLiteral(expr) => return expr.try_lock().unwrap().span(),
+            Self::NumberLiteral(expr) => return expr.try_lock().unwrap().span(),
+            Self::PlainStringLiteral(expr) => return expr.try_lock().unwrap().span(),
+            Self::FmtStringLiteral(expr) => return expr.try_lock().unwrap().span(),
+            Self::Ident(expr) => return expr.try_lock().unwrap().span(),
+            Self::Call(expr) => return expr.try_lock().unwrap().span(),
+            Self::Unary(expr) => return expr.try_lock().unwrap().span(),
+            Self::Binary(expr) => return expr.try_lock().unwrap().span(),
+            Self::Logical(expr) => return expr.try_lock().unwrap().span(),
+            Self::StaticRef(expr) => return expr.try_lock().unwrap().span(),
+            Self::Construct(expr) => return expr.try_lock().unwrap().span(),
+            Self::Get(expr) => return expr.try_lock().unwrap().span(),
+            Self::If(expr) => return expr.try_lock().unwrap().span(),
+            Self::Loop(expr) => return expr.try_lock().unwrap().span(),
+            Self::While(expr) => return expr.try_lock().unwrap().span(),
+            Self::For(expr) => return expr.try_lock().unwrap().span(),
+            Self::Match(expr) => return expr.try_lock().unwrap().span(),
         }
     }
 }
@@ -195,6 +264,7 @@ impl<T: ResolvedType> From<Expr<()>> for Expr<Option<T>> {
     fn from(value: Expr<()>) -> Self {
         match value {
             Expr::BoolLiteral(expr) => return Self::BoolLiteral(fe_from(expr)),
+            Expr::NoneLiteral(expr) => return Self::NoneLiteral(fe_from(expr)),
             Expr::NumberLiteral(expr) => return Self::NumberLiteral(fe_from(expr)),
             Expr::PlainStringLiteral(expr) => return Self::PlainStringLiteral(fe_from(expr)),
             Expr::FmtStringLiteral(expr) => return Self::FmtStringLiteral(fe_from(expr)),
@@ -202,12 +272,15 @@ impl<T: ResolvedType> From<Expr<()>> for Expr<Option<T>> {
             Expr::Call(expr) => return Self::Call(fe_from(expr)),
             Expr::Unary(expr) => return Self::Unary(fe_from(expr)),
             Expr::Binary(expr) => return Self::Binary(fe_from(expr)),
+            Expr::Logical(expr) => return Self::Logical(fe_from(expr)),
             Expr::StaticRef(expr) => return Self::StaticRef(fe_from(expr)),
             Expr::Construct(expr) => return Self::Construct(fe_from(expr)),
             Expr::Get(expr) => return Self::Get(fe_from(expr)),
             Expr::If(expr) => return Self::If(fe_from(expr)),
             Expr::Loop(expr) => return Self::Loop(fe_from(expr)),
             Expr::While(expr) => return Self::While(fe_from(expr)),
+            Expr::For(expr) => return Self::For(fe_from(expr)),
+            Expr::Match(expr) => return Self::Match(fe_from(expr)),
         }
     }
 }
@@ -216,6 +289,7 @@ impl<T: ResolvedType> Resolvable for Expr<Option<T>> {
     fn is_resolved(&self) -> bool {
         match self {
             Expr::BoolLiteral(expr) => return expr.try_lock().unwrap().is_resolved(),
+            Expr::NoneLiteral(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::NumberLiteral(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::PlainStringLiteral(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::FmtStringLiteral(expr) => return expr.try_lock().unwrap().is_resolved(),
@@ -223,12 +297,15 @@ impl<T: ResolvedType> Resolvable for Expr<Option<T>> {
             Expr::Call(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::Unary(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::Binary(expr) => return expr.try_lock().unwrap().is_resolved(),
+            Expr::Logical(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::StaticRef(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::Construct(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::Get(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::If(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::Loop(expr) => return expr.try_lock().unwrap().is_resolved(),
             Expr::While(expr) => return expr.try_lock().unwrap().is_resolved(),
+            Expr::For(expr) => return expr.try_lock().unwrap().is_resolved(),
+            Expr::Match(expr) => return expr.try_lock().unwrap().is_resolved(),
         }
     }
 }
@@ -239,6 +316,7 @@ impl<T: ResolvedType> TryFrom<Expr<Option<T>>> for Expr<T> {
     fn try_from(value: Expr<Option<T>>) -> Result<Self, Self::Error> {
         match value {
             Expr::BoolLiteral(expr) => return Ok(Self::BoolLiteral(fe_try_from(expr)?)),
+            Expr::NoneLiteral(expr) => return Ok(Self::NoneLiteral(fe_try_from(expr)?)),
             Expr::NumberLiteral(expr) => return Ok(Self::NumberLiteral(fe_try_from(expr)?)),
             Expr::PlainStringLiteral(expr) => {
                 return Ok(Self::PlainStringLiteral(fe_try_from(expr)?))
@@ -248,12 +326,15 @@ impl<T: ResolvedType> TryFrom<Expr<Option<T>>> for Expr<T> {
             Expr::Call(expr) => return Ok(Self::Call(fe_try_from(expr)?)),
             Expr::Unary(expr) => return Ok(Self::Unary(fe_try_from(expr)?)),
             Expr::Binary(expr) => return Ok(Self::Binary(fe_try_from(expr)?)),
+            Expr::Logical(expr) => return Ok(Self::Logical(fe_try_from(expr)?)),
             Expr::StaticRef(expr) => return Ok(Self::StaticRef(fe_try_from(expr)?)),
             Expr::Construct(expr) => return Ok(Self::Construct(fe_try_from(expr)?)),
             Expr::Get(expr) => return Ok(Self::Get(fe_try_from(expr)?)),
             Expr::If(expr) => return Ok(Self::If(fe_try_from(expr)?)),
             Expr::Loop(expr) => return Ok(Self::Loop(fe_try_from(expr)?)),
             Expr::While(expr) => return Ok(Self::While(fe_try_from(expr)?)),
+            Expr::For(expr) => return Ok(Self::For(fe_try_from(expr)?)),
+            Expr::Match(expr) => return Ok(Self::Match(fe_try_from(expr)?)),
         }
     }
 }
@@ -310,6 +391,12 @@ impl<T: ResolvedType> Node<Expr> for BoolLiteralExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for BoolLiteralExpr<T> {
+    fn span(&self) -> Span {
+        return self.literal.span.clone();
+    }
+}
+
 impl<T: ResolvedType> From<BoolLiteralExpr<()>> for BoolLiteralExpr<Option<T>> {
     fn from(value: BoolLiteralExpr<()>) -> Self {
         return Self {
@@ -341,6 +428,61 @@ impl<T: ResolvedType> TryFrom<BoolLiteralExpr<Option<T>>> for BoolLiteralExpr<T>
     }
 }
 
+/// `none`, the empty value of an optional
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoneLiteralExpr<T: ResolvedType = ()> {
+    pub id: NodeId<Expr>,
+    pub literal: Arc<Token>,
+    pub resolved_type: T,
+}
+
+impl<T: ResolvedType> Node<Expr> for NoneLiteralExpr<T> {
+    fn node_id(&self) -> NodeId<Expr> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Expr>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for NoneLiteralExpr<T> {
+    fn span(&self) -> Span {
+        return self.literal.span.clone();
+    }
+}
+
+impl<T: ResolvedType> From<NoneLiteralExpr<()>> for NoneLiteralExpr<Option<T>> {
+    fn from(value: NoneLiteralExpr<()>) -> Self {
+        return Self {
+            id: value.id,
+            literal: value.literal,
+            resolved_type: None,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for NoneLiteralExpr<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        return self.resolved_type.is_some();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<NoneLiteralExpr<Option<T>>> for NoneLiteralExpr<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: NoneLiteralExpr<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            literal: value.literal,
+            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
+                file: file!(),
+                line: line!(),
+            })?,
+        });
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct NumberLiteralExpr<T: ResolvedType = ()> {
     pub id: NodeId<Expr>,
@@ -359,6 +501,12 @@ impl<T: ResolvedType> Node<Expr> for NumberLiteralExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for NumberLiteralExpr<T> {
+    fn span(&self) -> Span {
+        return self.literal.span.clone();
+    }
+}
+
 impl<T: ResolvedType> From<NumberLiteralExpr<()>> for NumberLiteralExpr<Option<T>> {
     fn from(value: NumberLiteralExpr<()>) -> Self {
         return Self {
@@ -416,6 +564,12 @@ impl<T: ResolvedType> Node<Expr> for PlainStringLiteralExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for PlainStringLiteralExpr<T> {
+    fn span(&self) -> Span {
+        return self.literal.span.clone();
+    }
+}
+
 impl<T: ResolvedType> From<PlainStringLiteralExpr<()>> for PlainStringLiteralExpr<Option<T>> {
     fn from(value: PlainStringLiteralExpr<()>) -> Self {
         return Self {
@@ -465,6 +619,16 @@ impl<T: ResolvedType> Node<Expr> for FmtStringLiteralExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for FmtStringLiteralExpr<T> {
+    fn span(&self) -> Span {
+        if let Some(last) = self.rest.last() {
+            return self.first.span.to(&last.expr.0.try_lock().unwrap().span());
+        }
+
+        return self.first.span.clone();
+    }
+}
+
 impl<T: ResolvedType> From<FmtStringLiteralExpr<()>> for FmtStringLiteralExpr<Option<T>> {
     fn from(value: FmtStringLiteralExpr<()>) -> Self {
         return Self {
@@ -562,6 +726,12 @@ impl<T: ResolvedType> Node<Expr> for IdentExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for IdentExpr<T> {
+    fn span(&self) -> Span {
+        return self.ident.span.clone();
+    }
+}
+
 impl<T: ResolvedType> From<IdentExpr<()>> for IdentExpr<Option<T>> {
     fn from(value: IdentExpr<()>) -> Self {
         return Self {
@@ -614,6 +784,18 @@ impl<T: ResolvedType> Node<Expr> for CallExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for CallExpr<T> {
+    fn span(&self) -> Span {
+        return self
+            .callee
+            .0
+            .try_lock()
+            .unwrap()
+            .span()
+            .to(&self.close_paren_token.span);
+    }
+}
+
 impl<T: ResolvedType> From<CallExpr<()>> for CallExpr<Option<T>> {
     fn from(value: CallExpr<()>) -> Self {
         return Self {
@@ -752,6 +934,19 @@ impl<T: ResolvedType> Node<Expr> for UnaryExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for UnaryExpr<T> {
+    fn span(&self) -> Span {
+        let op_token = match &self.op {
+            UnaryOp::Ref(RefType::Shared { ref_token, .. }) => ref_token,
+            UnaryOp::Ref(RefType::Mut { ref_token, .. }) => ref_token,
+            UnaryOp::Not(not_token) => not_token,
+            UnaryOp::Minus(minus_token) => minus_token,
+        };
+
+        return op_token.span.to(&self.value.0.try_lock().unwrap().span());
+    }
+}
+
 impl<T: ResolvedType> From<UnaryExpr<()>> for UnaryExpr<Option<T>> {
     fn from(value: UnaryExpr<()>) -> Self {
         return Self {
@@ -793,6 +988,7 @@ impl<T: ResolvedType> TryFrom<UnaryExpr<Option<T>>> for UnaryExpr<T> {
 pub enum UnaryOp {
     Ref(RefType),
     Not(Arc<Token>),
+    Minus(Arc<Token>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -814,6 +1010,15 @@ impl<T: ResolvedType> Node<Expr> for BinaryExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for BinaryExpr<T> {
+    fn span(&self) -> Span {
+        let lhs = self.lhs.0.try_lock().unwrap().span();
+        let rhs = self.rhs.0.try_lock().unwrap().span();
+
+        return lhs.to(&rhs);
+    }
+}
+
 impl<T: ResolvedType> From<BinaryExpr<()>> for BinaryExpr<Option<T>> {
     fn from(value: BinaryExpr<()>) -> Self {
         return Self {
@@ -861,10 +1066,124 @@ impl<T: ResolvedType> TryFrom<BinaryExpr<Option<T>>> for BinaryExpr<T> {
 pub enum BinaryOp {
     Add(Arc<Token>),
     Subtract(Arc<Token>),
+    Multiply(Arc<Token>),
+    Divide(Arc<Token>),
+    Modulo(Arc<Token>),
     Less(Arc<Token>),
     LessEq(Arc<Token>),
     Greater(Arc<Token>),
     GreaterEq(Arc<Token>),
+    Equal(Arc<Token>),
+    NotEqual(Arc<Token>),
+    Range(Arc<Token>),
+    RangeInclusive(Arc<Token>),
+}
+
+impl BinaryOp {
+    pub fn token(&self) -> &Arc<Token> {
+        match self {
+            Self::Add(token)
+            | Self::Subtract(token)
+            | Self::Multiply(token)
+            | Self::Divide(token)
+            | Self::Modulo(token)
+            | Self::Less(token)
+            | Self::LessEq(token)
+            | Self::Greater(token)
+            | Self::GreaterEq(token)
+            | Self::Equal(token)
+            | Self::NotEqual(token)
+            | Self::Range(token)
+            | Self::RangeInclusive(token) => return token,
+        }
+    }
+}
+
+/// `and` / `or`, kept apart from [`BinaryExpr`] since the right side is only
+/// evaluated when the left side doesn't decide the result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalExpr<T: ResolvedType = ()> {
+    pub id: NodeId<Expr>,
+    pub lhs: NestedExpr<T>,
+    pub op: LogicalOp,
+    pub rhs: NestedExpr<T>,
+    pub resolved_type: T,
+}
+
+impl<T: ResolvedType> Node<Expr> for LogicalExpr<T> {
+    fn node_id(&self) -> NodeId<Expr> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Expr>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for LogicalExpr<T> {
+    fn span(&self) -> Span {
+        let lhs = self.lhs.0.try_lock().unwrap().span();
+        let rhs = self.rhs.0.try_lock().unwrap().span();
+
+        return lhs.to(&rhs);
+    }
+}
+
+impl<T: ResolvedType> From<LogicalExpr<()>> for LogicalExpr<Option<T>> {
+    fn from(value: LogicalExpr<()>) -> Self {
+        return Self {
+            id: value.id,
+            lhs: from(value.lhs),
+            op: value.op,
+            rhs: from(value.rhs),
+            resolved_type: None,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for LogicalExpr<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.lhs.is_resolved() {
+            return false;
+        }
+
+        if !self.rhs.is_resolved() {
+            return false;
+        }
+
+        return self.resolved_type.is_some();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<LogicalExpr<Option<T>>> for LogicalExpr<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: LogicalExpr<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            lhs: try_from(value.lhs)?,
+            op: value.op,
+            rhs: try_from(value.rhs)?,
+            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
+                file: file!(),
+                line: line!(),
+            })?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LogicalOp {
+    And(Arc<Token>),
+    Or(Arc<Token>),
+}
+
+impl LogicalOp {
+    pub fn token(&self) -> &Arc<Token> {
+        match self {
+            Self::And(token) | Self::Or(token) => return token,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -884,6 +1203,12 @@ impl<T: ResolvedType> Node<Expr> for StaticRefExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for StaticRefExpr<T> {
+    fn span(&self) -> Span {
+        return self.static_path.span();
+    }
+}
+
 impl<T: ResolvedType> From<StaticRefExpr<()>> for StaticRefExpr<Option<T>> {
     fn from(value: StaticRefExpr<()>) -> Self {
         return Self {
@@ -994,6 +1319,17 @@ impl<T: ResolvedType> Node<Expr> for ConstructExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for ConstructExpr<T> {
+    fn span(&self) -> Span {
+        let target = match &self.target {
+            ConstructTarget::Ident(ident) => ident.try_lock().unwrap().span(),
+            ConstructTarget::StaticPath(path) => path.span(),
+        };
+
+        return target.to(&self.close_squirly_brace.span);
+    }
+}
+
 impl<T: ResolvedType> From<ConstructExpr<()>> for ConstructExpr<Option<T>> {
     fn from(value: ConstructExpr<()>) -> Self {
         return Self {
@@ -1137,6 +1473,12 @@ impl<T: ResolvedType> Node<Expr> for GetExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for GetExpr<T> {
+    fn span(&self) -> Span {
+        return self.target.0.try_lock().unwrap().span().to(&self.name.span);
+    }
+}
+
 impl<T: ResolvedType> From<GetExpr<()>> for GetExpr<Option<T>> {
     fn from(value: GetExpr<()>) -> Self {
         return Self {
@@ -1199,6 +1541,19 @@ impl<T: ResolvedType> Node<Expr> for IfExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for IfExpr<T> {
+    fn span(&self) -> Span {
+        if let Some(semicolon_token) = &self.semicolon_token {
+            return self.if_token.span.to(&semicolon_token.span);
+        }
+
+        return self
+            .if_token
+            .span
+            .to(&self.condition.0.try_lock().unwrap().span());
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for IfExpr<T> {
     fn is_terminal(&mut self) -> bool {
         /* TODO: account for then stmts:
@@ -1690,6 +2045,15 @@ impl<T: ResolvedType> Node<Expr> for LoopExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for LoopExpr<T> {
+    fn span(&self) -> Span {
+        return self
+            .loop_token
+            .span
+            .to(&self.block.end_semicolon_token.span);
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for LoopExpr<T> {
     fn is_terminal(&mut self) -> bool {
         if let Some(resolved) = &self.resolved_terminal {
@@ -1771,6 +2135,19 @@ impl<T: ResolvedType> Node<Expr> for WhileExpr<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for WhileExpr<T> {
+    fn span(&self) -> Span {
+        if let Some(semicolon_token) = &self.semicolon_token {
+            return self.while_token.span.to(&semicolon_token.span);
+        }
+
+        return self
+            .while_token
+            .span
+            .to(&self.condition.0.try_lock().unwrap().span());
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for WhileExpr<T> {
     fn is_terminal(&mut self) -> bool {
         if let Some(resolved) = &self.resolved_terminal {
@@ -2054,9 +2431,387 @@ impl<T: ResolvedType> TryFrom<WhileExprElseBlock<Option<T>>> for WhileExprElseBl
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForExpr<T: ResolvedType = ()> {
+    pub id: NodeId<Expr>,
+    pub for_token: Arc<Token>,
+    pub label: Option<Arc<Token>>,
+    pub name: Arc<Token>,
+    pub in_token: Arc<Token>,
+    pub iter: NestedExpr<T>,
+    pub block: CodeBlock<T, ()>,
+    pub else_: ForExprElse<T>,
+    pub resolved_terminal: Option<bool>,
+    pub resolved_type: Option<T>,
+}
+
+impl<T: ResolvedType> Node<Expr> for ForExpr<T> {
+    fn node_id(&self) -> NodeId<Expr> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Expr>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for ForExpr<T> {
+    fn span(&self) -> Span {
+        return self
+            .for_token
+            .span
+            .to(&self.else_.else_expr.0.try_lock().unwrap().span());
+    }
+}
+
+impl<T: ResolvedType> IsTerminal<T> for ForExpr<T> {
+    fn is_terminal(&mut self) -> bool {
+        if let Some(resolved) = &self.resolved_terminal {
+            return *resolved;
+        }
+
+        // TODO: terminal when the block always breaks, and the else value is terminal
+        let is_terminal = false;
+
+        self.resolved_terminal = Some(is_terminal);
+
+        return is_terminal;
+    }
+}
+
+impl<T: ResolvedType> From<ForExpr<()>> for ForExpr<Option<T>> {
+    fn from(value: ForExpr<()>) -> Self {
+        return Self {
+            id: value.id,
+            for_token: value.for_token,
+            label: value.label,
+            name: value.name,
+            in_token: value.in_token,
+            iter: from(value.iter),
+            block: from(value.block),
+            else_: from(value.else_),
+            resolved_terminal: value.resolved_terminal,
+            resolved_type: None,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for ForExpr<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.iter.is_resolved() {
+            return log::trace!(false);
+        }
+
+        if !self.block.is_resolved() {
+            return log::trace!(false);
+        }
+
+        if !self.else_.is_resolved() {
+            return log::trace!(false);
+        }
+
+        return true;
+    }
+}
+
+impl<T: ResolvedType> TryFrom<ForExpr<Option<T>>> for ForExpr<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: ForExpr<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            for_token: value.for_token,
+            label: value.label,
+            name: value.name,
+            in_token: value.in_token,
+            iter: try_from(value.iter)?,
+            block: try_from(value.block)?,
+            else_: try_from(value.else_)?,
+            resolved_terminal: value.resolved_terminal,
+            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
+                file: file!(),
+                line: line!(),
+            })?,
+        });
+    }
+}
+
+/// The value of a `for` expression when the loop finishes without breaking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForExprElse<T: ResolvedType = ()> {
+    pub else_token: Arc<Token>,
+    pub else_expr: NestedExpr<T>,
+}
+
+impl<T: ResolvedType> From<ForExprElse<()>> for ForExprElse<Option<T>> {
+    fn from(value: ForExprElse<()>) -> Self {
+        return Self {
+            else_token: value.else_token,
+            else_expr: from(value.else_expr),
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for ForExprElse<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.else_expr.is_resolved() {
+            return log::trace!(false);
+        }
+
+        return true;
+    }
+}
+
+impl<T: ResolvedType> TryFrom<ForExprElse<Option<T>>> for ForExprElse<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: ForExprElse<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            else_token: value.else_token,
+            else_expr: try_from(value.else_expr)?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchExpr<T: ResolvedType = ()> {
+    pub id: NodeId<Expr>,
+    pub match_token: Arc<Token>,
+    pub label: Option<Arc<Token>>,
+    pub value: NestedExpr<T>,
+    pub arms: Vec<MatchArm<T>>,
+    pub semicolon_token: Arc<Token>,
+    pub resolved_terminal: Option<bool>,
+    pub resolved_type: Option<T>,
+}
+
+impl<T: ResolvedType> Node<Expr> for MatchExpr<T> {
+    fn node_id(&self) -> NodeId<Expr> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Expr>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for MatchExpr<T> {
+    fn span(&self) -> Span {
+        return self.match_token.span.to(&self.semicolon_token.span);
+    }
+}
+
+impl<T: ResolvedType> IsTerminal<T> for MatchExpr<T> {
+    fn is_terminal(&mut self) -> bool {
+        if let Some(resolved) = &self.resolved_terminal {
+            return *resolved;
+        }
+
+        // The match is exhaustive, so it's terminal when no arm can finish.
+        // A `then` finishes the arm with a value, so it doesn't count
+        let is_terminal = !self.arms.is_empty()
+            && self.arms.iter().all(|arm| match &arm.body {
+                MatchArmBody::Ternary(_) => false,
+                MatchArmBody::Block(block) => block.stmts.last().is_some_and(|stmt| {
+                    let stmt = &mut *stmt.try_lock().unwrap();
+                    !matches!(stmt, Stmt::Then(_)) && stmt.is_terminal()
+                }),
+            });
+
+        self.resolved_terminal = Some(is_terminal);
+
+        return is_terminal;
+    }
+}
+
+impl<T: ResolvedType> From<MatchExpr<()>> for MatchExpr<Option<T>> {
+    fn from(value: MatchExpr<()>) -> Self {
+        return Self {
+            id: value.id,
+            match_token: value.match_token,
+            label: value.label,
+            value: from(value.value),
+            arms: fe_from(value.arms),
+            semicolon_token: value.semicolon_token,
+            resolved_terminal: value.resolved_terminal,
+            resolved_type: None,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for MatchExpr<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.value.is_resolved() {
+            return log::trace!(false);
+        }
+
+        for arm in &self.arms {
+            if !arm.is_resolved() {
+                return log::trace!(false);
+            }
+        }
+
+        return self.resolved_type.is_some();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<MatchExpr<Option<T>>> for MatchExpr<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: MatchExpr<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            match_token: value.match_token,
+            label: value.label,
+            value: try_from(value.value)?,
+            arms: fe_try_from(value.arms)?,
+            semicolon_token: value.semicolon_token,
+            resolved_terminal: value.resolved_terminal,
+            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
+                file: file!(),
+                line: line!(),
+            })?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm<T: ResolvedType = ()> {
+    pub pattern: Pattern<T>,
+    pub guard: Option<MatchArmGuard<T>>,
+    pub body: MatchArmBody<T>,
+}
+
+impl<T: ResolvedType> From<MatchArm<()>> for MatchArm<Option<T>> {
+    fn from(value: MatchArm<()>) -> Self {
+        return Self {
+            pattern: from(value.pattern),
+            guard: value.guard.map(from),
+            body: from(value.body),
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for MatchArm<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.pattern.is_resolved() {
+            return log::trace!(false);
+        }
+
+        if let Some(guard) = &self.guard {
+            if !guard.condition.is_resolved() {
+                return log::trace!(false);
+            }
+        }
+
+        return self.body.is_resolved();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<MatchArm<Option<T>>> for MatchArm<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: MatchArm<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            pattern: try_from(value.pattern)?,
+            guard: fe_try_from(value.guard)?,
+            body: try_from(value.body)?,
+        });
+    }
+}
+
+/// `if condition` after a pattern, which has to hold for the arm to match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArmGuard<T: ResolvedType = ()> {
+    pub if_token: Arc<Token>,
+    pub condition: NestedExpr<T>,
+}
+
+impl<T: ResolvedType> From<MatchArmGuard<()>> for MatchArmGuard<Option<T>> {
+    fn from(value: MatchArmGuard<()>) -> Self {
+        return Self {
+            if_token: value.if_token,
+            condition: from(value.condition),
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<MatchArmGuard<Option<T>>> for MatchArmGuard<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: MatchArmGuard<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            if_token: value.if_token,
+            condition: try_from(value.condition)?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatchArmBody<T: ResolvedType = ()> {
+    Ternary(MatchArmTernary<T>),
+    Block(CodeBlock<T>),
+}
+
+impl<T: ResolvedType> From<MatchArmBody<()>> for MatchArmBody<Option<T>> {
+    fn from(value: MatchArmBody<()>) -> Self {
+        match value {
+            MatchArmBody::Ternary(value) => return Self::Ternary(from(value)),
+            MatchArmBody::Block(value) => return Self::Block(from(value)),
+        }
+    }
+}
+
+impl<T: ResolvedType> Resolvable for MatchArmBody<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        match self {
+            MatchArmBody::Ternary(value) => return value.then_expr.is_resolved(),
+            MatchArmBody::Block(value) => return value.is_resolved(),
+        }
+    }
+}
+
+impl<T: ResolvedType> TryFrom<MatchArmBody<Option<T>>> for MatchArmBody<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: MatchArmBody<Option<T>>) -> Result<Self, Self::Error> {
+        match value {
+            MatchArmBody::Ternary(value) => return Ok(Self::Ternary(try_from(value)?)),
+            MatchArmBody::Block(value) => return Ok(Self::Block(try_from(value)?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArmTernary<T: ResolvedType = ()> {
+    pub then_token: Arc<Token>,
+    pub then_expr: NestedExpr<T>,
+}
+
+impl<T: ResolvedType> From<MatchArmTernary<()>> for MatchArmTernary<Option<T>> {
+    fn from(value: MatchArmTernary<()>) -> Self {
+        return Self {
+            then_token: value.then_token,
+            then_expr: from(value.then_expr),
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<MatchArmTernary<Option<T>>> for MatchArmTernary<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: MatchArmTernary<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            then_token: value.then_token,
+            then_expr: try_from(value.then_expr)?,
+        });
+    }
+}
+
 // Visitor pattern
 pub trait ExprVisitor<T: ResolvedType, R = ()> {
     fn visit_bool_literal_expr(&mut self, expr: Arc<Mutex<BoolLiteralExpr<T>>>) -> R;
+    fn visit_none_literal_expr(&mut self, expr: Arc<Mutex<NoneLiteralExpr<T>>>) -> R;
     fn visit_number_literal_expr(&mut self, expr: Arc<Mutex<NumberLiteralExpr<T>>>) -> R;
     fn visit_plain_string_literal_expr(&mut self, expr: Arc<Mutex<PlainStringLiteralExpr<T>>>)
         -> R;
@@ -2065,12 +2820,15 @@ pub trait ExprVisitor<T: ResolvedType, R = ()> {
     fn visit_call_expr(&mut self, expr: Arc<Mutex<CallExpr<T>>>) -> R;
     fn visit_unary_expr(&mut self, expr: Arc<Mutex<UnaryExpr<T>>>) -> R;
     fn visit_binary_expr(&mut self, expr: Arc<Mutex<BinaryExpr<T>>>) -> R;
+    fn visit_logical_expr(&mut self, expr: Arc<Mutex<LogicalExpr<T>>>) -> R;
     fn visit_static_ref_expr(&mut self, expr: Arc<Mutex<StaticRefExpr<T>>>) -> R;
     fn visit_construct_expr(&mut self, expr: Arc<Mutex<ConstructExpr<T>>>) -> R;
     fn visit_get_expr(&mut self, expr: Arc<Mutex<GetExpr<T>>>) -> R;
     fn visit_if_expr(&mut self, expr: Arc<Mutex<IfExpr<T>>>) -> R;
     fn visit_loop_expr(&mut self, expr: Arc<Mutex<LoopExpr<T>>>) -> R;
     fn visit_while_expr(&mut self, expr: Arc<Mutex<WhileExpr<T>>>) -> R;
+    fn visit_for_expr(&mut self, expr: Arc<Mutex<ForExpr<T>>>) -> R;
+    fn visit_match_expr(&mut self, expr: Arc<Mutex<MatchExpr<T>>>) -> R;
 }
 
 pub trait ExprAccept<T: ResolvedType, R, V: ExprVisitor<T, R>> {
@@ -2081,6 +2839,7 @@ impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Expr<T> {
     fn accept(&self, visitor: &mut V) -> R {
         return match self {
             Self::BoolLiteral(expr) => expr.accept(visitor),
+            Self::NoneLiteral(expr) => expr.accept(visitor),
             Self::NumberLiteral(expr) => expr.accept(visitor),
             Self::PlainStringLiteral(expr) => expr.accept(visitor),
             Self::FmtStringLiteral(expr) => expr.accept(visitor),
@@ -2088,12 +2847,15 @@ impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Expr<T> {
             Self::Call(expr) => expr.accept(visitor),
             Self::Unary(expr) => expr.accept(visitor),
             Self::Binary(expr) => expr.accept(visitor),
+            Self::Logical(expr) => expr.accept(visitor),
             Self::StaticRef(expr) => expr.accept(visitor),
             Self::Construct(expr) => expr.accept(visitor),
             Self::Get(expr) => expr.accept(visitor),
             Self::If(expr) => expr.accept(visitor),
             Self::Loop(expr) => expr.accept(visitor),
             Self::While(expr) => expr.accept(visitor),
+            Self::For(expr) => expr.accept(visitor),
+            Self::Match(expr) => expr.accept(visitor),
         };
     }
 }
@@ -2106,6 +2868,14 @@ impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V>
     }
 }
 
+impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V>
+    for Arc<Mutex<NoneLiteralExpr<T>>>
+{
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_none_literal_expr(self.clone());
+    }
+}
+
 impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V>
     for Arc<Mutex<NumberLiteralExpr<T>>>
 {
@@ -2154,6 +2924,12 @@ impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Arc<Mutex
     }
 }
 
+impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Arc<Mutex<LogicalExpr<T>>> {
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_logical_expr(self.clone());
+    }
+}
+
 impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V>
     for Arc<Mutex<StaticRefExpr<T>>>
 {
@@ -2193,3 +2969,15 @@ impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Arc<Mutex
         return visitor.visit_while_expr(self.clone());
     }
 }
+
+impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Arc<Mutex<ForExpr<T>>> {
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_for_expr(self.clone());
+    }
+}
+
+impl<T: ResolvedType, R, V: ExprVisitor<T, R>> ExprAccept<T, R, V> for Arc<Mutex<MatchExpr<T>>> {
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_match_expr(self.clone());
+    }
+}
@@ -7,6 +7,9 @@ pub use expr::*;
 mod node;
 pub use node::*;
 
+mod pattern;
+pub use pattern::*;
+
 mod stmt;
 pub use stmt::*;
 
@@ -39,6 +42,10 @@ pub trait Resolvable {
     fn is_resolved(&self) -> bool;
 }
 
+pub trait Spanned {
+    fn span(&self) -> token::Span;
+}
+
 #[derive(Debug, Clone)]
 pub enum FeSyntaxPackage<T: ResolvedType = ()> {
     File(FeSyntaxFile<T>),
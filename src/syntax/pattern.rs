@@ -0,0 +1,364 @@
+use super::*;
+
+use crate::log;
+use crate::result::Result;
+use crate::token::{Span, Token};
+use crate::utils::{fe_from, fe_try_from, from, try_from};
+
+use std::sync::Arc;
+
+/// What a `match` arm compares the matched value against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern<T: ResolvedType = ()> {
+    /// `_`, which matches anything
+    Wildcard(WildcardPattern),
+
+    /// `1`, `-1`, `"text"` or `true`
+    Literal(LiteralPattern<T>),
+
+    /// A name, which matches anything and binds it to the name
+    Binding(BindingPattern<T>),
+
+    /// `Point { x: 0, y }` or `Shape::Circle { r, .. }`
+    Struct(StructPattern<T>),
+
+    /// `Shape::Point(x, _)` or `Light::Red`
+    Variant(VariantPattern<T>),
+}
+
+impl<T: ResolvedType> Pattern<T> {
+    /// The names the pattern binds, in the order they appear.
+    pub fn bindings(&self) -> Vec<Arc<Token>> {
+        match self {
+            Self::Wildcard(_) | Self::Literal(_) => return vec![],
+            Self::Binding(pattern) => return vec![pattern.name.clone()],
+            Self::Struct(pattern) => {
+                return pattern
+                    .fields
+                    .iter()
+                    .flat_map(|field| field.pattern.bindings())
+                    .collect();
+            }
+            Self::Variant(pattern) => {
+                let Some(fields) = &pattern.fields else {
+                    return vec![];
+                };
+
+                return fields
+                    .fields
+                    .iter()
+                    .flat_map(|field| field.pattern.bindings())
+                    .collect();
+            }
+        }
+    }
+}
+
+impl<T: ResolvedType> Spanned for Pattern<T> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Wildcard(pattern) => return pattern.underscore_token.span.clone(),
+            Self::Literal(pattern) => return pattern.value.0.try_lock().unwrap().span(),
+            Self::Binding(pattern) => return pattern.name.span.clone(),
+            Self::Struct(pattern) => {
+                return pattern
+                    .path
+                    .span()
+                    .to(&pattern.close_squirly_brace_token.span);
+            }
+            Self::Variant(pattern) => match &pattern.fields {
+                Some(fields) => return pattern.path.span().to(&fields.close_paren_token.span),
+                None => return pattern.path.span(),
+            },
+        }
+    }
+}
+
+impl<T: ResolvedType> From<Pattern<()>> for Pattern<Option<T>> {
+    fn from(value: Pattern<()>) -> Self {
+        match value {
+            Pattern::Wildcard(pattern) => return Self::Wildcard(pattern),
+            Pattern::Literal(pattern) => return Self::Literal(from(pattern)),
+            Pattern::Binding(pattern) => return Self::Binding(from(pattern)),
+            Pattern::Struct(pattern) => return Self::Struct(from(pattern)),
+            Pattern::Variant(pattern) => return Self::Variant(from(pattern)),
+        }
+    }
+}
+
+impl<T: ResolvedType> Resolvable for Pattern<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        match self {
+            Self::Wildcard(_) => return true,
+            Self::Literal(pattern) => return pattern.is_resolved(),
+            Self::Binding(pattern) => return pattern.is_resolved(),
+            Self::Struct(pattern) => return pattern.is_resolved(),
+            Self::Variant(pattern) => return pattern.is_resolved(),
+        }
+    }
+}
+
+impl<T: ResolvedType> TryFrom<Pattern<Option<T>>> for Pattern<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: Pattern<Option<T>>) -> Result<Self, Self::Error> {
+        match value {
+            Pattern::Wildcard(pattern) => return Ok(Self::Wildcard(pattern)),
+            Pattern::Literal(pattern) => return Ok(Self::Literal(try_from(pattern)?)),
+            Pattern::Binding(pattern) => return Ok(Self::Binding(try_from(pattern)?)),
+            Pattern::Struct(pattern) => return Ok(Self::Struct(try_from(pattern)?)),
+            Pattern::Variant(pattern) => return Ok(Self::Variant(try_from(pattern)?)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WildcardPattern {
+    pub underscore_token: Arc<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiteralPattern<T: ResolvedType = ()> {
+    pub value: NestedExpr<T>,
+}
+
+impl<T: ResolvedType> From<LiteralPattern<()>> for LiteralPattern<Option<T>> {
+    fn from(value: LiteralPattern<()>) -> Self {
+        return Self {
+            value: from(value.value),
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for LiteralPattern<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        return self.value.is_resolved();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<LiteralPattern<Option<T>>> for LiteralPattern<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: LiteralPattern<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            value: try_from(value.value)?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BindingPattern<T: ResolvedType = ()> {
+    pub name: Arc<Token>,
+
+    /// The type of the value bound to the name
+    pub resolved_type: T,
+}
+
+impl<T: ResolvedType> From<BindingPattern<()>> for BindingPattern<Option<T>> {
+    fn from(value: BindingPattern<()>) -> Self {
+        return Self {
+            name: value.name,
+            resolved_type: None,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for BindingPattern<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        return self.resolved_type.is_some();
+    }
+}
+
+impl<T: ResolvedType> TryFrom<BindingPattern<Option<T>>> for BindingPattern<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: BindingPattern<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            name: value.name,
+            resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
+                file: file!(),
+                line: line!(),
+            })?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructPattern<T: ResolvedType = ()> {
+    pub path: StaticPath<T>,
+    pub open_squirly_brace_token: Arc<Token>,
+    pub fields: Vec<StructPatternField<T>>,
+
+    /// `..`, which leaves out the fields that aren't listed
+    pub rest_token: Option<Arc<Token>>,
+    pub close_squirly_brace_token: Arc<Token>,
+}
+
+impl<T: ResolvedType> From<StructPattern<()>> for StructPattern<Option<T>> {
+    fn from(value: StructPattern<()>) -> Self {
+        return Self {
+            path: from(value.path),
+            open_squirly_brace_token: value.open_squirly_brace_token,
+            fields: fe_from(value.fields),
+            rest_token: value.rest_token,
+            close_squirly_brace_token: value.close_squirly_brace_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for StructPattern<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.path.is_resolved() {
+            return log::trace!(false);
+        }
+
+        return self.fields.iter().all(|field| field.pattern.is_resolved());
+    }
+}
+
+impl<T: ResolvedType> TryFrom<StructPattern<Option<T>>> for StructPattern<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: StructPattern<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            path: try_from(value.path)?,
+            open_squirly_brace_token: value.open_squirly_brace_token,
+            fields: fe_try_from(value.fields)?,
+            rest_token: value.rest_token,
+            close_squirly_brace_token: value.close_squirly_brace_token,
+        });
+    }
+}
+
+/// `name: pattern`, or just `name` to bind the field to its own name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructPatternField<T: ResolvedType = ()> {
+    pub name: Arc<Token>,
+    pub colon_token: Option<Arc<Token>>,
+    pub pattern: Pattern<T>,
+    pub comma_token: Option<Arc<Token>>,
+}
+
+impl<T: ResolvedType> From<StructPatternField<()>> for StructPatternField<Option<T>> {
+    fn from(value: StructPatternField<()>) -> Self {
+        return Self {
+            name: value.name,
+            colon_token: value.colon_token,
+            pattern: from(value.pattern),
+            comma_token: value.comma_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<StructPatternField<Option<T>>> for StructPatternField<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: StructPatternField<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            name: value.name,
+            colon_token: value.colon_token,
+            pattern: try_from(value.pattern)?,
+            comma_token: value.comma_token,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantPattern<T: ResolvedType = ()> {
+    pub path: StaticPath<T>,
+
+    /// Not set for unit variants
+    pub fields: Option<VariantPatternFields<T>>,
+}
+
+impl<T: ResolvedType> From<VariantPattern<()>> for VariantPattern<Option<T>> {
+    fn from(value: VariantPattern<()>) -> Self {
+        return Self {
+            path: from(value.path),
+            fields: value.fields.map(from),
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for VariantPattern<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.path.is_resolved() {
+            return log::trace!(false);
+        }
+
+        let Some(fields) = &self.fields else {
+            return true;
+        };
+
+        return fields
+            .fields
+            .iter()
+            .all(|field| field.pattern.is_resolved());
+    }
+}
+
+impl<T: ResolvedType> TryFrom<VariantPattern<Option<T>>> for VariantPattern<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: VariantPattern<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            path: try_from(value.path)?,
+            fields: fe_try_from(value.fields)?,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantPatternFields<T: ResolvedType = ()> {
+    pub open_paren_token: Arc<Token>,
+    pub fields: Vec<VariantPatternField<T>>,
+    pub close_paren_token: Arc<Token>,
+}
+
+impl<T: ResolvedType> From<VariantPatternFields<()>> for VariantPatternFields<Option<T>> {
+    fn from(value: VariantPatternFields<()>) -> Self {
+        return Self {
+            open_paren_token: value.open_paren_token,
+            fields: fe_from(value.fields),
+            close_paren_token: value.close_paren_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<VariantPatternFields<Option<T>>> for VariantPatternFields<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: VariantPatternFields<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            open_paren_token: value.open_paren_token,
+            fields: fe_try_from(value.fields)?,
+            close_paren_token: value.close_paren_token,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantPatternField<T: ResolvedType = ()> {
+    pub pattern: Pattern<T>,
+    pub comma_token: Option<Arc<Token>>,
+}
+
+impl<T: ResolvedType> From<VariantPatternField<()>> for VariantPatternField<Option<T>> {
+    fn from(value: VariantPatternField<()>) -> Self {
+        return Self {
+            pattern: from(value.pattern),
+            comma_token: value.comma_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<VariantPatternField<Option<T>>> for VariantPatternField<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: VariantPatternField<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            pattern: try_from(value.pattern)?,
+            comma_token: value.comma_token,
+        });
+    }
+}
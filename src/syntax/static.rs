@@ -1,7 +1,7 @@
 use super::*;
 
 use crate::result::Result;
-use crate::token::Token;
+use crate::token::{Span, Token};
 use crate::utils::{from, invert, try_from};
 
 use std::sync::Arc;
@@ -10,14 +10,38 @@ use std::sync::Arc;
 pub struct StaticType<T: ResolvedType = ()> {
     pub ref_type: Option<RefType>,
     pub static_path: StaticPath<T>,
+    pub generic_args: Option<StaticGenericArgs<T>>,
+
+    /// The `?` of an optional type like `Int?`
+    pub question_token: Option<Arc<Token>>,
     pub resolved_type: T,
 }
 
+impl<T: ResolvedType> Spanned for StaticType<T> {
+    fn span(&self) -> Span {
+        let end = match (&self.question_token, &self.generic_args) {
+            (Some(question_token), _) => question_token.span.clone(),
+            (None, Some(generic_args)) => generic_args.close_angle_token.span.clone(),
+            (None, None) => self.static_path.span(),
+        };
+
+        match &self.ref_type {
+            Some(RefType::Shared { ref_token, .. }) | Some(RefType::Mut { ref_token, .. }) => {
+                return ref_token.span.to(&end);
+            }
+
+            None => return self.static_path.span().to(&end),
+        }
+    }
+}
+
 impl<T: ResolvedType> From<StaticType<()>> for StaticType<Option<T>> {
     fn from(value: StaticType<()>) -> Self {
         return Self {
             ref_type: value.ref_type,
             static_path: from(value.static_path),
+            generic_args: value.generic_args.map(from),
+            question_token: value.question_token,
             resolved_type: None,
         };
     }
@@ -36,6 +60,8 @@ impl<T: ResolvedType> TryFrom<StaticType<Option<T>>> for StaticType<T> {
         return Ok(Self {
             ref_type: value.ref_type,
             static_path: try_from(value.static_path)?,
+            generic_args: invert(value.generic_args.map(try_from))?,
+            question_token: value.question_token,
             resolved_type: value.resolved_type.ok_or(FinalizeResolveTypeError {
                 file: file!(),
                 line: line!(),
@@ -44,6 +70,72 @@ impl<T: ResolvedType> TryFrom<StaticType<Option<T>>> for StaticType<T> {
     }
 }
 
+/// The types given to a generic struct, like the `<Int>` in `Box<Int>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticGenericArgs<T: ResolvedType = ()> {
+    pub open_angle_token: Arc<Token>,
+    pub args: Vec<StaticGenericArg<T>>,
+    pub close_angle_token: Arc<Token>,
+}
+
+impl<T: ResolvedType> From<StaticGenericArgs<()>> for StaticGenericArgs<Option<T>> {
+    fn from(value: StaticGenericArgs<()>) -> Self {
+        return Self {
+            open_angle_token: value.open_angle_token,
+            args: value.args.into_iter().map(from).collect(),
+            close_angle_token: value.close_angle_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for StaticGenericArgs<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        return self.args.iter().all(|arg| arg.static_type.is_resolved());
+    }
+}
+
+impl<T: ResolvedType> TryFrom<StaticGenericArgs<Option<T>>> for StaticGenericArgs<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: StaticGenericArgs<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            open_angle_token: value.open_angle_token,
+            args: value
+                .args
+                .into_iter()
+                .map(try_from)
+                .collect::<Result<Vec<StaticGenericArg<T>>, Self::Error>>()?,
+            close_angle_token: value.close_angle_token,
+        });
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticGenericArg<T: ResolvedType = ()> {
+    pub static_type: StaticType<T>,
+    pub comma_token: Option<Arc<Token>>,
+}
+
+impl<T: ResolvedType> From<StaticGenericArg<()>> for StaticGenericArg<Option<T>> {
+    fn from(value: StaticGenericArg<()>) -> Self {
+        return Self {
+            static_type: from(value.static_type),
+            comma_token: value.comma_token,
+        };
+    }
+}
+
+impl<T: ResolvedType> TryFrom<StaticGenericArg<Option<T>>> for StaticGenericArg<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: StaticGenericArg<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            static_type: try_from(value.static_type)?,
+            comma_token: value.comma_token,
+        });
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum RefType {
     Shared {
@@ -64,6 +156,20 @@ pub struct StaticPath<T: ResolvedType = ()> {
     pub resolved_type: T,
 }
 
+impl<T: ResolvedType> Spanned for StaticPath<T> {
+    fn span(&self) -> Span {
+        if let Some(root) = &self.root {
+            return root.span().to(&self.name.span);
+        }
+
+        if let Some(double_colon_token) = &self.double_colon_token {
+            return double_colon_token.span.to(&self.name.span);
+        }
+
+        return self.name.span.clone();
+    }
+}
+
 impl<T: ResolvedType> From<StaticPath<()>> for StaticPath<Option<T>> {
     fn from(value: StaticPath<()>) -> Self {
         return Self {
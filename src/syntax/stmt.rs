@@ -1,7 +1,7 @@
 use super::*;
 
 use crate::log;
-use crate::token::Token;
+use crate::token::{Span, Token};
 use crate::utils::{fe_from, fe_try_from, from, invert, try_from};
 
 #[derive(Debug, Clone)]
@@ -13,6 +13,7 @@ pub enum Stmt<T: ResolvedType = ()> {
     If(Arc<Mutex<IfStmt<T>>>),
     Loop(Arc<Mutex<LoopStmt<T>>>),
     While(Arc<Mutex<WhileStmt<T>>>),
+    For(Arc<Mutex<ForStmt<T>>>),
     Break(Arc<Mutex<BreakStmt<T>>>),
     Then(Arc<Mutex<ThenStmt<T>>>),
 }
@@ -69,6 +70,13 @@ impl<T: ResolvedType> PartialEq for Stmt<T> {
                 let cloned = { d.try_lock().unwrap().clone() };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::For(d) => {
+                let Self::For(other) = other else {
+                    return false;
+                };
+                let cloned = { d.try_lock().unwrap().clone() };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
             Self::Break(d) => {
                 let Self::Break(other) = other else {
                     return false;
@@ -97,6 +105,7 @@ impl<T: ResolvedType> Node<Stmt> for Stmt<T> {
             Self::If(stmt) => return stmt.try_lock().unwrap().node_id(),
             Self::Loop(stmt) => return stmt.try_lock().unwrap().node_id(),
             Self::While(stmt) => return stmt.try_lock().unwrap().node_id(),
+            Self::For(stmt) => return stmt.try_lock().unwrap().node_id(),
             Self::Break(stmt) => return stmt.try_lock().unwrap().node_id(),
             Self::Then(stmt) => return stmt.try_lock().unwrap().node_id(),
         }
@@ -111,12 +120,30 @@ impl<T: ResolvedType> Node<Stmt> for Stmt<T> {
             Self::If(stmt) => return stmt.try_lock().unwrap().set_node_id(id),
             Self::Loop(stmt) => return stmt.try_lock().unwrap().set_node_id(id),
             Self::While(stmt) => return stmt.try_lock().unwrap().set_node_id(id),
+            Self::For(stmt) => return stmt.try_lock().unwrap().set_node_id(id),
             Self::Break(stmt) => return stmt.try_lock().unwrap().set_node_id(id),
             Self::Then(stmt) => return stmt.try_lock().unwrap().set_node_id(id),
         }
     }
 }
 
+impl<T: ResolvedType> Spanned for Stmt<T> {
+    fn span(&self) -> Span {
+        match self {
+            Self::Expr(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::VarDecl(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::Assign(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::Return(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::If(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::Loop(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::While(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::For(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::Break(stmt) => return stmt.try_lock().unwrap().span(),
+            Self::Then(stmt) => return stmt.try_lock().unwrap().span(),
+        }
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for Stmt<T> {
     fn is_terminal(&mut self) -> bool {
         match self {
@@ -127,6 +154,7 @@ impl<T: ResolvedType> IsTerminal<T> for Stmt<T> {
             Self::If(stmt) => return stmt.try_lock().unwrap().is_terminal(),
             Self::Loop(stmt) => return stmt.try_lock().unwrap().is_terminal(),
             Self::While(stmt) => return stmt.try_lock().unwrap().is_terminal(),
+            Self::For(stmt) => return stmt.try_lock().unwrap().is_terminal(),
             Self::Break(stmt) => return stmt.try_lock().unwrap().is_terminal(),
             Self::Then(stmt) => return stmt.try_lock().unwrap().is_terminal(),
         }
@@ -143,6 +171,7 @@ impl<T: ResolvedType> From<Stmt<()>> for Stmt<Option<T>> {
             Stmt::If(stmt) => return Self::If(fe_from(stmt)),
             Stmt::Loop(stmt) => return Self::Loop(fe_from(stmt)),
             Stmt::While(stmt) => return Self::While(fe_from(stmt)),
+            Stmt::For(stmt) => return Self::For(fe_from(stmt)),
             Stmt::Break(stmt) => return Self::Break(fe_from(stmt)),
             Stmt::Then(stmt) => return Self::Then(fe_from(stmt)),
         }
@@ -159,6 +188,7 @@ impl<T: ResolvedType> Resolvable for Stmt<Option<T>> {
             Self::If(stmt) => return stmt.try_lock().unwrap().is_resolved(),
             Self::Loop(stmt) => return stmt.try_lock().unwrap().is_resolved(),
             Self::While(stmt) => return stmt.try_lock().unwrap().is_resolved(),
+            Self::For(stmt) => return stmt.try_lock().unwrap().is_resolved(),
             Self::Break(stmt) => return stmt.try_lock().unwrap().is_resolved(),
             Self::Then(stmt) => return stmt.try_lock().unwrap().is_resolved(),
         }
@@ -177,6 +207,7 @@ impl<T: ResolvedType> TryFrom<Stmt<Option<T>>> for Stmt<T> {
             Stmt::If(stmt) => return Ok(Self::If(fe_try_from(stmt)?)),
             Stmt::Loop(stmt) => return Ok(Self::Loop(fe_try_from(stmt)?)),
             Stmt::While(stmt) => return Ok(Self::While(fe_try_from(stmt)?)),
+            Stmt::For(stmt) => return Ok(Self::For(fe_try_from(stmt)?)),
             Stmt::Break(stmt) => return Ok(Self::Break(fe_try_from(stmt)?)),
             Stmt::Then(stmt) => return Ok(Self::Then(fe_try_from(stmt)?)),
         }
@@ -199,6 +230,12 @@ impl<T: ResolvedType> Node<Stmt> for ExprStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for ExprStmt<T> {
+    fn span(&self) -> Span {
+        return self.expr.try_lock().unwrap().span();
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for ExprStmt<T> {}
 
 impl<T: ResolvedType> PartialEq for ExprStmt<T> {
@@ -267,6 +304,27 @@ impl<T: ResolvedType> Node<Stmt> for VarDeclStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for VarDeclStmt<T> {
+    fn span(&self) -> Span {
+        let var_mut_token = match &self.var_mut {
+            VarDeclMut::Const(token) => token,
+            VarDeclMut::Mut(token) => token,
+        };
+
+        if let Some(value) = &self.value {
+            return var_mut_token
+                .span
+                .to(&value.value.0.try_lock().unwrap().span());
+        }
+
+        match &self.target {
+            VarDeclTarget::Ident(ident) => {
+                return var_mut_token.span.to(&ident.try_lock().unwrap().span());
+            }
+        }
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for VarDeclStmt<T> {}
 
 impl<T: ResolvedType> From<VarDeclStmt<()>> for VarDeclStmt<Option<T>> {
@@ -370,24 +428,21 @@ impl<T: ResolvedType> TryFrom<VarDeclTarget<Option<T>>> for VarDeclTarget<T> {
 #[derive(Debug, Clone, PartialEq)]
 pub struct VarDeclExplicitType<T: ResolvedType = ()> {
     pub colon_token: Arc<Token>,
-
-    // pub static_ref: StaticType<T>,
-    pub tmp: T,
+    pub static_type: StaticType<T>,
 }
 
 impl<T: ResolvedType> From<VarDeclExplicitType<()>> for VarDeclExplicitType<Option<T>> {
     fn from(value: VarDeclExplicitType<()>) -> Self {
         return Self {
             colon_token: value.colon_token,
-
-            tmp: None,
+            static_type: from(value.static_type),
         };
     }
 }
 
 impl<T: ResolvedType> Resolvable for VarDeclExplicitType<Option<T>> {
     fn is_resolved(&self) -> bool {
-        return self.tmp.is_some();
+        return self.static_type.is_resolved();
     }
 }
 
@@ -397,11 +452,7 @@ impl<T: ResolvedType> TryFrom<VarDeclExplicitType<Option<T>>> for VarDeclExplici
     fn try_from(value: VarDeclExplicitType<Option<T>>) -> Result<Self, Self::Error> {
         return Ok(Self {
             colon_token: value.colon_token,
-
-            tmp: value.tmp.ok_or(FinalizeResolveTypeError {
-                file: file!(),
-                line: line!(),
-            })?,
+            static_type: try_from(value.static_type)?,
         });
     }
 }
@@ -458,6 +509,15 @@ impl<T: ResolvedType> Node<Stmt> for AssignStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for AssignStmt<T> {
+    fn span(&self) -> Span {
+        let target = self.target.0.try_lock().unwrap().span();
+        let value = self.value.0.try_lock().unwrap().span();
+
+        return target.to(&value);
+    }
+}
+
 impl<T: ResolvedType> From<AssignStmt<()>> for AssignStmt<Option<T>> {
     fn from(value: AssignStmt<()>) -> Self {
         return Self {
@@ -502,6 +562,22 @@ pub enum AssignOp {
     Eq(Arc<Token>),
     PlusEq(Arc<Token>),
     MinusEq(Arc<Token>),
+    MultiplyEq(Arc<Token>),
+    DivideEq(Arc<Token>),
+    ModuloEq(Arc<Token>),
+}
+
+impl AssignOp {
+    pub fn token(&self) -> &Arc<Token> {
+        match self {
+            Self::Eq(token)
+            | Self::PlusEq(token)
+            | Self::MinusEq(token)
+            | Self::MultiplyEq(token)
+            | Self::DivideEq(token)
+            | Self::ModuloEq(token) => return token,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -514,6 +590,7 @@ pub struct ReturnStmt<T: ResolvedType = ()> {
     pub id: NodeId<Stmt>,
     pub return_token: Arc<Token>,
     pub value: Option<NestedExpr<T>>,
+    pub checked: bool,
 }
 
 impl<T: ResolvedType> Node<Stmt> for ReturnStmt<T> {
@@ -526,6 +603,19 @@ impl<T: ResolvedType> Node<Stmt> for ReturnStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for ReturnStmt<T> {
+    fn span(&self) -> Span {
+        if let Some(value) = &self.value {
+            return self
+                .return_token
+                .span
+                .to(&value.0.try_lock().unwrap().span());
+        }
+
+        return self.return_token.span.clone();
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for ReturnStmt<T> {
     fn is_terminal(&mut self) -> bool {
         return true;
@@ -538,12 +628,17 @@ impl<T: ResolvedType> From<ReturnStmt<()>> for ReturnStmt<Option<T>> {
             id: value.id,
             return_token: value.return_token,
             value: value.value.map(from),
+            checked: value.checked,
         };
     }
 }
 
 impl<T: ResolvedType> Resolvable for ReturnStmt<Option<T>> {
     fn is_resolved(&self) -> bool {
+        if !self.checked {
+            return false;
+        }
+
         if let Some(value) = &self.value {
             if !value.is_resolved() {
                 return false;
@@ -562,6 +657,7 @@ impl<T: ResolvedType> TryFrom<ReturnStmt<Option<T>>> for ReturnStmt<T> {
             id: value.id,
             return_token: value.return_token,
             value: invert(value.value.map(try_from))?,
+            checked: value.checked,
         });
     }
 }
@@ -588,6 +684,12 @@ impl<T: ResolvedType> Node<Stmt> for IfStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for IfStmt<T> {
+    fn span(&self) -> Span {
+        return self.if_token.span.to(&self.semicolon_token.span);
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for IfStmt<T> {
     fn is_terminal(&mut self) -> bool {
         if let Some(resolved) = &self.resolved_terminal {
@@ -791,15 +893,24 @@ impl<T: ResolvedType> Node<Stmt> for LoopStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for LoopStmt<T> {
+    fn span(&self) -> Span {
+        return self
+            .loop_token
+            .span
+            .to(&self.block.end_semicolon_token.span);
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for LoopStmt<T> {
     fn is_terminal(&mut self) -> bool {
         if let Some(resolved) = &self.resolved_terminal {
             return *resolved;
         }
 
+        // The type resolver decides once it knows whether a `break` exits the loop
         let is_terminal = false;
 
-        // TODO: check for infinite loop?
         // TODO: check for loop that is terminal without just breaking out of the loop?
 
         /* TODO: also account for weird situations like so:
@@ -878,15 +989,21 @@ impl<T: ResolvedType> Node<Stmt> for WhileStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for WhileStmt<T> {
+    fn span(&self) -> Span {
+        return self.while_token.span.to(&self.semicolon_token.span);
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for WhileStmt<T> {
     fn is_terminal(&mut self) -> bool {
         if let Some(resolved) = &self.resolved_terminal {
             return *resolved;
         }
 
+        // The type resolver decides for conditions that are always true
         let is_terminal = false;
 
-        // TODO: check for infinite while loop?
         // TODO: check for while loop that is terminal without just breaking out of the while?
 
         // TODO: does else-case influence terminality? ie if block AND else-case are terminal
@@ -985,6 +1102,95 @@ impl<T: ResolvedType> TryFrom<WhileStmtElse<Option<T>>> for WhileStmtElse<T> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForStmt<T: ResolvedType = ()> {
+    pub id: NodeId<Stmt>,
+    pub for_token: Arc<Token>,
+    pub label: Option<Arc<Token>>,
+    pub name: Arc<Token>,
+    pub in_token: Arc<Token>,
+    pub iter: NestedExpr<T>,
+    pub block: CodeBlock<T>,
+    pub resolved_terminal: Option<bool>,
+}
+
+impl<T: ResolvedType> Node<Stmt> for ForStmt<T> {
+    fn node_id(&self) -> NodeId<Stmt> {
+        return self.id;
+    }
+
+    fn set_node_id(&mut self, id: NodeId<Stmt>) {
+        self.id = id;
+    }
+}
+
+impl<T: ResolvedType> Spanned for ForStmt<T> {
+    fn span(&self) -> Span {
+        return self.for_token.span.to(&self.block.end_semicolon_token.span);
+    }
+}
+
+impl<T: ResolvedType> IsTerminal<T> for ForStmt<T> {
+    fn is_terminal(&mut self) -> bool {
+        if let Some(resolved) = &self.resolved_terminal {
+            return *resolved;
+        }
+
+        // The range can be empty, so the block might never run
+        let is_terminal = false;
+
+        self.resolved_terminal = Some(is_terminal);
+
+        return is_terminal;
+    }
+}
+
+impl<T: ResolvedType> From<ForStmt<()>> for ForStmt<Option<T>> {
+    fn from(value: ForStmt<()>) -> Self {
+        return Self {
+            id: value.id,
+            for_token: value.for_token,
+            label: value.label,
+            name: value.name,
+            in_token: value.in_token,
+            iter: from(value.iter),
+            block: from(value.block),
+            resolved_terminal: value.resolved_terminal,
+        };
+    }
+}
+
+impl<T: ResolvedType> Resolvable for ForStmt<Option<T>> {
+    fn is_resolved(&self) -> bool {
+        if !self.iter.is_resolved() {
+            return log::trace!(false);
+        }
+
+        if !self.block.is_resolved() {
+            return log::trace!(false);
+        }
+
+        return true;
+    }
+}
+
+impl<T: ResolvedType> TryFrom<ForStmt<Option<T>>> for ForStmt<T> {
+    type Error = FinalizeResolveTypeError;
+
+    fn try_from(value: ForStmt<Option<T>>) -> Result<Self, Self::Error> {
+        return Ok(Self {
+            id: value.id,
+            for_token: value.for_token,
+            label: value.label,
+            name: value.name,
+            in_token: value.in_token,
+            iter: try_from(value.iter)?,
+            block: try_from(value.block)?,
+            resolved_terminal: value.resolved_terminal,
+        });
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct BreakStmt<T: ResolvedType = ()> {
     pub id: NodeId<Stmt>,
@@ -1005,6 +1211,23 @@ impl<T: ResolvedType> Node<Stmt> for BreakStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for BreakStmt<T> {
+    fn span(&self) -> Span {
+        if let Some(value) = &self.value {
+            return self
+                .break_token
+                .span
+                .to(&value.0.try_lock().unwrap().span());
+        }
+
+        if let Some(label) = &self.label {
+            return self.break_token.span.to(&label.span);
+        }
+
+        return self.break_token.span.clone();
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for BreakStmt<T> {
     fn is_terminal(&mut self) -> bool {
         return true;
@@ -1073,6 +1296,8 @@ pub enum BreakHandler {
     LoopExpr(Arc<Mutex<LoopExpr<Option<FeType>>>>),
     WhileStmt(Arc<Mutex<WhileStmt<Option<FeType>>>>),
     WhileExpr(Arc<Mutex<WhileExpr<Option<FeType>>>>),
+    ForStmt(Arc<Mutex<ForStmt<Option<FeType>>>>),
+    ForExpr(Arc<Mutex<ForExpr<Option<FeType>>>>),
 }
 
 impl Node<Self> for BreakHandler {
@@ -1082,6 +1307,8 @@ impl Node<Self> for BreakHandler {
             Self::LoopExpr(h) => h.try_lock().unwrap().node_id().into(),
             Self::WhileStmt(h) => h.try_lock().unwrap().node_id().into(),
             Self::WhileExpr(h) => h.try_lock().unwrap().node_id().into(),
+            Self::ForStmt(h) => h.try_lock().unwrap().node_id().into(),
+            Self::ForExpr(h) => h.try_lock().unwrap().node_id().into(),
         }
     }
 
@@ -1091,6 +1318,8 @@ impl Node<Self> for BreakHandler {
             Self::LoopExpr(h) => h.try_lock().unwrap().set_node_id(id.into()),
             Self::WhileStmt(h) => h.try_lock().unwrap().set_node_id(id.into()),
             Self::WhileExpr(h) => h.try_lock().unwrap().set_node_id(id.into()),
+            Self::ForStmt(h) => h.try_lock().unwrap().set_node_id(id.into()),
+            Self::ForExpr(h) => h.try_lock().unwrap().set_node_id(id.into()),
         }
     }
 }
@@ -1126,6 +1355,20 @@ impl PartialEq for BreakHandler {
                 };
                 return PartialEq::eq(&cloned, &other.try_lock().unwrap());
             }
+            Self::ForStmt(v) => {
+                let cloned = { v.try_lock().unwrap().clone() };
+                let Self::ForStmt(other) = other else {
+                    return false;
+                };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
+            Self::ForExpr(v) => {
+                let cloned = { v.try_lock().unwrap().clone() };
+                let Self::ForExpr(other) = other else {
+                    return false;
+                };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
         }
     }
 }
@@ -1150,6 +1393,15 @@ impl<T: ResolvedType> Node<Stmt> for ThenStmt<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for ThenStmt<T> {
+    fn span(&self) -> Span {
+        return self
+            .then_token
+            .span
+            .to(&self.value.0.try_lock().unwrap().span());
+    }
+}
+
 impl<T: ResolvedType> IsTerminal<T> for ThenStmt<T> {
     fn is_terminal(&mut self) -> bool {
         return true;
@@ -1210,6 +1462,7 @@ impl<T: ResolvedType> TryFrom<ThenStmt<Option<T>>> for ThenStmt<T> {
 pub enum ThenHandler {
     IfExpr(IfBlock, Arc<Mutex<IfExpr<Option<FeType>>>>),
     IfStmt(IfBlock, Arc<Mutex<IfStmt<Option<FeType>>>>),
+    MatchExpr(Arc<Mutex<MatchExpr<Option<FeType>>>>),
 }
 
 impl Node<Self> for ThenHandler {
@@ -1217,6 +1470,7 @@ impl Node<Self> for ThenHandler {
         match self {
             Self::IfExpr(_, h) => h.try_lock().unwrap().node_id().into(),
             Self::IfStmt(_, h) => h.try_lock().unwrap().node_id().into(),
+            Self::MatchExpr(h) => h.try_lock().unwrap().node_id().into(),
         }
     }
 
@@ -1224,6 +1478,7 @@ impl Node<Self> for ThenHandler {
         match self {
             Self::IfExpr(_, h) => h.try_lock().unwrap().set_node_id(id.into()),
             Self::IfStmt(_, h) => h.try_lock().unwrap().set_node_id(id.into()),
+            Self::MatchExpr(h) => h.try_lock().unwrap().set_node_id(id.into()),
         }
     }
 }
@@ -1245,6 +1500,13 @@ impl PartialEq for ThenHandler {
                 };
                 return v1 == other1 && PartialEq::eq(&cloned, &other2.try_lock().unwrap());
             }
+            Self::MatchExpr(v) => {
+                let cloned = { v.try_lock().unwrap().clone() };
+                let Self::MatchExpr(other) = other else {
+                    return false;
+                };
+                return PartialEq::eq(&cloned, &other.try_lock().unwrap());
+            }
         }
     }
 }
@@ -1265,6 +1527,7 @@ pub trait StmtVisitor<T: ResolvedType, R = ()> {
     fn visit_if_stmt(&mut self, stmt: Arc<Mutex<IfStmt<T>>>) -> R;
     fn visit_loop_stmt(&mut self, stmt: Arc<Mutex<LoopStmt<T>>>) -> R;
     fn visit_while_stmt(&mut self, stmt: Arc<Mutex<WhileStmt<T>>>) -> R;
+    fn visit_for_stmt(&mut self, stmt: Arc<Mutex<ForStmt<T>>>) -> R;
     fn visit_break_stmt(&mut self, stmt: Arc<Mutex<BreakStmt<T>>>) -> R;
     fn visit_then_stmt(&mut self, stmt: Arc<Mutex<ThenStmt<T>>>) -> R;
 }
@@ -1283,6 +1546,7 @@ impl<T: ResolvedType, R, V: StmtVisitor<T, R>> StmtAccept<T, R, V> for Stmt<T> {
             Self::If(stmt) => stmt.accept(visitor),
             Self::Loop(stmt) => stmt.accept(visitor),
             Self::While(stmt) => stmt.accept(visitor),
+            Self::For(stmt) => stmt.accept(visitor),
             Self::Break(stmt) => stmt.accept(visitor),
             Self::Then(stmt) => stmt.accept(visitor),
         };
@@ -1331,6 +1595,12 @@ impl<T: ResolvedType, R, V: StmtVisitor<T, R>> StmtAccept<T, R, V> for Arc<Mutex
     }
 }
 
+impl<T: ResolvedType, R, V: StmtVisitor<T, R>> StmtAccept<T, R, V> for Arc<Mutex<ForStmt<T>>> {
+    fn accept(&self, visitor: &mut V) -> R {
+        return visitor.visit_for_stmt(self.clone());
+    }
+}
+
 impl<T: ResolvedType, R, V: StmtVisitor<T, R>> StmtAccept<T, R, V> for Arc<Mutex<BreakStmt<T>>> {
     fn accept(&self, visitor: &mut V) -> R {
         return visitor.visit_break_stmt(self.clone());
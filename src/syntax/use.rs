@@ -1,7 +1,7 @@
 use super::*;
 
 use crate::log;
-use crate::token::Token;
+use crate::token::{Span, Token};
 use crate::utils::{from, try_from};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -22,6 +22,17 @@ impl<T: ResolvedType> Node<Use> for Use<T> {
     }
 }
 
+impl<T: ResolvedType> Spanned for Use<T> {
+    fn span(&self) -> Span {
+        let start = match &self.use_mod {
+            Some(UseMod::Pub(token)) => token,
+            None => &self.use_token,
+        };
+
+        return start.span.to(&self.path.span());
+    }
+}
+
 impl<T: ResolvedType> From<Use<()>> for Use<Option<T>> {
     fn from(value: Use<()>) -> Self {
         return Self {
@@ -59,6 +70,24 @@ pub struct UseStaticPath<ResolvedType = ()> {
     pub details: Either<UseStaticPathNext<ResolvedType>, ResolvedType>,
 }
 
+impl<T: ResolvedType> Spanned for UseStaticPath<T> {
+    fn span(&self) -> Span {
+        let start = match &self.pre {
+            Some(UseStaticPathPre::DoubleColon(token))
+            | Some(UseStaticPathPre::CurrentDir(token))
+            | Some(UseStaticPathPre::RootDir(token)) => &token.span,
+
+            None => &self.name.span,
+        };
+
+        match &self.details {
+            Either::A(UseStaticPathNext::Single(single)) => return start.to(&single.path.span()),
+            Either::A(UseStaticPathNext::Many(many)) => return start.to(&many.close_brace.span),
+            Either::B(_) => return start.to(&self.name.span),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum UseStaticPathPre {
     DoubleColon(Arc<Token>),
@@ -114,10 +114,24 @@ pub enum TokenType {
     Minus,      // -
     MinusEqual, // -=
 
-    Equal, // =
+    Asterisk,      // *
+    AsteriskEqual, // *=
+
+    Slash,      // /
+    SlashEqual, // /=
+
+    Percent,      // %
+    PercentEqual, // %=
+
+    Equal,      // =
+    EqualEqual, // ==
+
+    BangEqual, // !=
 
     Amp, // &
 
+    At, // @
+
     Question, // ?
 
     Less,      // <
@@ -129,27 +143,37 @@ pub enum TokenType {
     Colon,       // :
     DoubleColon, // ::
 
-    Dot,      // .
-    DotSlash, // ./
+    Dot,         // .
+    DotSlash,    // ./
+    DotDot,      // ..
+    DotDotEqual, // ..=
 
     Tilde,      // ~
     TildeSlash, // ~/
 
     // Keywords
+    And,
     Break,
     Const,
     Else,
+    Enum,
     Fn,
+    For,
     If,
+    Impl,
+    In,
     Loop,
+    Match,
     Mut,
     Norm,
     Not,
+    Or,
     Pub,
     Pure,
     Return,
     Risk,
     Safe,
+    SelfVal,
     Struct,
     Then,
     Use,
@@ -158,6 +182,7 @@ pub enum TokenType {
     // Literals
     True,
     False,
+    NoneLiteral,
 
     PlainString,
 
@@ -191,6 +216,31 @@ impl Span {
             end: Position::zero(),
         };
     }
+
+    pub fn to(&self, other: &Span) -> Self {
+        return Self {
+            start: self.start.clone(),
+            end: other.end.clone(),
+        };
+    }
+
+    /// A span within one line, from column `start` up to `end`, where `index`
+    /// is the offset of `start` in the file.
+    #[cfg(test)]
+    pub fn on_line(index: usize, line: usize, start: usize, end: usize) -> Self {
+        return Self {
+            start: Position {
+                index,
+                line,
+                column: start,
+            },
+            end: Position {
+                index: index + end - start,
+                line,
+                column: end,
+            },
+        };
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -2,6 +2,7 @@ use crate::type_resolver::ExportsPackage;
 
 use std::{
     collections::HashMap,
+    fmt,
     sync::{Arc, Mutex},
 };
 
@@ -18,11 +19,37 @@ pub enum FeType {
     Callable(Callable),
     Struct(FeStruct),
     Instance(FeInstance),
+
+    /// A declared enum, like `Struct` is for structs
+    Enum(FeEnum),
+
+    /// A value of an enum, like `Instance` is for structs
+    EnumInstance(FeEnum),
+
     String(Option<StringDetails>),
     Bool(Option<bool>),
     Number(Option<NumberDetails>),
+
+    /// `a..b` or `a..=b`, over values of the boxed type.
+    Range(Box<FeType>),
+
+    /// `T?`, either a value of the boxed type or `none`
+    Optional(Box<FeType>),
+
+    /// The type of `none` before it's known what it's missing, which casts to
+    /// any optional
+    EmptyOptional,
+
     Ref(FeRefOf),
     Owned(FeOwnedOf),
+
+    /// A type parameter, like the `T` in `fn id<T>(x: T): T`, inside the
+    /// declaration it belongs to.
+    Generic(Arc<str>),
+
+    /// The type of an expression that failed to type check.
+    /// Casts to and from anything, so one mistake doesn't cascade into more errors.
+    Error,
 }
 
 impl PartialEq for FeType {
@@ -36,11 +63,18 @@ impl PartialEq for FeType {
             (Self::Callable(this), Self::Callable(other)) => return this == other,
             (Self::Struct(this), Self::Struct(other)) => return this == other,
             (Self::Instance(this), Self::Instance(other)) => return this == other,
+            (Self::Enum(this), Self::Enum(other)) => return this == other,
+            (Self::EnumInstance(this), Self::EnumInstance(other)) => return this == other,
             (Self::String(this), Self::String(other)) => return this == other,
             (Self::Bool(this), Self::Bool(other)) => return this == other,
             (Self::Number(this), Self::Number(other)) => return this == other,
+            (Self::Range(this), Self::Range(other)) => return this == other,
+            (Self::Optional(this), Self::Optional(other)) => return this == other,
+            (Self::EmptyOptional, Self::EmptyOptional) => return true,
             (Self::Ref(this), Self::Ref(other)) => return this == other,
             (Self::Owned(this), Self::Owned(other)) => return this == other,
+            (Self::Generic(this), Self::Generic(other)) => return this == other,
+            (Self::Error, Self::Error) => return true,
 
             _ => return false,
         }
@@ -65,12 +99,103 @@ impl FeType {
             _ => return self,
         }
     }
+
+    /// The type of a variable initialized with a value of this type, where a number
+    /// literal that didn't take a size from anywhere else is an `Int`, or an `F64`
+    /// when it's a decimal.
+    pub fn with_default_number_size(self) -> FeType {
+        match self {
+            Self::Number(Some(NumberDetails::Integer(None, value))) => {
+                return Self::Number(Some(NumberDetails::Integer(Some(IntegerSize::I64), value)));
+            }
+            Self::Number(Some(NumberDetails::Decimal(None, value))) => {
+                return Self::Number(Some(NumberDetails::Decimal(Some(DecimalSize::F64), value)));
+            }
+            Self::Optional(of) => return Self::Optional(Box::new(of.with_default_number_size())),
+            Self::Range(of) => return Self::Range(Box::new(of.with_default_number_size())),
+            typ => return typ,
+        }
+    }
+}
+
+impl fmt::Display for FeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Package(_) => return write!(f, "package"),
+            Self::Callable(callable) => {
+                let params = callable
+                    .params
+                    .iter()
+                    .map(|(_, typ)| typ.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                if !callable.generics.is_empty() {
+                    write!(f, "fn<{}>({params})", callable.generics.join(", "))?;
+                } else {
+                    write!(f, "fn({params})")?;
+                }
+
+                if let Some(return_type) = &callable.return_type {
+                    write!(f, ": {return_type}")?;
+                }
+
+                return Ok(());
+            }
+            Self::Struct(fe_struct) => return write!(f, "struct {}", fe_struct.name),
+            Self::Instance(instance) if !instance.generic_args.is_empty() => {
+                let args = instance
+                    .generic_args
+                    .iter()
+                    .map(|arg| arg.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                return write!(f, "{}<{args}>", instance.name);
+            }
+            Self::Instance(instance) => return write!(f, "{}", instance.name),
+            Self::Enum(fe_enum) => return write!(f, "enum {}", fe_enum.name),
+            Self::EnumInstance(fe_enum) => return write!(f, "{}", fe_enum.name),
+            Self::String(_) => return write!(f, "{STRING_TYPE_NAME}"),
+            Self::Bool(_) => return write!(f, "{BOOL_TYPE_NAME}"),
+            // `Int` is what's written for an `I64` nearly everywhere
+            Self::Number(Some(NumberDetails::Integer(Some(IntegerSize::I64), _))) => {
+                return write!(f, "{INT_TYPE_NAME}")
+            }
+            Self::Number(Some(NumberDetails::Integer(Some(size), _))) => {
+                return write!(f, "{}", size.name())
+            }
+            Self::Number(Some(NumberDetails::Decimal(Some(size), _))) => {
+                return write!(f, "{}", size.name())
+            }
+
+            // Literals that haven't taken a size yet, like Rust's `{integer}`
+            Self::Number(Some(NumberDetails::Integer(None, _))) => return write!(f, "{{integer}}"),
+            Self::Number(Some(NumberDetails::Decimal(None, _))) => return write!(f, "{{decimal}}"),
+            Self::Number(None) => return write!(f, "Number"),
+            Self::Range(of) => return write!(f, "Range<{of}>"),
+            Self::Optional(of) => return write!(f, "{of}?"),
+            Self::EmptyOptional => return write!(f, "none"),
+            Self::Ref(FeRefOf {
+                ref_type: FeRefType::Const,
+                of,
+            }) => return write!(f, "&{of}"),
+            Self::Ref(FeRefOf {
+                ref_type: FeRefType::Mut,
+                of,
+            }) => return write!(f, "&mut {of}"),
+            Self::Owned(owned) => return write!(f, "{}", owned.of),
+            Self::Generic(name) => return write!(f, "{name}"),
+            Self::Error => return write!(f, "{{unknown}}"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Callable {
     pub special: Option<SpecialCallable>,
     pub name: Arc<str>,
+    pub generics: Vec<Arc<str>>,
     pub params: Vec<(Arc<str>, FeType)>,
     pub return_type: Option<Box<FeType>>,
 }
@@ -84,7 +209,9 @@ pub enum SpecialCallable {
 pub struct FeStruct {
     pub special: Option<SpecialStruct>,
     pub name: Arc<str>,
+    pub generics: Vec<Arc<str>>,
     pub fields: Vec<FeStructField>,
+    pub methods: FeMethods,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -95,13 +222,100 @@ pub struct FeStructField {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub enum SpecialStruct {}
+pub enum SpecialStruct {
+    /// A variant like `Circle { r: Int }` of the enum, constructed like a struct
+    EnumVariant(FeEnum),
+}
+
+/// The methods and associated functions of a struct, from its `impl` blocks.
+///
+/// Shared by the struct and all of its instances, so methods declared after a
+/// struct is first used are still found on it.
+#[derive(Debug, Clone, Default)]
+pub struct FeMethods(Arc<Mutex<HashMap<Arc<str>, FeMethod>>>);
+
+impl PartialEq for FeMethods {
+    fn eq(&self, other: &Self) -> bool {
+        return Arc::ptr_eq(&self.0, &other.0);
+    }
+}
+
+impl FeMethods {
+    pub fn get(&self, name: &str) -> Option<FeMethod> {
+        return self.0.try_lock().unwrap().get(name).cloned();
+    }
+
+    /// Returns false if there's already a method with the same name.
+    pub fn insert(&self, method: FeMethod) -> bool {
+        let mut methods = self.0.try_lock().unwrap();
+
+        if methods.contains_key(&method.callable.name) {
+            return false;
+        }
+
+        methods.insert(method.callable.name.clone(), method);
+
+        return true;
+    }
+
+    pub fn names(&self) -> Vec<Arc<str>> {
+        return self.0.try_lock().unwrap().keys().cloned().collect();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeMethod {
+    /// How it takes the instance it's called on, `None` for associated functions
+    pub receiver: Option<FeReceiver>,
+
+    /// Without the receiver. Its generics start with the struct's type parameters.
+    pub callable: Callable,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeReceiver {
+    Owned(FeOwnedMut),
+    Ref(FeRefType),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeEnum {
+    pub name: Arc<str>,
+    pub variants: Vec<FeEnumVariant>,
+}
+
+impl FeEnum {
+    pub fn variant(&self, name: &str) -> Option<&FeEnumVariant> {
+        return self
+            .variants
+            .iter()
+            .find(|variant| variant.name.as_ref() == name);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeEnumVariant {
+    pub name: Arc<str>,
+    pub fields: FeEnumVariantFields,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeEnumVariantFields {
+    Unit,
+    Tuple(Vec<FeType>),
+    Struct(Vec<FeStructField>),
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FeInstance {
     pub special: Option<SpecialInstance>,
     pub name: Arc<str>,
+
+    /// The types the struct's type parameters stand for in this instance, which
+    /// are already substituted into its fields
+    pub generic_args: Vec<FeType>,
     pub fields: HashMap<Arc<str>, FeStructField>,
+    pub methods: FeMethods,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -113,11 +327,109 @@ pub enum StringDetails {
     Format,
 }
 
+/// The size and, when known at compile time, the value of a number.
+///
+/// Literals have no size until they're used somewhere that has one, like
+/// Rust's `{integer}`, so `1` can be passed as a `U8` or an `I64`.
 #[derive(Debug, Clone, PartialEq)]
 pub enum NumberDetails {
-    // TODO: number sizes, pos/neg, bounds, bignums, etc
-    Integer(Option<i64>),
-    Decimal(Option<f64>),
+    // TODO: bignums
+    Integer(Option<IntegerSize>, Option<i128>),
+    Decimal(Option<DecimalSize>, Option<f64>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegerSize {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+impl IntegerSize {
+    pub const ALL: &'static [IntegerSize] = &[
+        Self::I8,
+        Self::I16,
+        Self::I32,
+        Self::I64,
+        Self::U8,
+        Self::U16,
+        Self::U32,
+        Self::U64,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::I8 => return "I8",
+            Self::I16 => return "I16",
+            Self::I32 => return "I32",
+            Self::I64 => return "I64",
+            Self::U8 => return "U8",
+            Self::U16 => return "U16",
+            Self::U32 => return "U32",
+            Self::U64 => return "U64",
+        }
+    }
+
+    pub fn min(&self) -> i128 {
+        match self {
+            Self::I8 => return i8::MIN.into(),
+            Self::I16 => return i16::MIN.into(),
+            Self::I32 => return i32::MIN.into(),
+            Self::I64 => return i64::MIN.into(),
+            Self::U8 | Self::U16 | Self::U32 | Self::U64 => return 0,
+        }
+    }
+
+    pub fn max(&self) -> i128 {
+        match self {
+            Self::I8 => return i8::MAX.into(),
+            Self::I16 => return i16::MAX.into(),
+            Self::I32 => return i32::MAX.into(),
+            Self::I64 => return i64::MAX.into(),
+            Self::U8 => return u8::MAX.into(),
+            Self::U16 => return u16::MAX.into(),
+            Self::U32 => return u32::MAX.into(),
+            Self::U64 => return u64::MAX.into(),
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        return self.min() < 0;
+    }
+
+    pub fn contains(&self, value: i128) -> bool {
+        return (self.min()..=self.max()).contains(&value);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalSize {
+    F32,
+    F64,
+}
+
+impl DecimalSize {
+    pub const ALL: &'static [DecimalSize] = &[Self::F32, Self::F64];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::F32 => return "F32",
+            Self::F64 => return "F64",
+        }
+    }
+
+    /// Whether `value` fits this size without overflowing to infinity.
+    pub fn contains(&self, value: f64) -> bool {
+        match self {
+            Self::F32 => return (value as f32).is_finite(),
+            Self::F64 => return value.is_finite(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
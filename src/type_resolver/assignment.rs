@@ -0,0 +1,734 @@
+use super::*;
+
+/// Checks that variables declared without a value, like `const x: Int`, are
+/// assigned on every path before they're read, and that a `const` is assigned
+/// at most once.
+///
+/// Runs once the package is resolved, since the resolver itself skips nodes
+/// that were resolved on an earlier pass.
+pub(super) struct DefiniteAssignment {
+    current_file: PathBuf,
+    errors: Vec<Diagnostic>,
+
+    /// Every variable declared without a value, indexed by the position of its
+    /// state in [`Flow`]
+    late_vars: Vec<LateVar>,
+
+    /// Names in scope, innermost last. `None` for names declared with a value,
+    /// which can hide a late variable of the same name.
+    scopes: Vec<Vec<(Arc<str>, Option<usize>)>>,
+
+    /// What's assigned at the current point, `None` where it can't be reached
+    flow: Option<Flow>,
+
+    /// Loops and `if`s that a `break` or `then` can leave, innermost last
+    exits: Vec<Exit>,
+}
+
+#[derive(Debug, Clone)]
+struct LateVar {
+    is_const: bool,
+
+    /// The first assignment found, for pointing at it when there's a second one
+    assigned_at: Option<Span>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Init {
+    Unassigned,
+
+    /// Assigned on some paths here, but not all
+    Maybe,
+
+    Assigned,
+}
+
+type Flow = Vec<Init>;
+
+struct Exit {
+    id: NodeId<()>,
+
+    /// The flow at every `break` or `then` leaving it
+    flows: Vec<Flow>,
+}
+
+impl DefiniteAssignment {
+    pub(super) fn check_package(pkg: &FeSyntaxPackage<FeType>) -> Vec<Diagnostic> {
+        let mut this = Self {
+            current_file: PathBuf::new(),
+            errors: vec![],
+
+            late_vars: vec![],
+            scopes: vec![],
+            flow: None,
+            exits: vec![],
+        };
+
+        this.check_pkg(pkg);
+
+        return this.errors;
+    }
+
+    fn check_pkg(&mut self, pkg: &FeSyntaxPackage<FeType>) {
+        match pkg {
+            FeSyntaxPackage::File(file) => self.check_file(file),
+            FeSyntaxPackage::Dir(dir) => {
+                self.check_file(&dir.entry_file);
+
+                for pkg in dir.local_packages.values() {
+                    self.check_pkg(&pkg.try_lock().unwrap());
+                }
+            }
+        }
+    }
+
+    fn check_file(&mut self, file: &FeSyntaxFile<FeType>) {
+        self.current_file = file.path.clone();
+
+        let syntax = file.syntax.try_lock().unwrap();
+
+        for decl in &syntax.decls {
+            match &*decl.try_lock().unwrap() {
+                Decl::Fn(decl) => self.check_fn(&decl.try_lock().unwrap()),
+                Decl::Impl(decl) => {
+                    for method in &decl.try_lock().unwrap().methods {
+                        self.check_fn(&method.try_lock().unwrap());
+                    }
+                }
+                Decl::Struct(_) | Decl::Enum(_) => {}
+            }
+        }
+    }
+
+    fn check_fn(&mut self, decl: &FnDecl<FeType>) {
+        let FnDeclBody::Block(body) = &decl.body else {
+            return;
+        };
+
+        self.late_vars.clear();
+        self.flow = Some(vec![]);
+
+        self.scopes.push(
+            decl.params
+                .iter()
+                .map(|param| (param.name.lexeme.clone(), None))
+                .collect(),
+        );
+
+        self.block(body);
+
+        self.scopes.pop();
+    }
+
+    fn block<S: PartialEq>(&mut self, block: &CodeBlock<FeType, S>) {
+        self.scopes.push(vec![]);
+
+        for stmt in &block.stmts {
+            stmt.try_lock().unwrap().accept(self);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn expr(&mut self, expr: &NestedExpr<FeType>) {
+        expr.0.try_lock().unwrap().accept(self);
+    }
+
+    fn declare(&mut self, name: &Arc<Token>, late_var: Option<LateVar>) {
+        let index = late_var.map(|late_var| {
+            self.late_vars.push(late_var);
+
+            if let Some(flow) = &mut self.flow {
+                flow.resize(self.late_vars.len(), Init::Unassigned);
+            }
+
+            return self.late_vars.len() - 1;
+        });
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push((name.lexeme.clone(), index));
+        }
+    }
+
+    /// The late variable a name refers to, if it's one.
+    fn lookup(&self, name: &str) -> Option<usize> {
+        return self
+            .scopes
+            .iter()
+            .flatten()
+            .rev()
+            .find(|(scoped, _)| &**scoped == name)
+            .and_then(|(_, index)| *index);
+    }
+
+    fn init(&self, index: usize) -> Option<Init> {
+        let flow = self.flow.as_ref()?;
+
+        return Some(flow.get(index).copied().unwrap_or(Init::Unassigned));
+    }
+
+    fn read(&mut self, name: &Arc<Token>) {
+        let Some(index) = self.lookup(&name.lexeme) else {
+            return;
+        };
+
+        if let Some(Init::Unassigned | Init::Maybe) = self.init(index) {
+            let diagnostic = self.type_error(TypeError::UnassignedVariable {
+                name: name.lexeme.clone(),
+                span: name.span.clone(),
+            });
+
+            self.report(diagnostic);
+        }
+    }
+
+    fn assign(&mut self, name: &Arc<Token>) {
+        let Some(index) = self.lookup(&name.lexeme) else {
+            return;
+        };
+
+        let late_var = self.late_vars[index].clone();
+
+        if let (true, Some(Init::Assigned | Init::Maybe)) = (late_var.is_const, self.init(index)) {
+            let mut diagnostic = self.type_error(TypeError::AssignToConst {
+                span: name.span.clone(),
+            });
+
+            match late_var.assigned_at {
+                Some(assigned_at) if assigned_at == name.span => {
+                    diagnostic =
+                        diagnostic.with_note("it's assigned again on the next run of the loop");
+                }
+                Some(assigned_at) => {
+                    diagnostic = diagnostic.with_secondary(assigned_at, "first assigned here");
+                }
+                None => {}
+            }
+
+            self.report(diagnostic);
+        }
+
+        if let Some(flow) = &mut self.flow {
+            flow.resize(flow.len().max(index + 1), Init::Unassigned);
+            flow[index] = Init::Assigned;
+        }
+
+        self.late_vars[index]
+            .assigned_at
+            .get_or_insert_with(|| name.span.clone());
+    }
+
+    /// Continues from where every one of `flows` meets, `None` if none of them
+    /// get there.
+    fn join(flows: impl IntoIterator<Item = Option<Flow>>) -> Option<Flow> {
+        let mut joined: Option<Flow> = None;
+
+        for flow in flows.into_iter().flatten() {
+            let Some(current) = &mut joined else {
+                joined = Some(flow);
+                continue;
+            };
+
+            // Variables declared in only one of the branches are out of scope after them
+            current.truncate(flow.len());
+
+            for (current, other) in current.iter_mut().zip(flow) {
+                if *current != other {
+                    *current = Init::Maybe;
+                }
+            }
+        }
+
+        return joined;
+    }
+
+    /// Checks branches that each start from the current flow, like the blocks of
+    /// an `if`, and continues from where they meet.
+    fn branches(&mut self, branches: Vec<&dyn Fn(&mut Self)>) {
+        let start = self.flow.clone();
+        let mut ends = vec![];
+
+        for branch in branches {
+            self.flow = start.clone();
+            branch(self);
+            ends.push(self.flow.take());
+        }
+
+        self.flow = Self::join(ends);
+    }
+
+    /// Checks the body of a loop once for its first run, and again for every
+    /// run after that, where anything the body assigns may already be assigned.
+    ///
+    /// Continues from where the loop ends without a `break`, and returns the flow
+    /// at every `break`.
+    fn loop_body(&mut self, id: NodeId<()>, may_skip: bool, body: &dyn Fn(&mut Self)) -> Vec<Flow> {
+        let start = self.flow.clone();
+
+        self.exits.push(Exit { id, flows: vec![] });
+
+        body(self);
+
+        if let (Some(end), Some(start)) = (&self.flow, &start) {
+            if end != start {
+                self.flow = Self::join([Some(start.clone()), Some(end.clone())]);
+                body(self);
+            }
+        }
+
+        let exit = self.exits.pop().unwrap();
+
+        self.flow = if may_skip {
+            Self::join([start, self.flow.take()])
+        } else {
+            None
+        };
+
+        return exit.flows;
+    }
+
+    /// Continues from where a loop ends, with or without a `break`.
+    fn loop_end(&mut self, breaks: Vec<Flow>) {
+        let mut ends = vec![self.flow.take()];
+        ends.extend(breaks.into_iter().map(Some));
+
+        self.flow = Self::join(ends);
+    }
+
+    fn exit(&mut self, id: NodeId<()>) {
+        let flow = self.flow.take();
+
+        if let (Some(flow), Some(exit)) = (flow, self.exits.iter_mut().find(|e| e.id == id)) {
+            exit.flows.push(flow);
+        }
+    }
+
+    /// Whether a `while` condition is always true, making it a `loop`.
+    fn is_always_true(condition: &NestedExpr<FeType>) -> bool {
+        let resolved_type = condition.0.try_lock().unwrap().resolved_type();
+
+        return matches!(
+            resolved_type.as_ref().map(FeType::actual_type),
+            Some(FeType::Bool(Some(true)))
+        );
+    }
+
+    fn type_error(&self, err: TypeError) -> Diagnostic {
+        return Diagnostic::error(err.to_string())
+            .with_code(err.code())
+            .with_file(self.current_file.clone())
+            .with_span(err.span().clone());
+    }
+
+    fn report(&mut self, diagnostic: Diagnostic) {
+        // Loop bodies are checked twice, only report once
+        if !self.errors.contains(&diagnostic) {
+            self.errors.push(diagnostic);
+        }
+    }
+}
+
+impl StmtVisitor<FeType> for DefiniteAssignment {
+    fn visit_expr_stmt(&mut self, stmt: Arc<Mutex<ExprStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        stmt.expr.try_lock().unwrap().accept(self);
+    }
+
+    fn visit_var_decl_stmt(&mut self, stmt: Arc<Mutex<VarDeclStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        if let Some(value) = &stmt.value {
+            self.expr(&value.value);
+        }
+
+        let VarDeclTarget::Ident(ident) = &stmt.target;
+        let name = ident.try_lock().unwrap().ident.clone();
+
+        let late_var = stmt.value.is_none().then_some(LateVar {
+            is_const: matches!(stmt.var_mut, VarDeclMut::Const(_)),
+            assigned_at: None,
+        });
+
+        self.declare(&name, late_var);
+    }
+
+    fn visit_assign_stmt(&mut self, stmt: Arc<Mutex<AssignStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        self.expr(&stmt.value);
+
+        let target = stmt.target.0.try_lock().unwrap();
+
+        let Expr::Ident(ident) = &*target else {
+            // Assigning to a field reads the variable it's in
+            target.accept(self);
+            return;
+        };
+
+        let name = ident.try_lock().unwrap().ident.clone();
+
+        if !matches!(stmt.op, AssignOp::Eq(_)) {
+            self.read(&name);
+        }
+
+        self.assign(&name);
+    }
+
+    fn visit_return_stmt(&mut self, stmt: Arc<Mutex<ReturnStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        if let Some(value) = &stmt.value {
+            self.expr(value);
+        }
+
+        self.flow = None;
+    }
+
+    fn visit_if_stmt(&mut self, stmt: Arc<Mutex<IfStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        let id = stmt.node_id().into::<()>();
+        self.exits.push(Exit { id, flows: vec![] });
+
+        self.expr(&stmt.condition);
+
+        let mut ends = vec![];
+        let mut flow = self.flow.clone();
+
+        self.block(&stmt.then);
+        ends.push(self.flow.take());
+
+        for else_if in &stmt.else_ifs {
+            self.flow = flow.clone();
+            self.expr(&else_if.condition);
+            flow = self.flow.clone();
+
+            self.block(&else_if.then);
+            ends.push(self.flow.take());
+        }
+
+        self.flow = flow;
+        if let Some(else_) = &stmt.else_ {
+            self.block(&else_.then);
+        }
+        ends.push(self.flow.take());
+
+        let exit = self.exits.pop().unwrap();
+        ends.extend(exit.flows.into_iter().map(Some));
+
+        self.flow = Self::join(ends);
+    }
+
+    fn visit_loop_stmt(&mut self, stmt: Arc<Mutex<LoopStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        let id = stmt.node_id().into::<()>();
+        let breaks = self.loop_body(id, false, &|this| this.block(&stmt.block));
+        self.loop_end(breaks);
+    }
+
+    fn visit_while_stmt(&mut self, stmt: Arc<Mutex<WhileStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        let id = stmt.node_id().into::<()>();
+        let may_skip = !Self::is_always_true(&stmt.condition);
+
+        let breaks = self.loop_body(id, may_skip, &|this| {
+            this.expr(&stmt.condition);
+            this.block(&stmt.block);
+        });
+
+        // The `else` runs when the loop ends without a `break`
+        if let Some(else_) = &stmt.else_ {
+            self.block(&else_.block);
+        }
+
+        self.loop_end(breaks);
+    }
+
+    fn visit_for_stmt(&mut self, stmt: Arc<Mutex<ForStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        self.expr(&stmt.iter);
+
+        let id = stmt.node_id().into::<()>();
+        let breaks = self.loop_body(id, true, &|this| {
+            this.scopes.push(vec![(stmt.name.lexeme.clone(), None)]);
+            this.block(&stmt.block);
+            this.scopes.pop();
+        });
+        self.loop_end(breaks);
+    }
+
+    fn visit_break_stmt(&mut self, stmt: Arc<Mutex<BreakStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        if let Some(value) = &stmt.value {
+            self.expr(value);
+        }
+
+        if let Some(handler) = &stmt.handler {
+            self.exit(handler.node_id().into::<()>());
+        }
+
+        self.flow = None;
+    }
+
+    fn visit_then_stmt(&mut self, stmt: Arc<Mutex<ThenStmt<FeType>>>) {
+        let stmt = &*stmt.try_lock().unwrap();
+
+        self.expr(&stmt.value);
+
+        if let Some(handler) = &stmt.handler {
+            self.exit(handler.node_id().into::<()>());
+        }
+
+        self.flow = None;
+    }
+}
+
+impl ExprVisitor<FeType> for DefiniteAssignment {
+    fn visit_bool_literal_expr(&mut self, _expr: Arc<Mutex<BoolLiteralExpr<FeType>>>) {}
+
+    fn visit_none_literal_expr(&mut self, _expr: Arc<Mutex<NoneLiteralExpr<FeType>>>) {}
+
+    fn visit_number_literal_expr(&mut self, _expr: Arc<Mutex<NumberLiteralExpr<FeType>>>) {}
+
+    fn visit_plain_string_literal_expr(
+        &mut self,
+        _expr: Arc<Mutex<PlainStringLiteralExpr<FeType>>>,
+    ) {
+    }
+
+    fn visit_fmt_string_literal_expr(&mut self, expr: Arc<Mutex<FmtStringLiteralExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        for part in &expr.rest {
+            self.expr(&part.expr);
+        }
+    }
+
+    fn visit_ident_expr(&mut self, expr: Arc<Mutex<IdentExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.read(&expr.ident);
+    }
+
+    fn visit_call_expr(&mut self, expr: Arc<Mutex<CallExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.expr(&expr.callee);
+
+        for arg in &expr.args {
+            self.expr(&arg.value);
+        }
+    }
+
+    fn visit_unary_expr(&mut self, expr: Arc<Mutex<UnaryExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.expr(&expr.value);
+    }
+
+    fn visit_binary_expr(&mut self, expr: Arc<Mutex<BinaryExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.expr(&expr.lhs);
+        self.expr(&expr.rhs);
+    }
+
+    fn visit_logical_expr(&mut self, expr: Arc<Mutex<LogicalExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.expr(&expr.lhs);
+
+        // The right-hand side doesn't always run
+        self.branches(vec![&|this| this.expr(&expr.rhs), &|_| {}]);
+    }
+
+    fn visit_static_ref_expr(&mut self, _expr: Arc<Mutex<StaticRefExpr<FeType>>>) {}
+
+    fn visit_construct_expr(&mut self, expr: Arc<Mutex<ConstructExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        for arg in &expr.args {
+            match arg {
+                ConstructArg::Field(field) => self.expr(&field.value),
+            }
+        }
+    }
+
+    fn visit_get_expr(&mut self, expr: Arc<Mutex<GetExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.expr(&expr.target);
+    }
+
+    fn visit_if_expr(&mut self, expr: Arc<Mutex<IfExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        let id = expr.node_id().into::<()>();
+        self.exits.push(Exit { id, flows: vec![] });
+
+        self.expr(&expr.condition);
+
+        let mut ends = vec![];
+        let mut flow = self.flow.clone();
+
+        match &expr.then {
+            IfExprThen::Ternary(then) => self.expr(&then.then_expr),
+            IfExprThen::Block(then) => self.block(&then.block),
+        }
+        ends.push(self.flow.take());
+
+        for else_if in &expr.else_ifs {
+            self.flow = flow.clone();
+
+            match else_if {
+                IfExprElseIf::Ternary(else_if) => {
+                    self.expr(&else_if.condition);
+                    flow = self.flow.clone();
+                    self.expr(&else_if.expr);
+                }
+                IfExprElseIf::Block(else_if) => {
+                    self.expr(&else_if.condition);
+                    flow = self.flow.clone();
+                    self.block(&else_if.block);
+                }
+            }
+
+            ends.push(self.flow.take());
+        }
+
+        self.flow = flow;
+        match &expr.else_ {
+            Some(IfExprElse::Ternary(else_)) => self.expr(&else_.else_expr),
+            Some(IfExprElse::Block(else_)) => self.block(&else_.block),
+            None => {}
+        }
+        ends.push(self.flow.take());
+
+        let exit = self.exits.pop().unwrap();
+        ends.extend(exit.flows.into_iter().map(Some));
+
+        self.flow = Self::join(ends);
+    }
+
+    fn visit_match_expr(&mut self, expr: Arc<Mutex<MatchExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        let id = expr.node_id().into::<()>();
+        self.exits.push(Exit { id, flows: vec![] });
+
+        self.expr(&expr.value);
+
+        // Exhaustive, so one of the arms always runs
+        let mut ends = vec![];
+        let flow = self.flow.clone();
+
+        for arm in &expr.arms {
+            self.flow = flow.clone();
+
+            self.scopes.push(vec![]);
+            for name in arm.pattern.bindings() {
+                self.declare(&name, None);
+            }
+
+            if let Some(guard) = &arm.guard {
+                self.expr(&guard.condition);
+            }
+
+            match &arm.body {
+                MatchArmBody::Ternary(ternary) => self.expr(&ternary.then_expr),
+                MatchArmBody::Block(block) => self.block(block),
+            }
+
+            self.scopes.pop();
+
+            ends.push(self.flow.take());
+        }
+
+        let exit = self.exits.pop().unwrap();
+        ends.extend(exit.flows.into_iter().map(Some));
+
+        self.flow = Self::join(ends);
+    }
+
+    fn visit_loop_expr(&mut self, expr: Arc<Mutex<LoopExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        let id = expr.node_id().into::<()>();
+        let breaks = self.loop_body(id, false, &|this| this.block(&expr.block));
+        self.loop_end(breaks);
+    }
+
+    fn visit_while_expr(&mut self, expr: Arc<Mutex<WhileExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        let id = expr.node_id().into::<()>();
+        let may_skip = !Self::is_always_true(&expr.condition);
+
+        let breaks = self.loop_body(id, may_skip, &|this| {
+            this.expr(&expr.condition);
+            this.block(&expr.block);
+        });
+
+        self.branches(vec![
+            &|this| match &expr.then {
+                Some(WhileExprThen::Ternary(then)) => this.expr(&then.then_expr),
+                Some(WhileExprThen::Block(then)) => this.block(&then.block),
+                None => {}
+            },
+            &|this| match &expr.else_ {
+                Some(WhileExprElse::Ternary(else_)) => this.expr(&else_.else_expr),
+                Some(WhileExprElse::Block(else_)) => this.block(&else_.block),
+                None => {}
+            },
+        ]);
+
+        self.loop_end(breaks);
+    }
+
+    fn visit_for_expr(&mut self, expr: Arc<Mutex<ForExpr<FeType>>>) {
+        let expr = &*expr.try_lock().unwrap();
+
+        self.expr(&expr.iter);
+
+        let id = expr.node_id().into::<()>();
+
+        let breaks = self.loop_body(id, true, &|this| {
+            this.scopes.push(vec![(expr.name.lexeme.clone(), None)]);
+            this.block(&expr.block);
+            this.scopes.pop();
+        });
+
+        // Leaving with `break` skips the `else`
+        self.expr(&expr.else_.else_expr);
+
+        self.loop_end(breaks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join() {
+        use Init::*;
+
+        assert_eq!(
+            DefiniteAssignment::join([
+                Some(vec![Assigned, Unassigned, Assigned]),
+                Some(vec![Assigned, Assigned]),
+            ]),
+            Some(vec![Assigned, Maybe])
+        );
+        assert_eq!(
+            DefiniteAssignment::join([None, Some(vec![Assigned]), None]),
+            Some(vec![Assigned])
+        );
+        assert_eq!(DefiniteAssignment::join([None, None]), None);
+    }
+}
@@ -0,0 +1,384 @@
+use super::*;
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Why an operation on numbers can't be done, found at compile time.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum ConstEvalError {
+    /// The operands are of different kinds or sizes, like an `Int` and a decimal.
+    IncompatibleOperands,
+
+    /// The known result doesn't fit the size it's computed in.
+    Overflow,
+
+    /// The known divisor of an integer `/` or `%` is zero.
+    DivisionByZero,
+}
+
+// Folding of the values known at compile time through operators, like `2 * 3` to `6`
+impl FeTypeResolver {
+    /// `typ` with its compile time value forgotten, e.g. the `Int` of the literal `0`.
+    ///
+    /// Used wherever a value could come from more than one place, like the
+    /// branches of an `if` or a `mut` variable that can be reassigned.
+    pub(super) fn without_known_value(typ: FeType) -> FeType {
+        match typ {
+            FeType::Number(Some(NumberDetails::Integer(size, _))) => {
+                return FeType::Number(Some(NumberDetails::Integer(size, None)));
+            }
+            FeType::Number(Some(NumberDetails::Decimal(size, _))) => {
+                return FeType::Number(Some(NumberDetails::Decimal(size, None)));
+            }
+            FeType::Bool(_) => return FeType::Bool(None),
+            FeType::Optional(of) => {
+                return FeType::Optional(Box::new(Self::without_known_value(*of)))
+            }
+            typ => return typ,
+        }
+    }
+
+    /// A variable's declared type, keeping what's known about the value it's
+    /// initialized with, so `const limit: U8 = 200` can still be folded.
+    pub(super) fn with_known_value_of(explicit: FeType, value: &FeType) -> FeType {
+        match (explicit, value.actual_type()) {
+            (FeType::Bool(None), FeType::Bool(known)) => return FeType::Bool(*known),
+            (
+                FeType::Number(Some(NumberDetails::Integer(size, None))),
+                FeType::Number(Some(NumberDetails::Integer(_, known))),
+            ) => {
+                return FeType::Number(Some(NumberDetails::Integer(size, *known)));
+            }
+            (
+                FeType::Number(Some(NumberDetails::Decimal(size, None))),
+                FeType::Number(Some(NumberDetails::Decimal(_, known))),
+            ) => {
+                return FeType::Number(Some(NumberDetails::Decimal(size, *known)));
+            }
+            (
+                FeType::Number(Some(NumberDetails::Decimal(size, None))),
+                FeType::Number(Some(NumberDetails::Integer(None, known))),
+            ) => {
+                let known = known.map(|value| value as f64);
+                return FeType::Number(Some(NumberDetails::Decimal(size, known)));
+            }
+            (explicit, _) => return explicit,
+        }
+    }
+
+    /// The number type `lhs` and `rhs` are both used as, without their values.
+    ///
+    /// `None` when they're of different kinds or sizes, like an `Int` and a decimal,
+    /// or a `U8` and an `I64`. Literals take the size of the other side.
+    pub(super) fn common_number_type(
+        lhs: &Option<NumberDetails>,
+        rhs: &Option<NumberDetails>,
+    ) -> Option<Option<NumberDetails>> {
+        fn common_size<S: Copy + PartialEq>(lhs: &Option<S>, rhs: &Option<S>) -> Option<Option<S>> {
+            match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) if lhs != rhs => return None,
+                _ => return Some(lhs.or(*rhs)),
+            }
+        }
+
+        match (lhs, rhs) {
+            (Some(NumberDetails::Integer(lhs, _)), Some(NumberDetails::Integer(rhs, _))) => {
+                return Some(Some(NumberDetails::Integer(common_size(lhs, rhs)?, None)));
+            }
+            (Some(NumberDetails::Decimal(lhs, _)), Some(NumberDetails::Decimal(rhs, _))) => {
+                return Some(Some(NumberDetails::Decimal(common_size(lhs, rhs)?, None)));
+            }
+            (None, _) | (_, None) => return Some(None),
+            _ => return None,
+        }
+    }
+
+    /// The number type of `lhs <op> rhs` for arithmetic operators, with its value
+    /// when both operands are known.
+    ///
+    /// `Int` division truncates towards zero, and the remainder takes the sign of `lhs`.
+    /// Decimals never overflow or divide by zero, they become infinite or NaN at runtime.
+    pub(super) fn fold_arithmetic(
+        op: &BinaryOp,
+        lhs: &Option<NumberDetails>,
+        rhs: &Option<NumberDetails>,
+    ) -> Result<Option<NumberDetails>, ConstEvalError> {
+        let Some(common) = Self::common_number_type(lhs, rhs) else {
+            return Err(ConstEvalError::IncompatibleOperands);
+        };
+
+        match (common, lhs, rhs) {
+            (
+                Some(NumberDetails::Integer(size, _)),
+                Some(NumberDetails::Integer(_, lhs)),
+                Some(NumberDetails::Integer(_, rhs)),
+            ) => {
+                if matches!(op, BinaryOp::Divide(_) | BinaryOp::Modulo(_)) && *rhs == Some(0) {
+                    return Err(ConstEvalError::DivisionByZero);
+                }
+
+                let Some((lhs, rhs)) = lhs.zip(*rhs) else {
+                    return Ok(Some(NumberDetails::Integer(size, None)));
+                };
+
+                let value = match op {
+                    BinaryOp::Add(_) => lhs.checked_add(rhs),
+                    BinaryOp::Subtract(_) => lhs.checked_sub(rhs),
+                    BinaryOp::Multiply(_) => lhs.checked_mul(rhs),
+                    BinaryOp::Divide(_) => lhs.checked_div(rhs),
+                    BinaryOp::Modulo(_) => lhs.checked_rem(rhs),
+                    _ => return Ok(Some(NumberDetails::Integer(size, None))),
+                };
+
+                let value = Self::check_integer_overflow(size, value)?;
+
+                return Ok(Some(NumberDetails::Integer(size, Some(value))));
+            }
+            (
+                Some(NumberDetails::Decimal(size, _)),
+                Some(NumberDetails::Decimal(_, lhs)),
+                Some(NumberDetails::Decimal(_, rhs)),
+            ) => {
+                let value = lhs
+                    .zip(*rhs)
+                    .and_then(|(lhs, rhs)| match size {
+                        // Folded in the precision it's computed in at runtime
+                        Some(DecimalSize::F32) => {
+                            return Self::fold_decimal(op, lhs as f32, rhs as f32).map(f64::from);
+                        }
+                        _ => return Self::fold_decimal(op, lhs, rhs),
+                    })
+                    .filter(|value| size.is_none_or(|size| size.contains(*value)));
+
+                return Ok(Some(NumberDetails::Decimal(size, value)));
+            }
+            (common, _, _) => return Ok(common),
+        }
+    }
+
+    fn fold_decimal<F>(op: &BinaryOp, lhs: F, rhs: F) -> Option<F>
+    where
+        F: Add<Output = F> + Sub<Output = F> + Mul<Output = F> + Div<Output = F> + Rem<Output = F>,
+    {
+        match op {
+            BinaryOp::Add(_) => return Some(lhs + rhs),
+            BinaryOp::Subtract(_) => return Some(lhs - rhs),
+            BinaryOp::Multiply(_) => return Some(lhs * rhs),
+            BinaryOp::Divide(_) => return Some(lhs / rhs),
+            BinaryOp::Modulo(_) => return Some(lhs % rhs),
+            _ => return None,
+        }
+    }
+
+    /// The number type of `-value`, with its value when it's known.
+    pub(super) fn fold_negate(
+        value: &Option<NumberDetails>,
+    ) -> Result<Option<NumberDetails>, ConstEvalError> {
+        match value {
+            Some(NumberDetails::Integer(size, Some(value))) => {
+                let value = Self::check_integer_overflow(*size, value.checked_neg())?;
+
+                return Ok(Some(NumberDetails::Integer(*size, Some(value))));
+            }
+            Some(NumberDetails::Decimal(size, value)) => {
+                return Ok(Some(NumberDetails::Decimal(
+                    *size,
+                    value.map(|value| -value),
+                )));
+            }
+            value => return Ok(value.clone()),
+        }
+    }
+
+    /// The result of `lhs <op> rhs` for `<`, `<=`, `>` and `>=`, if both are known.
+    ///
+    /// `None` when the numbers are of different kinds or sizes.
+    pub(super) fn fold_comparison(
+        op: &BinaryOp,
+        lhs: &Option<NumberDetails>,
+        rhs: &Option<NumberDetails>,
+    ) -> Option<Option<bool>> {
+        Self::common_number_type(lhs, rhs)?;
+
+        let ordering = match (lhs, rhs) {
+            (Some(NumberDetails::Integer(_, lhs)), Some(NumberDetails::Integer(_, rhs))) => {
+                lhs.zip(*rhs).map(|(lhs, rhs)| lhs.partial_cmp(&rhs))
+            }
+            (Some(NumberDetails::Decimal(_, lhs)), Some(NumberDetails::Decimal(_, rhs))) => {
+                lhs.zip(*rhs).map(|(lhs, rhs)| lhs.partial_cmp(&rhs))
+            }
+            _ => None,
+        };
+
+        let Some(ordering) = ordering else {
+            return Some(None);
+        };
+
+        // Comparisons with NaN are always false
+        let Some(ordering) = ordering else {
+            return Some(Some(false));
+        };
+
+        match op {
+            BinaryOp::Less(_) => return Some(Some(ordering.is_lt())),
+            BinaryOp::LessEq(_) => return Some(Some(ordering.is_le())),
+            BinaryOp::Greater(_) => return Some(Some(ordering.is_gt())),
+            BinaryOp::GreaterEq(_) => return Some(Some(ordering.is_ge())),
+            _ => return Some(None),
+        }
+    }
+
+    /// Whether `lhs == rhs`, if both are known at compile time.
+    ///
+    /// `None` when the types can't be compared: numbers only compare to numbers of
+    /// the same kind and size, instances to instances of the same struct and enum
+    /// values to values of the same enum.
+    pub(super) fn known_equality(lhs: &FeType, rhs: &FeType) -> Option<Option<bool>> {
+        match (lhs, rhs) {
+            (FeType::Number(lhs), FeType::Number(rhs)) => {
+                Self::common_number_type(lhs, rhs)?;
+
+                match (lhs, rhs) {
+                    (
+                        Some(NumberDetails::Integer(_, lhs)),
+                        Some(NumberDetails::Integer(_, rhs)),
+                    ) => {
+                        return Some(lhs.zip(*rhs).map(|(lhs, rhs)| lhs == rhs));
+                    }
+                    (
+                        Some(NumberDetails::Decimal(_, lhs)),
+                        Some(NumberDetails::Decimal(_, rhs)),
+                    ) => {
+                        return Some(lhs.zip(*rhs).map(|(lhs, rhs)| lhs == rhs));
+                    }
+                    _ => return Some(None),
+                }
+            }
+
+            (FeType::Bool(lhs), FeType::Bool(rhs)) => {
+                return Some(lhs.zip(*rhs).map(|(lhs, rhs)| lhs == rhs));
+            }
+
+            (FeType::String(_), FeType::String(_)) => return Some(None),
+
+            (FeType::Instance(lhs), FeType::Instance(rhs)) if lhs.name == rhs.name => {
+                return Some(None);
+            }
+
+            (FeType::EnumInstance(lhs), FeType::EnumInstance(rhs)) if lhs.name == rhs.name => {
+                return Some(None);
+            }
+
+            (FeType::EmptyOptional, FeType::EmptyOptional) => return Some(Some(true)),
+            (FeType::EmptyOptional, FeType::Optional(_))
+            | (FeType::Optional(_), FeType::EmptyOptional) => return Some(None),
+
+            // A value is compared to an optional as if it was wrapped in a `Some`
+            (FeType::Optional(lhs), FeType::Optional(rhs)) => {
+                Self::known_equality(lhs.actual_type(), rhs.actual_type())?;
+                return Some(None);
+            }
+            (FeType::Optional(lhs), rhs) | (rhs, FeType::Optional(lhs)) => {
+                Self::known_equality(lhs.actual_type(), rhs)?;
+                return Some(None);
+            }
+
+            _ => return None,
+        }
+    }
+
+    /// `value` if it was computed without overflowing `size`. Integers without a
+    /// size are computed as an `Int`.
+    fn check_integer_overflow(
+        size: Option<IntegerSize>,
+        value: Option<i128>,
+    ) -> Result<i128, ConstEvalError> {
+        let size = size.unwrap_or(IntegerSize::I64);
+
+        match value {
+            Some(value) if size.contains(value) => return Ok(value),
+            _ => return Err(ConstEvalError::Overflow),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(token_type: TokenType, lexeme: &str) -> Arc<Token> {
+        return Arc::new(Token {
+            token_type,
+            lexeme: lexeme.into(),
+            span: Span::zero(),
+        });
+    }
+
+    fn int(size: Option<IntegerSize>, value: i128) -> Option<NumberDetails> {
+        return Some(NumberDetails::Integer(size, Some(value)));
+    }
+
+    #[test]
+    fn test_fold_arithmetic() {
+        let add = BinaryOp::Add(op(TokenType::Plus, "+"));
+        let divide = BinaryOp::Divide(op(TokenType::Slash, "/"));
+
+        assert_eq!(
+            FeTypeResolver::fold_arithmetic(&add, &int(None, 2), &int(None, 3)),
+            Ok(int(None, 5))
+        );
+        assert_eq!(
+            FeTypeResolver::fold_arithmetic(
+                &add,
+                &int(Some(IntegerSize::U8), 200),
+                &int(None, 100)
+            ),
+            Err(ConstEvalError::Overflow)
+        );
+        assert_eq!(
+            FeTypeResolver::fold_arithmetic(&add, &int(None, i64::MAX.into()), &int(None, 1)),
+            Err(ConstEvalError::Overflow)
+        );
+        assert_eq!(
+            FeTypeResolver::fold_arithmetic(
+                &divide,
+                &Some(NumberDetails::Integer(None, None)),
+                &int(None, 0)
+            ),
+            Err(ConstEvalError::DivisionByZero)
+        );
+        assert_eq!(
+            FeTypeResolver::fold_arithmetic(
+                &add,
+                &int(Some(IntegerSize::U8), 1),
+                &int(Some(IntegerSize::I8), 1)
+            ),
+            Err(ConstEvalError::IncompatibleOperands)
+        );
+    }
+
+    #[test]
+    fn test_fold_comparison() {
+        let less = BinaryOp::Less(op(TokenType::Less, "<"));
+
+        assert_eq!(
+            FeTypeResolver::fold_comparison(&less, &int(None, 2), &int(None, 3)),
+            Some(Some(true))
+        );
+        assert_eq!(
+            FeTypeResolver::fold_comparison(
+                &less,
+                &int(None, 2),
+                &Some(NumberDetails::Integer(None, None))
+            ),
+            Some(None)
+        );
+        assert_eq!(
+            FeTypeResolver::fold_comparison(
+                &less,
+                &int(None, 2),
+                &Some(NumberDetails::Decimal(None, Some(3.0)))
+            ),
+            None
+        );
+    }
+}
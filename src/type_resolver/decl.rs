@@ -5,11 +5,278 @@ impl DeclVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         &mut self,
         shared_decl: Arc<Mutex<FnDecl<Option<FeType>>>>,
     ) -> Result<bool> {
+        let (changed, callable) = self.resolve_fn_signature(&shared_decl)?;
+
+        let Some(callable) = callable else {
+            return Ok(changed);
+        };
+
+        let decl = &*shared_decl.try_lock().unwrap();
+
+        self.scope.try_lock().unwrap().insert(
+            decl.name.lexeme.clone(),
+            ScopedType {
+                is_pub: matches!(decl.decl_mod, Some(DeclMod::Pub(_))),
+                typ: FeType::Callable(callable),
+                decl: Some(ScopedDecl {
+                    file: self.current_file.clone(),
+                    token: match &decl.fn_mod {
+                        Some(FnMod::Pure(token))
+                        | Some(FnMod::Safe(token))
+                        | Some(FnMod::Norm(token))
+                        | Some(FnMod::Risk(token)) => token.clone(),
+                        None => decl.fn_token.clone(),
+                    },
+                    is_late: false,
+                }),
+            },
+        );
+
+        return Ok(changed);
+    }
+
+    fn visit_struct_decl(
+        &mut self,
+        shared_decl: Arc<Mutex<StructDecl<Option<FeType>>>>,
+    ) -> Result<bool> {
+        let decl = &mut *shared_decl.try_lock().unwrap();
+
+        if decl.is_resolved() {
+            return Ok(false);
+        }
+
+        let mut changed = false;
+
+        let generics = decl
+            .generics
+            .as_ref()
+            .map(DeclGenerics::names)
+            .unwrap_or_default();
+
+        self.scope.try_lock().unwrap().begin_scope(None);
+        self.insert_generics(&generics);
+
+        self.current_type_decl = Some(decl.name.lexeme.clone());
+
+        let mut fields = vec![];
+        let mut all_done = true;
+        for field in &mut decl.fields {
+            changed |= field.static_type_ref.accept(self)?;
+
+            if let Some(resolved) = &field.static_type_ref.resolved_type {
+                fields.push(FeStructField {
+                    is_pub: matches!(field.field_mod, Some(StructFieldMod::Pub(_))),
+                    name: field.name.lexeme.clone(),
+                    typ: resolved.clone(),
+                });
+            } else {
+                all_done = false;
+            }
+        }
+
+        self.current_type_decl = None;
+        self.scope.try_lock().unwrap().end_scope();
+
+        if all_done {
+            changed = true;
+            self.scope.try_lock().unwrap().insert(
+                decl.name.lexeme.clone(),
+                ScopedType {
+                    is_pub: matches!(decl.decl_mod, Some(DeclMod::Pub(_))),
+                    typ: FeType::Struct(FeStruct {
+                        special: None,
+                        name: decl.name.lexeme.clone(),
+                        generics,
+                        fields,
+                        methods: FeMethods::default(),
+                    }),
+                    decl: Some(ScopedDecl {
+                        file: self.current_file.clone(),
+                        token: decl.struct_token.clone(),
+                        is_late: false,
+                    }),
+                },
+            );
+        }
+
+        return Ok(changed);
+    }
+
+    fn visit_enum_decl(
+        &mut self,
+        shared_decl: Arc<Mutex<EnumDecl<Option<FeType>>>>,
+    ) -> Result<bool> {
+        let decl = &mut *shared_decl.try_lock().unwrap();
+
+        if decl.is_resolved() {
+            return Ok(false);
+        }
+
+        let mut changed = false;
+
+        self.current_type_decl = Some(decl.name.lexeme.clone());
+
+        let mut variants = vec![];
+        let mut all_done = true;
+        for variant in &mut decl.variants {
+            let fields = match &mut variant.fields {
+                EnumDeclVariantFields::Unit => Some(FeEnumVariantFields::Unit),
+
+                EnumDeclVariantFields::Tuple { fields, .. } => {
+                    let mut types = vec![];
+
+                    for field in fields.iter_mut() {
+                        changed |= field.static_type_ref.accept(self)?;
+
+                        if let Some(resolved) = &field.static_type_ref.resolved_type {
+                            types.push(resolved.clone());
+                        }
+                    }
+
+                    let is_done = types.len() == fields.len();
+                    is_done.then_some(FeEnumVariantFields::Tuple(types))
+                }
+
+                EnumDeclVariantFields::Struct { fields, .. } => {
+                    let mut struct_fields = vec![];
+
+                    for field in fields.iter_mut() {
+                        changed |= field.static_type_ref.accept(self)?;
+
+                        if let Some(resolved) = &field.static_type_ref.resolved_type {
+                            struct_fields.push(FeStructField {
+                                is_pub: true,
+                                name: field.name.lexeme.clone(),
+                                typ: resolved.clone(),
+                            });
+                        }
+                    }
+
+                    let is_done = struct_fields.len() == fields.len();
+                    is_done.then_some(FeEnumVariantFields::Struct(struct_fields))
+                }
+            };
+
+            let Some(fields) = fields else {
+                all_done = false;
+                continue;
+            };
+
+            variants.push(FeEnumVariant {
+                name: variant.name.lexeme.clone(),
+                fields,
+            });
+        }
+
+        self.current_type_decl = None;
+
+        if !all_done {
+            return Ok(changed);
+        }
+
+        decl.has_resolved_variants = true;
+
+        let mut seen = HashSet::new();
+        for variant in &decl.variants {
+            if !seen.insert(variant.name.lexeme.clone()) {
+                self.report(TypeError::DuplicateVariant {
+                    name: variant.name.lexeme.clone(),
+                    enum_name: decl.name.lexeme.clone(),
+                    span: variant.name.span.clone(),
+                });
+            }
+        }
+
+        self.scope.try_lock().unwrap().insert(
+            decl.name.lexeme.clone(),
+            ScopedType {
+                is_pub: matches!(decl.decl_mod, Some(DeclMod::Pub(_))),
+                typ: FeType::Enum(FeEnum {
+                    name: decl.name.lexeme.clone(),
+                    variants,
+                }),
+                decl: Some(ScopedDecl {
+                    file: self.current_file.clone(),
+                    token: decl.enum_token.clone(),
+                    is_late: false,
+                }),
+            },
+        );
+
+        return Ok(true);
+    }
+
+    fn visit_impl_decl(
+        &mut self,
+        shared_decl: Arc<Mutex<ImplDecl<Option<FeType>>>>,
+    ) -> Result<bool> {
+        let decl = &mut *shared_decl.try_lock().unwrap();
+
+        if decl.is_signature_resolved() {
+            return Ok(false);
+        }
+
+        let mut changed = false;
+
+        if !decl.target.is_resolved() {
+            changed |= decl.target.accept(self)?;
+            self.check_impl_target(&mut decl.target);
+        }
+
+        let (generics, target) = match &decl.target.resolved_type {
+            Some(FeType::Struct(target)) => (target.generics.clone(), Some(target.clone())),
+            Some(_) => (vec![], None),
+            None => return Ok(changed),
+        };
+
+        // The struct's type parameters are in scope for all of its methods
+        self.scope.try_lock().unwrap().begin_scope(None);
+        self.insert_generics(&generics);
+
+        for method in &decl.methods {
+            let (method_changed, callable) = self.resolve_fn_signature(method)?;
+            changed |= method_changed;
+
+            let (Some(target), Some(mut callable)) = (&target, callable) else {
+                continue;
+            };
+
+            callable.generics = generics.iter().cloned().chain(callable.generics).collect();
+
+            let method = &*method.try_lock().unwrap();
+
+            let is_new = target.methods.insert(FeMethod {
+                receiver: method.self_param.as_ref().map(Self::receiver_of),
+                callable,
+            });
+
+            if !is_new {
+                self.report(TypeError::DuplicateMethod {
+                    name: method.name.lexeme.clone(),
+                    struct_name: target.name.clone(),
+                    span: method.name.span.clone(),
+                });
+            }
+        }
+
+        self.scope.try_lock().unwrap().end_scope();
+
+        return Ok(changed);
+    }
+}
+
+impl FeTypeResolver {
+    /// Resolves the types of a function's params and return type. Once they're all
+    /// resolved, returns the function's type.
+    fn resolve_fn_signature(
+        &mut self,
+        shared_decl: &Arc<Mutex<FnDecl<Option<FeType>>>>,
+    ) -> Result<(bool, Option<Callable>)> {
         {
             let decl = &mut *shared_decl.try_lock().unwrap();
 
             if decl.is_signature_resolved() {
-                return Ok(false);
+                return Ok((false, None));
             }
         }
 
@@ -18,6 +285,19 @@ impl DeclVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let mut params = vec![];
         let mut all_resolved = true;
 
+        let generics = {
+            let decl = &*shared_decl.try_lock().unwrap();
+
+            decl.generics
+                .as_ref()
+                .map(DeclGenerics::names)
+                .unwrap_or_default()
+        };
+
+        // The signature can refer to the type parameters, but nothing outside it can
+        self.scope.try_lock().unwrap().begin_scope(None);
+        self.insert_generics(&generics);
+
         {
             let decl = &mut *shared_decl.try_lock().unwrap();
 
@@ -58,74 +338,23 @@ impl DeclVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             }
         }
 
-        if all_resolved {
-            let decl = &mut *shared_decl.try_lock().unwrap();
+        self.scope.try_lock().unwrap().end_scope();
 
-            changed = true;
-            self.scope.try_lock().unwrap().insert(
-                decl.name.lexeme.clone(),
-                ScopedType {
-                    is_pub: matches!(decl.decl_mod, Some(DeclMod::Pub(_))),
-                    typ: FeType::Callable(Callable {
-                        special: None,
-                        name: decl.name.lexeme.clone(),
-                        params,
-                        return_type: fn_return_type,
-                    }),
-                },
-            );
-
-            decl.has_resolved_signature = true;
+        if !all_resolved {
+            return Ok((changed, None));
         }
 
-        return Ok(changed);
-    }
-
-    fn visit_struct_decl(
-        &mut self,
-        shared_decl: Arc<Mutex<StructDecl<Option<FeType>>>>,
-    ) -> Result<bool> {
         let decl = &mut *shared_decl.try_lock().unwrap();
+        decl.has_resolved_signature = true;
 
-        if decl.is_resolved() {
-            return Ok(false);
-        }
-
-        let mut changed = false;
-
-        // TODO: Generics
-
-        let mut fields = vec![];
-        let mut all_done = true;
-        for field in &mut decl.fields {
-            changed |= field.static_type_ref.accept(self)?;
-
-            if let Some(resolved) = &field.static_type_ref.resolved_type {
-                fields.push(FeStructField {
-                    is_pub: matches!(field.field_mod, Some(StructFieldMod::Pub(_))),
-                    name: field.name.lexeme.clone(),
-                    typ: resolved.clone(),
-                });
-            } else {
-                all_done = false;
-            }
-        }
-
-        if all_done {
-            changed = true;
-            self.scope.try_lock().unwrap().insert(
-                decl.name.lexeme.clone(),
-                ScopedType {
-                    is_pub: matches!(decl.decl_mod, Some(DeclMod::Pub(_))),
-                    typ: FeType::Struct(FeStruct {
-                        special: None,
-                        name: decl.name.lexeme.clone(),
-                        fields,
-                    }),
-                },
-            );
-        }
+        let callable = Callable {
+            special: None,
+            name: decl.name.lexeme.clone(),
+            generics,
+            params,
+            return_type: fn_return_type,
+        };
 
-        return Ok(changed);
+        return Ok((true, Some(callable)));
     }
 }
@@ -0,0 +1,373 @@
+use crate::diagnostics::ErrorCode;
+use crate::r#type::FeType;
+use crate::token::Span;
+
+use std::sync::Arc;
+
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum TypeError {
+    #[error("Cannot find '{name}' in this scope")]
+    UnknownName { name: Arc<str>, span: Span },
+
+    #[error("Cannot find type '{name}' in this scope")]
+    UnknownType { name: Arc<str>, span: Span },
+
+    #[error("Recursive type '{name}' needs indirection")]
+    RecursiveType { name: Arc<str>, span: Span },
+
+    #[error("Unresolved import '{name}'")]
+    UnresolvedImport { name: Arc<str>, span: Span },
+
+    #[error("'{name}' is private")]
+    PrivateImport { name: Arc<str>, span: Span },
+
+    #[error("Expected a package, found {found}")]
+    NotAPackage { found: FeType, span: Span },
+
+    #[error("Mismatched types. Expected {expected}, found {found}")]
+    MismatchedTypes {
+        expected: FeType,
+        found: FeType,
+        span: Span,
+    },
+
+    #[error("{value} is out of range for {expected}")]
+    NumberOutOfRange {
+        value: Arc<str>,
+        expected: FeType,
+        span: Span,
+    },
+
+    #[error("Expected a value, but this expression has no type")]
+    NoValue { span: Span },
+
+    #[error("Expected a condition of type Bool, found {found}")]
+    NonBoolCondition { found: FeType, span: Span },
+
+    #[error("Expected a function, found {found}")]
+    NotCallable { found: FeType, span: Span },
+
+    #[error("Too many arguments. Expected {expected}, found {found}")]
+    TooManyArgs {
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+
+    #[error("Missing arguments. Expected {expected}, found {found}")]
+    MissingArgs {
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+
+    #[error("Cannot apply '{op}' to {operand}")]
+    InvalidUnaryOperand {
+        op: Arc<str>,
+        operand: FeType,
+        span: Span,
+    },
+
+    #[error("Cannot apply '{op}' to {lhs} and {rhs}")]
+    InvalidBinaryOperands {
+        op: Arc<str>,
+        lhs: FeType,
+        rhs: FeType,
+        span: Span,
+    },
+
+    #[error("Cannot format {found} in a string")]
+    NotFormattable { found: FeType, span: Span },
+
+    #[error("Attempt to divide by zero")]
+    DivisionByZero { span: Span },
+
+    #[error("This arithmetic operation will overflow")]
+    ArithmeticOverflow { span: Span },
+
+    #[error("Cannot iterate over {found}, expected a range")]
+    NotIterable { found: FeType, span: Span },
+
+    #[error("Cannot construct {found}, expected a struct")]
+    NotConstructable { found: FeType, span: Span },
+
+    #[error("No field '{name}' on struct {struct_name}")]
+    UnknownField {
+        name: Arc<str>,
+        struct_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("Field '{name}' is specified more than once")]
+    DuplicateField { name: Arc<str>, span: Span },
+
+    #[error("Missing fields for struct {struct_name}: {}", fields.join(", "))]
+    MissingFields {
+        struct_name: Arc<str>,
+        fields: Vec<Arc<str>>,
+        span: Span,
+    },
+
+    #[error("Cannot access property '{name}' on {found}")]
+    NotAnInstance {
+        name: Arc<str>,
+        found: FeType,
+        span: Span,
+    },
+
+    #[error("No property '{name}' on {instance_name}")]
+    UnknownProperty {
+        name: Arc<str>,
+        instance_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("Cannot assign to {found}")]
+    NotAssignable { found: FeType, span: Span },
+
+    #[error("Cannot assign twice to a const variable")]
+    AssignToConst { span: Span },
+
+    #[error("Variable '{name}' is used before it's assigned")]
+    UnassignedVariable { name: Arc<str>, span: Span },
+
+    #[error("Variable '{name}' needs a type or a value")]
+    MissingTypeAnnotation { name: Arc<str>, span: Span },
+
+    #[error("Cannot infer the type of 'none'")]
+    UnknownNoneType { span: Span },
+
+    #[error("Wrong number of type arguments for {name}: expected {expected}, found {found}")]
+    WrongGenericArgCount {
+        name: Arc<str>,
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+
+    #[error("Cannot infer the type of '{name}'")]
+    CannotInferGeneric { name: Arc<str>, span: Span },
+
+    #[error("Cannot add methods to {found}, expected a struct")]
+    ImplTargetNotStruct { found: FeType, span: Span },
+
+    #[error("Methods for {name} must be declared in the same file as it")]
+    ForeignImpl { name: Arc<str>, span: Span },
+
+    #[error("Duplicate method '{name}' on {struct_name}")]
+    DuplicateMethod {
+        name: Arc<str>,
+        struct_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("No function '{name}' on struct {struct_name}")]
+    UnknownMethod {
+        name: Arc<str>,
+        struct_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("'{name}' is an associated function of {struct_name}, not a method")]
+    NotAMethod {
+        name: Arc<str>,
+        struct_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("Cannot call '{name}' on a const value, it takes '&mut self'")]
+    MutMethodOnConst { name: Arc<str>, span: Span },
+
+    #[error("Cannot assign through a shared reference")]
+    AssignThroughSharedRef { span: Span },
+
+    #[error("Duplicate variant '{name}' on enum {enum_name}")]
+    DuplicateVariant {
+        name: Arc<str>,
+        enum_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("No variant '{name}' on enum {enum_name}")]
+    UnknownVariant {
+        name: Arc<str>,
+        enum_name: Arc<str>,
+        span: Span,
+    },
+
+    #[error("Non-exhaustive match. Pattern '{missing}' isn't covered")]
+    NonExhaustiveMatch { missing: Arc<str>, span: Span },
+
+    #[error("Wrong number of fields in pattern for {name}: expected {expected}, found {found}")]
+    PatternFieldCount {
+        name: Arc<str>,
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+
+    #[error("Expected a pattern, found {found}")]
+    NotAPattern { found: FeType, span: Span },
+
+    #[error("Missing value. Other arms of this match give {expected}")]
+    MissingArmValue { expected: FeType, span: Span },
+
+    #[error("Return statements are only allowed inside functions")]
+    ReturnOutsideFn { span: Span },
+
+    #[error("Missing return value. Expected {expected}")]
+    MissingReturnValue { expected: FeType, span: Span },
+
+    #[error("Unexpected return value. This function doesn't return anything")]
+    UnexpectedReturnValue { span: Span },
+
+    #[error("'break' is only allowed inside loops")]
+    BreakOutsideLoop { span: Span },
+
+    #[error("'then' is only allowed inside 'if' and 'match' blocks")]
+    ThenOutsideIf { span: Span },
+
+    #[error("Cannot find label '{label}'")]
+    UnknownLabel { label: Arc<str>, span: Span },
+
+    #[error("Cannot 'break' out of a labeled loop without its label")]
+    UnlabeledBreak { span: Span },
+
+    #[error("Cannot find a block for this 'then' to give a value to")]
+    UnlabeledThen { span: Span },
+
+    #[error("'{keyword}' branches of while expressions are not supported yet")]
+    UnsupportedWhileBranch { keyword: Arc<str>, span: Span },
+
+    #[error("Cannot break with a value from a loop statement")]
+    BreakWithValue { span: Span },
+
+    #[error("Cannot 'then' a value from an if statement")]
+    ThenWithValue { span: Span },
+}
+
+impl TypeError {
+    pub fn span(&self) -> &Span {
+        match self {
+            Self::UnknownName { span, .. }
+            | Self::UnknownType { span, .. }
+            | Self::RecursiveType { span, .. }
+            | Self::UnresolvedImport { span, .. }
+            | Self::PrivateImport { span, .. }
+            | Self::NotAPackage { span, .. }
+            | Self::MismatchedTypes { span, .. }
+            | Self::NumberOutOfRange { span, .. }
+            | Self::NoValue { span }
+            | Self::NonBoolCondition { span, .. }
+            | Self::NotCallable { span, .. }
+            | Self::TooManyArgs { span, .. }
+            | Self::MissingArgs { span, .. }
+            | Self::InvalidUnaryOperand { span, .. }
+            | Self::InvalidBinaryOperands { span, .. }
+            | Self::UnsupportedWhileBranch { span, .. }
+            | Self::NotFormattable { span, .. }
+            | Self::DivisionByZero { span }
+            | Self::ArithmeticOverflow { span }
+            | Self::NotIterable { span, .. }
+            | Self::NotConstructable { span, .. }
+            | Self::UnknownField { span, .. }
+            | Self::DuplicateField { span, .. }
+            | Self::MissingFields { span, .. }
+            | Self::NotAnInstance { span, .. }
+            | Self::UnknownProperty { span, .. }
+            | Self::NotAssignable { span, .. }
+            | Self::AssignToConst { span }
+            | Self::UnassignedVariable { span, .. }
+            | Self::MissingTypeAnnotation { span, .. }
+            | Self::UnknownNoneType { span }
+            | Self::WrongGenericArgCount { span, .. }
+            | Self::CannotInferGeneric { span, .. }
+            | Self::ImplTargetNotStruct { span, .. }
+            | Self::ForeignImpl { span, .. }
+            | Self::DuplicateMethod { span, .. }
+            | Self::UnknownMethod { span, .. }
+            | Self::NotAMethod { span, .. }
+            | Self::MutMethodOnConst { span, .. }
+            | Self::AssignThroughSharedRef { span }
+            | Self::DuplicateVariant { span, .. }
+            | Self::UnknownVariant { span, .. }
+            | Self::NonExhaustiveMatch { span, .. }
+            | Self::PatternFieldCount { span, .. }
+            | Self::NotAPattern { span, .. }
+            | Self::MissingArmValue { span, .. }
+            | Self::ReturnOutsideFn { span }
+            | Self::MissingReturnValue { span, .. }
+            | Self::UnexpectedReturnValue { span }
+            | Self::BreakOutsideLoop { span }
+            | Self::ThenOutsideIf { span }
+            | Self::UnknownLabel { span, .. }
+            | Self::UnlabeledBreak { span }
+            | Self::UnlabeledThen { span }
+            | Self::BreakWithValue { span }
+            | Self::ThenWithValue { span } => return span,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::UnknownName { .. } => return ErrorCode::UNKNOWN_NAME,
+            Self::UnknownType { .. } => return ErrorCode::UNKNOWN_TYPE,
+            Self::RecursiveType { .. } => return ErrorCode::RECURSIVE_TYPE,
+            Self::UnresolvedImport { .. } => return ErrorCode::UNRESOLVED_IMPORT,
+            Self::PrivateImport { .. } => return ErrorCode::PRIVATE_IMPORT,
+            Self::NotAPackage { .. } => return ErrorCode::NOT_A_PACKAGE,
+            Self::MismatchedTypes { .. } => return ErrorCode::MISMATCHED_TYPES,
+            Self::NumberOutOfRange { .. } => return ErrorCode::NUMBER_OUT_OF_RANGE,
+            Self::NoValue { .. } => return ErrorCode::NO_VALUE,
+            Self::NonBoolCondition { .. } => return ErrorCode::NON_BOOL_CONDITION,
+            Self::NotCallable { .. } => return ErrorCode::NOT_CALLABLE,
+            Self::TooManyArgs { .. } => return ErrorCode::TOO_MANY_ARGS,
+            Self::MissingArgs { .. } => return ErrorCode::MISSING_ARGS,
+            Self::InvalidUnaryOperand { .. } => return ErrorCode::INVALID_UNARY_OPERAND,
+            Self::InvalidBinaryOperands { .. } => return ErrorCode::INVALID_BINARY_OPERANDS,
+            Self::UnsupportedWhileBranch { .. } => return ErrorCode::UNSUPPORTED,
+            Self::NotFormattable { .. } => return ErrorCode::NOT_FORMATTABLE,
+            Self::DivisionByZero { .. } => return ErrorCode::DIVISION_BY_ZERO,
+            Self::ArithmeticOverflow { .. } => return ErrorCode::ARITHMETIC_OVERFLOW,
+            Self::NotIterable { .. } => return ErrorCode::NOT_ITERABLE,
+            Self::NotConstructable { .. } => return ErrorCode::NOT_CONSTRUCTABLE,
+            Self::UnknownField { .. } => return ErrorCode::UNKNOWN_FIELD,
+            Self::DuplicateField { .. } => return ErrorCode::DUPLICATE_FIELD,
+            Self::MissingFields { .. } => return ErrorCode::MISSING_FIELDS,
+            Self::NotAnInstance { .. } => return ErrorCode::NOT_AN_INSTANCE,
+            Self::UnknownProperty { .. } => return ErrorCode::UNKNOWN_PROPERTY,
+            Self::NotAssignable { .. } => return ErrorCode::NOT_ASSIGNABLE,
+            Self::AssignToConst { .. } => return ErrorCode::ASSIGN_TO_CONST,
+            Self::UnassignedVariable { .. } => return ErrorCode::UNASSIGNED_VARIABLE,
+            Self::MissingTypeAnnotation { .. } => return ErrorCode::MISSING_TYPE_ANNOTATION,
+            Self::UnknownNoneType { .. } => return ErrorCode::UNKNOWN_NONE_TYPE,
+            Self::WrongGenericArgCount { .. } => return ErrorCode::WRONG_GENERIC_ARG_COUNT,
+            Self::CannotInferGeneric { .. } => return ErrorCode::CANNOT_INFER_GENERIC,
+            Self::ImplTargetNotStruct { .. } => return ErrorCode::IMPL_TARGET_NOT_STRUCT,
+            Self::ForeignImpl { .. } => return ErrorCode::FOREIGN_IMPL,
+            Self::DuplicateMethod { .. } => return ErrorCode::DUPLICATE_METHOD,
+            Self::UnknownMethod { .. } => return ErrorCode::UNKNOWN_METHOD,
+            Self::NotAMethod { .. } => return ErrorCode::NOT_A_METHOD,
+            Self::MutMethodOnConst { .. } => return ErrorCode::MUT_METHOD_ON_CONST,
+            Self::AssignThroughSharedRef { .. } => return ErrorCode::ASSIGN_THROUGH_SHARED_REF,
+            Self::DuplicateVariant { .. } => return ErrorCode::DUPLICATE_VARIANT,
+            Self::UnknownVariant { .. } => return ErrorCode::UNKNOWN_VARIANT,
+            Self::NonExhaustiveMatch { .. } => return ErrorCode::NON_EXHAUSTIVE_MATCH,
+            Self::PatternFieldCount { .. } => return ErrorCode::PATTERN_FIELD_COUNT,
+            Self::NotAPattern { .. } => return ErrorCode::NOT_A_PATTERN,
+            Self::MissingArmValue { .. } => return ErrorCode::MISSING_ARM_VALUE,
+            Self::ReturnOutsideFn { .. } => return ErrorCode::RETURN_OUTSIDE_FN,
+            Self::MissingReturnValue { .. } => return ErrorCode::MISSING_RETURN_VALUE,
+            Self::UnexpectedReturnValue { .. } => return ErrorCode::UNEXPECTED_RETURN_VALUE,
+            Self::BreakOutsideLoop { .. } => return ErrorCode::BREAK_OUTSIDE_LOOP,
+            Self::ThenOutsideIf { .. } => return ErrorCode::THEN_OUTSIDE_IF,
+            Self::UnknownLabel { .. } => return ErrorCode::UNKNOWN_LABEL,
+            Self::UnlabeledBreak { .. } => return ErrorCode::UNLABELED_BREAK,
+            Self::UnlabeledThen { .. } => return ErrorCode::UNLABELED_THEN,
+            Self::BreakWithValue { .. } => return ErrorCode::BREAK_WITH_VALUE,
+            Self::ThenWithValue { .. } => return ErrorCode::THEN_WITH_VALUE,
+        }
+    }
+}
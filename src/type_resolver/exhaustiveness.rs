@@ -0,0 +1,308 @@
+use super::*;
+
+/// A `match` arm's pattern, reduced to what matters for checking that the
+/// arms cover every value.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Pat {
+    /// Matches anything, like `_` or a binding
+    Wild,
+
+    /// Matches values made with the constructor, whose fields match the patterns
+    Ctor(Ctor, Vec<Pat>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Ctor {
+    Bool(bool),
+
+    /// The index of one of an enum's variants
+    Variant(usize),
+
+    /// A struct instance, which only has the one way of being made
+    Single,
+
+    /// A number or string, which can't cover every value of its type
+    Literal(Arc<str>),
+}
+
+impl FeTypeResolver {
+    /// A value of `typ` that none of the patterns match, written as a pattern.
+    pub(super) fn missing_pattern(pats: Vec<Pat>, typ: &FeType) -> Option<String> {
+        let rows = pats.into_iter().map(|pat| vec![pat]).collect::<Vec<_>>();
+
+        let missing = Self::uncovered(&rows, std::slice::from_ref(typ))?;
+
+        return Some(Self::display_pat(&missing[0], typ));
+    }
+
+    /// The fields of a value of `typ` made with `ctor`, in the order its `Pat` lists them.
+    ///
+    /// Variants keep the order they're declared in, struct fields are sorted by name.
+    pub(super) fn ctor_fields(typ: &FeType, ctor: &Ctor) -> Vec<(Arc<str>, FeType)> {
+        match (typ.actual_type(), ctor) {
+            (FeType::EnumInstance(fe_enum), Ctor::Variant(idx)) => {
+                match &fe_enum.variants[*idx].fields {
+                    FeEnumVariantFields::Unit => return vec![],
+                    FeEnumVariantFields::Tuple(types) => {
+                        return types
+                            .iter()
+                            .enumerate()
+                            .map(|(i, typ)| (i.to_string().into(), typ.clone()))
+                            .collect();
+                    }
+                    FeEnumVariantFields::Struct(fields) => {
+                        return fields
+                            .iter()
+                            .map(|field| (field.name.clone(), field.typ.clone()))
+                            .collect();
+                    }
+                }
+            }
+
+            (FeType::Instance(instance), Ctor::Single) => {
+                let mut fields = instance
+                    .fields
+                    .values()
+                    .map(|field| (field.name.clone(), field.typ.clone()))
+                    .collect::<Vec<_>>();
+
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                return fields;
+            }
+
+            _ => return vec![],
+        }
+    }
+
+    /// Every constructor of `typ`, or `None` when there are too many to list.
+    fn all_ctors(typ: &FeType) -> Option<Vec<Ctor>> {
+        match typ.actual_type() {
+            FeType::Bool(_) => return Some(vec![Ctor::Bool(true), Ctor::Bool(false)]),
+            FeType::EnumInstance(fe_enum) => {
+                return Some((0..fe_enum.variants.len()).map(Ctor::Variant).collect());
+            }
+            FeType::Instance(_) => return Some(vec![Ctor::Single]),
+            _ => return None,
+        }
+    }
+
+    /// Values, one for each of `types`, that no row of patterns matches.
+    ///
+    /// Looks at the first column: if its constructors cover the type, each is
+    /// checked with its fields as extra columns, otherwise only the rows that
+    /// match anything there can cover what the rest of the columns leave.
+    fn uncovered(rows: &[Vec<Pat>], types: &[FeType]) -> Option<Vec<Pat>> {
+        let Some((typ, rest_types)) = types.split_first() else {
+            if rows.is_empty() {
+                return Some(vec![]);
+            }
+
+            return None;
+        };
+
+        let mut used = vec![];
+        for row in rows {
+            if let Pat::Ctor(ctor, _) = &row[0] {
+                if !used.contains(ctor) {
+                    used.push(ctor.clone());
+                }
+            }
+        }
+
+        let all = Self::all_ctors(typ);
+
+        if let Some(all) = &all {
+            if all.iter().all(|ctor| used.contains(ctor)) {
+                for ctor in all {
+                    let fields = Self::ctor_fields(typ, ctor);
+
+                    let specialized = rows
+                        .iter()
+                        .filter_map(|row| match &row[0] {
+                            Pat::Wild => {
+                                let mut row_fields = vec![Pat::Wild; fields.len()];
+                                row_fields.extend(row[1..].iter().cloned());
+
+                                return Some(row_fields);
+                            }
+                            Pat::Ctor(row_ctor, row_fields) if row_ctor == ctor => {
+                                let mut row_fields = row_fields.clone();
+                                row_fields.extend(row[1..].iter().cloned());
+
+                                return Some(row_fields);
+                            }
+                            Pat::Ctor(..) => return None,
+                        })
+                        .collect::<Vec<_>>();
+
+                    let mut sub_types = fields
+                        .iter()
+                        .map(|(_, typ)| typ.clone())
+                        .collect::<Vec<_>>();
+                    sub_types.extend(rest_types.iter().cloned());
+
+                    if let Some(mut missing) = Self::uncovered(&specialized, &sub_types) {
+                        let rest = missing.split_off(fields.len());
+
+                        let mut result = vec![Pat::Ctor(ctor.clone(), missing)];
+                        result.extend(rest);
+
+                        return Some(result);
+                    }
+                }
+
+                return None;
+            }
+        }
+
+        let default = rows
+            .iter()
+            .filter(|row| row[0] == Pat::Wild)
+            .map(|row| row[1..].to_vec())
+            .collect::<Vec<_>>();
+
+        let rest = Self::uncovered(&default, rest_types)?;
+
+        // Name a constructor that isn't used, when there's one to name
+        let first = match all
+            .into_iter()
+            .flatten()
+            .find(|ctor| !used.is_empty() && !used.contains(ctor))
+        {
+            Some(ctor) => {
+                let fields = Self::ctor_fields(typ, &ctor);
+                Pat::Ctor(ctor, vec![Pat::Wild; fields.len()])
+            }
+            None => Pat::Wild,
+        };
+
+        let mut result = vec![first];
+        result.extend(rest);
+
+        return Some(result);
+    }
+
+    fn display_pat(pat: &Pat, typ: &FeType) -> String {
+        let Pat::Ctor(ctor, pats) = pat else {
+            return "_".to_string();
+        };
+
+        let fields = Self::ctor_fields(typ, ctor);
+
+        match (typ.actual_type(), ctor) {
+            (_, Ctor::Bool(value)) => return value.to_string(),
+            (_, Ctor::Literal(literal)) => return literal.to_string(),
+
+            (FeType::EnumInstance(fe_enum), Ctor::Variant(idx)) => {
+                let variant = &fe_enum.variants[*idx];
+                let name = format!("{}::{}", fe_enum.name, variant.name);
+
+                match &variant.fields {
+                    FeEnumVariantFields::Unit => return name,
+                    FeEnumVariantFields::Tuple(_) => {
+                        let pats = pats
+                            .iter()
+                            .zip(&fields)
+                            .map(|(pat, (_, typ))| Self::display_pat(pat, typ))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+
+                        return format!("{name}({pats})");
+                    }
+                    FeEnumVariantFields::Struct(_) => {
+                        return Self::display_struct_pat(&name, pats, &fields);
+                    }
+                }
+            }
+
+            (FeType::Instance(instance), Ctor::Single) => {
+                return Self::display_struct_pat(&instance.name, pats, &fields);
+            }
+
+            _ => return "_".to_string(),
+        }
+    }
+
+    /// `Name { field: pattern, .. }`, leaving out the fields that match anything.
+    fn display_struct_pat(name: &str, pats: &[Pat], fields: &[(Arc<str>, FeType)]) -> String {
+        let mut shown = pats
+            .iter()
+            .zip(fields)
+            .filter(|(pat, _)| **pat != Pat::Wild)
+            .map(|(pat, (name, typ))| format!("{name}: {}", Self::display_pat(pat, typ)))
+            .collect::<Vec<_>>();
+
+        if shown.len() < fields.len() {
+            shown.push("..".to_string());
+        }
+
+        return format!("{name} {{ {} }}", shown.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn light() -> FeType {
+        let variant = |name: &str, fields| FeEnumVariant {
+            name: name.into(),
+            fields,
+        };
+
+        return FeType::EnumInstance(FeEnum {
+            name: "Light".into(),
+            variants: vec![
+                variant("Off", FeEnumVariantFields::Unit),
+                variant(
+                    "On",
+                    FeEnumVariantFields::Tuple(vec![FeType::Bool(None), FeType::Bool(None)]),
+                ),
+            ],
+        });
+    }
+
+    fn on(a: Pat, b: Pat) -> Pat {
+        return Pat::Ctor(Ctor::Variant(1), vec![a, b]);
+    }
+
+    fn bool_pat(value: bool) -> Pat {
+        return Pat::Ctor(Ctor::Bool(value), vec![]);
+    }
+
+    #[test]
+    fn test_missing_pattern() {
+        let off = Pat::Ctor(Ctor::Variant(0), vec![]);
+
+        assert_eq!(
+            FeTypeResolver::missing_pattern(vec![off.clone()], &light()),
+            Some("Light::On(_, _)".to_string())
+        );
+        assert_eq!(
+            FeTypeResolver::missing_pattern(
+                vec![
+                    off.clone(),
+                    on(bool_pat(true), Pat::Wild),
+                    on(Pat::Wild, bool_pat(true)),
+                ],
+                &light()
+            ),
+            Some("Light::On(false, false)".to_string())
+        );
+        assert_eq!(
+            FeTypeResolver::missing_pattern(
+                vec![off, on(bool_pat(true), Pat::Wild), on(Pat::Wild, Pat::Wild)],
+                &light()
+            ),
+            None
+        );
+        assert_eq!(
+            FeTypeResolver::missing_pattern(
+                vec![Pat::Ctor(Ctor::Literal("1".into()), vec![])],
+                &FeType::Number(None)
+            ),
+            Some("_".to_string())
+        );
+    }
+}
@@ -22,6 +22,21 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         return Ok(true);
     }
 
+    fn visit_none_literal_expr(
+        &mut self,
+        shared_expr: Arc<Mutex<NoneLiteralExpr<Option<FeType>>>>,
+    ) -> Result<bool> {
+        let mut expr = shared_expr.try_lock().unwrap();
+
+        if expr.is_resolved() {
+            return Ok(false);
+        }
+
+        expr.resolved_type = Some(FeType::EmptyOptional);
+
+        return Ok(true);
+    }
+
     fn visit_number_literal_expr(
         &mut self,
         shared_expr: Arc<Mutex<NumberLiteralExpr<Option<FeType>>>>,
@@ -33,8 +48,8 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         }
 
         expr.resolved_type = Some(FeType::Number(Some(match expr.details {
-            NumberLiteralDetails::Integer(val) => NumberDetails::Integer(Some(val as i64)),
-            NumberLiteralDetails::Decimal(val) => NumberDetails::Decimal(Some(val)),
+            NumberLiteralDetails::Integer(val) => NumberDetails::Integer(None, Some(val.into())),
+            NumberLiteralDetails::Decimal(val) => NumberDetails::Decimal(None, Some(val)),
         })));
 
         return Ok(true);
@@ -77,6 +92,19 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         }
 
         if is_all_checked {
+            for part in &expr.rest {
+                let part_expr = part.expr.0.try_lock().unwrap();
+
+                if let Some(found) = part_expr.resolved_type().flatten() {
+                    if !Self::is_formattable(found.actual_type()) {
+                        self.report(TypeError::NotFormattable {
+                            found: found.clone(),
+                            span: part_expr.span(),
+                        });
+                    }
+                }
+            }
+
             expr.resolved_type = Some(FeType::String(Some(StringDetails::Format)));
             changed = true;
         }
@@ -96,9 +124,27 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         let ident = &expr.ident.lexeme;
 
-        if let Some(found) = self.scope.try_lock().unwrap().search(ident) {
+        let found = self.scope.try_lock().unwrap().search(ident).cloned();
+
+        if let Some(found) = found {
             expr.resolved_type = Some(found.typ.clone());
             self.expr_lookup.insert(expr.id, found.typ.clone());
+        } else if self.report_unresolved {
+            let mut diagnostic = self.type_error(TypeError::UnknownName {
+                name: ident.clone(),
+                span: expr.ident.span.clone(),
+            });
+
+            let names = self.scope.try_lock().unwrap().names();
+            if let Some(suggestion) =
+                self.typo_suggestion("an item", ident, expr.ident.span.clone(), names)
+            {
+                diagnostic = diagnostic.with_suggestion(suggestion);
+            }
+
+            self.report_diagnostic(diagnostic);
+
+            expr.resolved_type = Some(FeType::Error);
         } else {
             return Ok(false);
         }
@@ -124,22 +170,47 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             changed |= callee.accept(self)?;
         }
 
+        let call_span = callee.span().to(&expr.close_paren_token.span);
+
         let Some(resolved_type) = callee.resolved_type().flatten() else {
             return Ok(false);
         };
 
-        let FeType::Callable(callee) = resolved_type else {
-            todo!("How to call on ?? {callee:#?}");
+        let callee = match resolved_type {
+            FeType::Callable(callee) => Some(callee),
+            FeType::Error => None,
+
+            other => {
+                self.report(TypeError::NotCallable {
+                    found: other,
+                    span: callee.span(),
+                });
+
+                None
+            }
         };
 
-        if expr.args.len() > callee.params.len() {
-            todo!(
-                "too many args!\nExpected: {:#?}\nGot: {:#?}",
-                callee.params,
-                expr.args
-            );
+        if let Some(callee) = &callee {
+            if expr.args.len() > callee.params.len() {
+                let extra_arg = &expr.args[callee.params.len()];
+
+                self.report(TypeError::TooManyArgs {
+                    expected: callee.params.len(),
+                    found: expr.args.len(),
+                    span: extra_arg.value.0.try_lock().unwrap().span(),
+                });
+            } else if expr.args.len() < callee.params.len() {
+                self.report(TypeError::MissingArgs {
+                    expected: callee.params.len(),
+                    found: expr.args.len(),
+                    span: expr.close_paren_token.span.clone(),
+                });
+            }
         }
 
+        let mut bindings = HashMap::new();
+        let mut all_args_resolved = true;
+
         for i in 0..expr.args.len() {
             let arg = &mut expr.args[i];
 
@@ -148,11 +219,15 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                 let local_changed = expr.accept(self)?;
 
                 if !local_changed {
+                    all_args_resolved = false;
                     continue;
                 }
                 changed = true;
 
                 let Some(resolved_type) = expr.resolved_type() else {
+                    self.report(TypeError::NoValue { span: expr.span() });
+
+                    arg.resolved_type = Some(FeType::Error);
                     continue;
                 };
 
@@ -160,16 +235,42 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             }
 
             let Some(resolved_type) = &arg.resolved_type else {
-                todo!("How did this get here??")
+                all_args_resolved = false;
+                continue;
             };
-            let (_, param) = &callee.params[i];
+
+            let Some((_, param)) = callee.as_ref().and_then(|callee| callee.params.get(i)) else {
+                continue;
+            };
+
+            Self::infer_generics(param, resolved_type, &mut bindings);
+            let param = &Self::substitute_generics(param, &bindings);
 
             if !Self::can_implicit_cast(resolved_type, param) {
-                todo!("wrong type!\nCannot implicitly cast {resolved_type:#?}\nto {param:#?}");
+                self.report(Self::mismatched_types(
+                    param.clone(),
+                    resolved_type.clone(),
+                    arg.value.0.try_lock().unwrap().span(),
+                ));
             }
         }
 
-        expr.resolved_type = callee.return_type.as_deref().map(|rt| Some(rt.clone()));
+        expr.resolved_type = match callee {
+            // The return type depends on what's passed
+            Some(callee) if !callee.generics.is_empty() && !all_args_resolved => None,
+
+            Some(callee) => {
+                let bound = self.bound_generics(&callee.generics, &bindings, &call_span);
+                let bindings = callee.generics.iter().cloned().zip(bound).collect();
+
+                callee
+                    .return_type
+                    .as_deref()
+                    .map(|rt| Some(Self::substitute_generics(rt, &bindings)))
+            }
+
+            None => Some(Some(FeType::Error)),
+        };
 
         return Ok(changed);
     }
@@ -188,6 +289,8 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         changed |= expr.value.0.try_lock().unwrap().accept(self)?;
 
+        let span = expr.span();
+
         if let Some(resolved_type) = expr.value.0.try_lock().unwrap().resolved_type().flatten() {
             changed = true;
 
@@ -204,18 +307,58 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                         of: Box::new(resolved_type),
                     }));
                 }
-                UnaryOp::Not(_) => {
+                UnaryOp::Not(op) => {
                     // Only apply to bool
                     if !Self::can_implicit_cast(&resolved_type, &FeType::Bool(None)) {
-                        todo!("Can't cast implicitly to bool");
+                        self.report(TypeError::InvalidUnaryOperand {
+                            op: op.lexeme.clone(),
+                            operand: resolved_type.clone(),
+                            span,
+                        });
                     }
 
-                    expr.resolved_type = if let FeType::Bool(details) = &resolved_type {
+                    expr.resolved_type = if let FeType::Bool(details) = resolved_type.actual_type()
+                    {
                         Some(FeType::Bool(details.map(|known_val| !known_val)))
                     } else {
                         Some(FeType::Bool(None))
                     };
                 }
+                UnaryOp::Minus(op) => {
+                    expr.resolved_type = match resolved_type.actual_type() {
+                        FeType::Error => Some(FeType::Error),
+
+                        FeType::Number(Some(NumberDetails::Integer(Some(size), _)))
+                            if !size.is_signed() =>
+                        {
+                            self.report(TypeError::InvalidUnaryOperand {
+                                op: op.lexeme.clone(),
+                                operand: resolved_type.clone(),
+                                span,
+                            });
+
+                            Some(FeType::Error)
+                        }
+
+                        FeType::Number(details) => match Self::fold_negate(details) {
+                            Ok(details) => Some(FeType::Number(details)),
+                            Err(_) => {
+                                self.report(TypeError::ArithmeticOverflow { span });
+                                Some(FeType::Error)
+                            }
+                        },
+
+                        _ => {
+                            self.report(TypeError::InvalidUnaryOperand {
+                                op: op.lexeme.clone(),
+                                operand: resolved_type.clone(),
+                                span,
+                            });
+
+                            Some(FeType::Error)
+                        }
+                    };
+                }
             }
         }
 
@@ -237,13 +380,30 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         changed |= expr.lhs.0.try_lock().unwrap().accept(self)?;
         changed |= expr.rhs.0.try_lock().unwrap().accept(self)?;
 
+        let span = expr.span();
+        let lhs_span = expr.lhs.0.try_lock().unwrap().span();
+        let rhs_span = expr.rhs.0.try_lock().unwrap().span();
+
         if let (Some(resolved_lhs), Some(resolved_rhs)) = (
             expr.lhs.0.try_lock().unwrap().resolved_type().flatten(),
             expr.rhs.0.try_lock().unwrap().resolved_type().flatten(),
         ) {
             changed = true;
 
+            let invalid_operands = TypeError::InvalidBinaryOperands {
+                op: expr.op.token().lexeme.clone(),
+                lhs: resolved_lhs.clone(),
+                rhs: resolved_rhs.clone(),
+                span: span.clone(),
+            };
+
             match &expr.op {
+                _ if matches!(resolved_lhs.actual_type(), FeType::Error)
+                    || matches!(resolved_rhs.actual_type(), FeType::Error) =>
+                {
+                    expr.resolved_type = Some(FeType::Error);
+                }
+
                 BinaryOp::Less(_)
                 | BinaryOp::LessEq(_)
                 | BinaryOp::Greater(_)
@@ -251,162 +411,181 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                     let resolved_lhs = resolved_lhs.actual_type();
                     let resolved_rhs = resolved_rhs.actual_type();
 
-                    if matches!(
-                        (resolved_lhs, resolved_rhs),
-                        (FeType::Number(_), FeType::Number(_))
+                    match (resolved_lhs, resolved_rhs) {
+                        (FeType::Number(lhs), FeType::Number(rhs)) => {
+                            match Self::fold_comparison(&expr.op, lhs, rhs) {
+                                Some(known) => {
+                                    if let Some(common) = Self::common_number_type(lhs, rhs) {
+                                        self.check_number_operands(
+                                            &common,
+                                            [(resolved_lhs, &lhs_span), (resolved_rhs, &rhs_span)],
+                                        );
+                                    }
+
+                                    expr.resolved_type = Some(FeType::Bool(known));
+                                }
+                                None => {
+                                    self.report(invalid_operands);
+                                    expr.resolved_type = Some(FeType::Error);
+                                }
+                            }
+                        }
+                        _ => {
+                            self.report(invalid_operands);
+                            expr.resolved_type = Some(FeType::Error);
+                        }
+                    }
+                }
+
+                BinaryOp::Equal(_) | BinaryOp::NotEqual(_) => {
+                    let is_equal = matches!(expr.op, BinaryOp::Equal(_));
+
+                    match Self::known_equality(
+                        resolved_lhs.actual_type(),
+                        resolved_rhs.actual_type(),
                     ) {
-                        expr.resolved_type = Some(FeType::Bool(None));
-                    } else {
-                        todo!();
+                        Some(known) => {
+                            expr.resolved_type =
+                                Some(FeType::Bool(known.map(|equal| equal == is_equal)));
+                        }
+                        None => {
+                            self.report(invalid_operands);
+                            expr.resolved_type = Some(FeType::Error);
+                        }
                     }
                 }
 
-                BinaryOp::Add(_) => {
+                BinaryOp::Range(_) | BinaryOp::RangeInclusive(_) => {
                     let resolved_lhs = resolved_lhs.actual_type();
                     let resolved_rhs = resolved_rhs.actual_type();
 
-                    match (resolved_lhs, resolved_rhs) {
-                        (FeType::Number(lhs), FeType::Number(rhs)) => match (lhs, rhs) {
-                            // known values at compile time
-                            (
-                                Some(NumberDetails::Integer(Some(lhs))),
-                                Some(NumberDetails::Integer(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Integer(Some(*lhs + *rhs)),
-                                )));
-                            }
-                            (
-                                Some(NumberDetails::Decimal(Some(lhs))),
-                                Some(NumberDetails::Integer(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Decimal(Some(*lhs + *rhs as f64)),
-                                )));
-                            }
-                            (
-                                Some(NumberDetails::Integer(Some(lhs))),
-                                Some(NumberDetails::Decimal(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Decimal(Some(*lhs as f64 + *rhs)),
-                                )));
-                            }
-                            (
-                                Some(NumberDetails::Decimal(Some(lhs))),
-                                Some(NumberDetails::Decimal(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Decimal(Some(*lhs + *rhs)),
-                                )));
-                            }
+                    let common = match (resolved_lhs, resolved_rhs) {
+                        (FeType::Number(lhs), FeType::Number(rhs)) => {
+                            Self::common_number_type(lhs, rhs)
+                        }
+                        _ => None,
+                    };
 
-                            // unknown values, known types
-                            (
-                                Some(NumberDetails::Integer(_)),
-                                Some(NumberDetails::Integer(None)),
-                            )
-                            | (
-                                Some(NumberDetails::Integer(None)),
-                                Some(NumberDetails::Integer(_)),
-                            ) => {
-                                expr.resolved_type =
-                                    Some(FeType::Number(Some(NumberDetails::Integer(None))));
-                            }
-                            (Some(NumberDetails::Decimal(_)), _) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    // TODO: we know this is greater-than lhs val
-                                    NumberDetails::Decimal(None),
-                                )));
-                            }
-                            (_, Some(NumberDetails::Decimal(_))) => {
-                                expr.resolved_type =
-                                    Some(FeType::Number(Some(NumberDetails::Decimal(None))))
-                            }
+                    match common {
+                        Some(Some(NumberDetails::Integer(size, _))) => {
+                            self.check_number_operands(
+                                &Some(NumberDetails::Integer(size, None)),
+                                [(resolved_lhs, &lhs_span), (resolved_rhs, &rhs_span)],
+                            );
 
-                            // other
-                            (_, None) | (None, _) => {
-                                expr.resolved_type = Some(FeType::Number(None));
-                            }
-                        },
-                        _ => todo!("unsure how to add {resolved_lhs:#?} to {resolved_rhs:#?}"),
+                            expr.resolved_type = Some(FeType::Range(Box::new(FeType::Number(
+                                Some(NumberDetails::Integer(size, None)),
+                            ))));
+                        }
+                        Some(None) => {
+                            expr.resolved_type = Some(FeType::Range(Box::new(FeType::Number(
+                                Some(NumberDetails::Integer(None, None)),
+                            ))));
+                        }
+                        _ => {
+                            self.report(invalid_operands);
+                            expr.resolved_type = Some(FeType::Error);
+                        }
                     }
                 }
 
-                BinaryOp::Subtract(_) => {
+                BinaryOp::Add(_)
+                | BinaryOp::Subtract(_)
+                | BinaryOp::Multiply(_)
+                | BinaryOp::Divide(_)
+                | BinaryOp::Modulo(_) => {
                     let resolved_lhs = resolved_lhs.actual_type();
                     let resolved_rhs = resolved_rhs.actual_type();
 
                     match (resolved_lhs, resolved_rhs) {
-                        (FeType::Number(lhs), FeType::Number(rhs)) => match (lhs, rhs) {
-                            // known values at compile time
-                            (
-                                Some(NumberDetails::Integer(Some(lhs))),
-                                Some(NumberDetails::Integer(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Integer(Some(*lhs - *rhs)),
-                                )));
-                            }
-                            (
-                                Some(NumberDetails::Decimal(Some(lhs))),
-                                Some(NumberDetails::Integer(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Decimal(Some(*lhs - *rhs as f64)),
-                                )));
-                            }
-                            (
-                                Some(NumberDetails::Integer(Some(lhs))),
-                                Some(NumberDetails::Decimal(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Decimal(Some(*lhs as f64 - *rhs)),
-                                )));
-                            }
-                            (
-                                Some(NumberDetails::Decimal(Some(lhs))),
-                                Some(NumberDetails::Decimal(Some(rhs))),
-                            ) => {
-                                expr.resolved_type = Some(FeType::Number(Some(
-                                    NumberDetails::Decimal(Some(*lhs - *rhs)),
-                                )));
-                            }
-
-                            // unknown values, known types
-                            (
-                                Some(NumberDetails::Integer(_)),
-                                Some(NumberDetails::Integer(None)),
-                            )
-                            | (
-                                Some(NumberDetails::Integer(None)),
-                                Some(NumberDetails::Integer(_)),
-                            ) => {
-                                expr.resolved_type =
-                                    Some(FeType::Number(Some(NumberDetails::Integer(None))));
-                            }
-                            (Some(NumberDetails::Decimal(_)), _) => {
-                                expr.resolved_type =
-                                    Some(FeType::Number(Some(NumberDetails::Decimal(None))));
-                            }
-                            (_, Some(NumberDetails::Decimal(_))) => {
-                                expr.resolved_type =
-                                    Some(FeType::Number(Some(NumberDetails::Decimal(None))))
-                            }
-
-                            // other
-                            (_, None) | (None, _) => {
-                                expr.resolved_type = Some(FeType::Number(None));
+                        (FeType::Number(lhs), FeType::Number(rhs)) => {
+                            match Self::fold_arithmetic(&expr.op, lhs, rhs) {
+                                Ok(details) => {
+                                    self.check_number_operands(
+                                        &details,
+                                        [(resolved_lhs, &lhs_span), (resolved_rhs, &rhs_span)],
+                                    );
+
+                                    expr.resolved_type = Some(FeType::Number(details));
+                                }
+                                Err(ConstEvalError::IncompatibleOperands) => {
+                                    self.report(invalid_operands);
+                                    expr.resolved_type = Some(FeType::Error);
+                                }
+                                Err(ConstEvalError::Overflow) => {
+                                    self.report(TypeError::ArithmeticOverflow { span });
+                                    expr.resolved_type = Some(FeType::Error);
+                                }
+                                Err(ConstEvalError::DivisionByZero) => {
+                                    self.report(TypeError::DivisionByZero { span: rhs_span });
+                                    expr.resolved_type = Some(FeType::Error);
+                                }
                             }
-                        },
-                        _ => todo!("unsure how to add {resolved_lhs:#?} to {resolved_rhs:#?}"),
+                        }
+                        _ => {
+                            self.report(invalid_operands);
+                            expr.resolved_type = Some(FeType::Error);
+                        }
                     }
                 }
             }
         }
 
-        if !changed {
-            todo!("determine lhs or rhs error");
+        return Ok(changed);
+    }
+
+    fn visit_logical_expr(
+        &mut self,
+        shared_expr: Arc<Mutex<LogicalExpr<Option<FeType>>>>,
+    ) -> Result<bool> {
+        let expr = &mut *shared_expr.try_lock().unwrap();
+
+        if expr.is_resolved() {
+            return Ok(false);
+        }
+
+        let mut changed = false;
+
+        changed |= expr.lhs.0.try_lock().unwrap().accept(self)?;
+        changed |= expr.rhs.0.try_lock().unwrap().accept(self)?;
+
+        let span = expr.span();
+
+        if let (Some(resolved_lhs), Some(resolved_rhs)) = (
+            expr.lhs.0.try_lock().unwrap().resolved_type().flatten(),
+            expr.rhs.0.try_lock().unwrap().resolved_type().flatten(),
+        ) {
+            changed = true;
+
+            let lhs = resolved_lhs.actual_type();
+            let rhs = resolved_rhs.actual_type();
+
+            expr.resolved_type = match (lhs, rhs) {
+                (FeType::Error, _) | (_, FeType::Error) => Some(FeType::Error),
+
+                // Known when either side decides the result
+                (FeType::Bool(lhs), FeType::Bool(rhs)) => match (&expr.op, lhs, rhs) {
+                    (LogicalOp::And(_), Some(false), _) | (LogicalOp::And(_), _, Some(false)) => {
+                        Some(FeType::Bool(Some(false)))
+                    }
+                    (LogicalOp::Or(_), Some(true), _) | (LogicalOp::Or(_), _, Some(true)) => {
+                        Some(FeType::Bool(Some(true)))
+                    }
+                    (_, Some(lhs), Some(rhs)) => Some(FeType::Bool(Some(*lhs && *rhs))),
+                    _ => Some(FeType::Bool(None)),
+                },
+
+                _ => {
+                    self.report(TypeError::InvalidBinaryOperands {
+                        op: expr.op.token().lexeme.clone(),
+                        lhs: resolved_lhs.clone(),
+                        rhs: resolved_rhs.clone(),
+                        span,
+                    });
+
+                    Some(FeType::Error)
+                }
+            };
         }
 
         return Ok(changed);
@@ -446,6 +625,11 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let mut changed = false;
         let mut target = None;
 
+        let target_span = match &expr.target {
+            ConstructTarget::Ident(ident) => ident.try_lock().unwrap().span(),
+            ConstructTarget::StaticPath(path) => path.span(),
+        };
+
         match &mut expr.target {
             ConstructTarget::Ident(ident) => {
                 changed |= ident.accept(self)?;
@@ -464,8 +648,30 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         }
 
         if let Some(target) = target {
-            let FeType::Struct(target) = target else {
-                todo!("Can't construct type {target:#?}");
+            let target = match target {
+                FeType::Struct(target) => target,
+
+                other => {
+                    if other != FeType::Error {
+                        self.report(TypeError::NotConstructable {
+                            found: other,
+                            span: target_span,
+                        });
+                    }
+
+                    for arg in &mut expr.args {
+                        match arg {
+                            ConstructArg::Field(field) => {
+                                changed |= field.value.0.try_lock().unwrap().accept(self)?;
+                            }
+                        }
+                    }
+
+                    changed |= expr.resolved_type.is_none();
+                    expr.resolved_type = Some(FeType::Error);
+
+                    return Ok(changed);
+                }
             };
 
             let fields_map = target
@@ -477,59 +683,98 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
             let mut seen = HashSet::new();
 
+            let mut bindings = HashMap::new();
+            let mut all_args_resolved = true;
+
             for arg in &mut expr.args {
                 match arg {
                     ConstructArg::Field(field) => {
                         changed |= field.value.0.try_lock().unwrap().accept(self)?;
 
                         let Some(struct_field) = fields_map.get(&field.name.lexeme) else {
-                            todo!(
-                                "No field found with name {:?} for struct {:?}",
-                                field.name.lexeme,
-                                target.name
-                            );
+                            let mut diagnostic = self.type_error(TypeError::UnknownField {
+                                name: field.name.lexeme.clone(),
+                                struct_name: target.name.clone(),
+                                span: field.name.span.clone(),
+                            });
+
+                            let names = fields_map.keys().cloned().collect();
+                            if let Some(suggestion) = self.typo_suggestion(
+                                "a field",
+                                &field.name.lexeme,
+                                field.name.span.clone(),
+                                names,
+                            ) {
+                                diagnostic = diagnostic.with_suggestion(suggestion);
+                            }
+
+                            self.report_diagnostic(diagnostic);
+                            continue;
                         };
 
                         if seen.contains(&field.name.lexeme) {
-                            todo!("Duplicate arg! {field:#?}");
+                            self.report(TypeError::DuplicateField {
+                                name: field.name.lexeme.clone(),
+                                span: field.name.span.clone(),
+                            });
                         }
 
                         seen.insert(field.name.lexeme.clone());
 
+                        let value_span = field.value.0.try_lock().unwrap().span();
+
                         if let Some(resolved) =
                             field.value.0.try_lock().unwrap().resolved_type().flatten()
                         {
-                            if !Self::can_implicit_cast(&resolved, &struct_field.typ) {
-                                todo!("Invalid type! {resolved:#?}");
+                            Self::infer_generics(&struct_field.typ, &resolved, &mut bindings);
+                            let field_type =
+                                Self::substitute_generics(&struct_field.typ, &bindings);
+
+                            if !Self::can_implicit_cast(&resolved, &field_type) {
+                                self.report(Self::mismatched_types(
+                                    field_type, resolved, value_span,
+                                ));
                             }
+                        } else {
+                            all_args_resolved = false;
                         }
                     }
                 }
             }
 
-            let leftover_fields = fields_map.into_iter().filter_map(|(name, field)| {
-                if seen.contains(&name) {
-                    return None;
+            // TODO: Check for default or optional
+            let leftover_fields = target
+                .fields
+                .iter()
+                .filter(|field| !seen.contains(&field.name))
+                .map(|field| field.name.clone())
+                .collect::<Vec<Arc<str>>>();
+
+            if !leftover_fields.is_empty() {
+                let mut diagnostic = self.type_error(TypeError::MissingFields {
+                    struct_name: target.name.clone(),
+                    fields: leftover_fields,
+                    span: expr.span(),
+                });
+
+                if let Some(suggestion) = Self::missing_fields_suggestion(&expr, &target, &seen) {
+                    diagnostic = diagnostic.with_suggestion(suggestion);
                 }
 
-                return Some(field);
-            });
-
-            for field in leftover_fields {
-                // TODO: Check for default or optional
+                self.report_diagnostic(diagnostic);
+            }
 
-                todo!("Field not instantiated! {field:#?}");
+            // The type arguments depend on the field values
+            if !target.generics.is_empty() && !all_args_resolved {
+                return Ok(changed);
             }
 
-            expr.resolved_type = Some(FeType::Instance(FeInstance {
-                special: None,
-                name: target.name,
-                fields: target
-                    .fields
-                    .into_iter()
-                    .map(|f| (f.name.clone(), f))
-                    .collect(),
-            }));
+            let generic_args = self.bound_generics(&target.generics, &bindings, &expr.span());
+
+            expr.resolved_type = match target.special {
+                Some(SpecialStruct::EnumVariant(of)) => Some(FeType::EnumInstance(of)),
+                None => Some(FeType::Instance(Self::instance_of(target, generic_args))),
+            };
         }
 
         return Ok(changed);
@@ -546,20 +791,61 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         changed |= expr.target.0.try_lock().unwrap().accept(self)?;
 
-        if let Some(resolved) = expr.target.0.try_lock().unwrap().resolved_type().flatten() {
+        let target = expr.target.0.try_lock().unwrap();
+
+        if let Some(resolved) = target.resolved_type().flatten() {
             // TODO: I don't love this, what if theres a shared ref of a mut ref or something weird?
             let Some(instance) = resolved.instance() else {
-                todo!("How can you get a property of a non-instance? Maybe the type system needs reworking... {resolved:#?}");
-            };
+                if resolved.actual_type() != &FeType::Error {
+                    self.report(TypeError::NotAnInstance {
+                        name: expr.name.lexeme.clone(),
+                        found: resolved.clone(),
+                        span: expr.name.span.clone(),
+                    });
+                }
 
-            // TODO: methods?
+                expr.resolved_type = Some(FeType::Error);
+                return Ok(true);
+            };
 
             let Some(field) = instance.fields.get(&expr.name.lexeme).cloned() else {
-                todo!(
-                    "Couldn't find property {:#?} on instance {:#?}",
-                    expr.name,
-                    instance
-                );
+                if let Some(method) = instance.methods.get(&expr.name.lexeme) {
+                    expr.resolved_type = Some(
+                        self.method_on_instance(&target, &resolved, instance, method, &expr.name),
+                    );
+                    return Ok(true);
+                }
+
+                // Methods of a struct from another package might not be known yet
+                if !self.report_unresolved {
+                    return Ok(changed);
+                }
+
+                let mut diagnostic = self.type_error(TypeError::UnknownProperty {
+                    name: expr.name.lexeme.clone(),
+                    instance_name: instance.name.clone(),
+                    span: expr.name.span.clone(),
+                });
+
+                let names = instance
+                    .fields
+                    .keys()
+                    .cloned()
+                    .chain(instance.methods.names())
+                    .collect();
+                if let Some(suggestion) = self.typo_suggestion(
+                    "a property",
+                    &expr.name.lexeme,
+                    expr.name.span.clone(),
+                    names,
+                ) {
+                    diagnostic = diagnostic.with_suggestion(suggestion);
+                }
+
+                self.report_diagnostic(diagnostic);
+
+                expr.resolved_type = Some(FeType::Error);
+                return Ok(true);
             };
 
             let resolved = match resolved {
@@ -606,15 +892,7 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             if !condition.is_resolved() {
                 changed |= condition.accept(self)?;
 
-                let Some(resolved_type) = condition.resolved_type() else {
-                    todo!("Can't check if condition on no type!");
-                };
-
-                if let Some(resolved_type) = resolved_type {
-                    if !Self::can_implicit_cast(&resolved_type, &FeType::Bool(None)) {
-                        todo!("Can't cast to bool!");
-                    }
-                }
+                self.check_condition(&condition);
             }
         }
 
@@ -622,6 +900,9 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         let mut typ = None;
 
+        // Without an else, or with a branch that doesn't give a value, the value is optional
+        let mut may_be_empty = false;
+
         let then = {
             let expr = &mut *shared_expr.try_lock().unwrap();
             expr.then.clone()
@@ -656,11 +937,7 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
                 changed |= local_changed;
 
-                if terminal.is_none() {
-                    todo!("TODO: Implicit optional");
-                }
-
-                // TODO: Try to determine and check type here if terminal is Then stmt
+                may_be_empty |= terminal.is_none();
             }
         }
 
@@ -677,15 +954,7 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
                         changed |= condition.accept(self)?;
 
-                        let Some(resolved_type) = condition.resolved_type() else {
-                            todo!("Can't check if condition on no type!");
-                        };
-
-                        if let Some(resolved_type) = resolved_type {
-                            if !Self::can_implicit_cast(&resolved_type, &FeType::Bool(None)) {
-                                todo!("Can't cast to bool!");
-                            }
-                        }
+                        self.check_condition(&condition);
                     }
 
                     {
@@ -694,21 +963,24 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                         changed |= expr.accept(self)?;
 
                         let Some(resolved_type) = expr.resolved_type() else {
-                            todo!("Can't use no-type as a value");
+                            self.report(TypeError::NoValue { span: expr.span() });
+                            continue;
                         };
 
-                        if let Some(typ) = &typ {
-                            if let Some(resolved_type) = resolved_type {
-                                if !Self::can_implicit_cast(&resolved_type, typ) {
-                                    todo!("Can't cast!");
-                                }
-                            }
-                        } else if let Some(resolved_type) = resolved_type {
-                            typ = Some(resolved_type.clone());
+                        if let Some(resolved_type) = resolved_type {
+                            self.join_branch_type(&mut typ, resolved_type, expr.span());
                         }
                     }
                 }
                 IfExprElseIf::Block(else_if) => {
+                    {
+                        let condition = else_if.condition.0.try_lock().unwrap();
+
+                        changed |= condition.accept(self)?;
+
+                        self.check_condition(&condition);
+                    }
+
                     self.scope
                         .try_lock()
                         .unwrap()
@@ -718,14 +990,13 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                         )));
 
                     self.thenable_count += 1;
-                    let (local_changed, _terminal) = self.resolve_stmts(&else_if.block.stmts)?;
+                    let (local_changed, terminal) = self.resolve_stmts(&else_if.block.stmts)?;
                     self.thenable_count -= 1;
 
                     self.scope.try_lock().unwrap().end_scope();
 
                     changed |= local_changed;
-
-                    // TODO: Try to determine and check type here if terminal is Then stmt
+                    may_be_empty |= terminal.is_none();
                 }
             }
         }
@@ -743,18 +1014,16 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
                         changed |= else_expr.accept(self)?;
 
-                        let Some(resolved_type) = else_expr.resolved_type() else {
-                            todo!("Can't use no-type as a value");
-                        };
+                        match else_expr.resolved_type() {
+                            None => self.report(TypeError::NoValue {
+                                span: else_expr.span(),
+                            }),
 
-                        if let Some(typ) = &typ {
-                            if let Some(resolved_type) = resolved_type {
-                                if !Self::can_implicit_cast(&resolved_type, typ) {
-                                    todo!("Can't cast!");
-                                }
+                            Some(Some(resolved_type)) => {
+                                self.join_branch_type(&mut typ, resolved_type, else_expr.span());
                             }
-                        } else if let Some(resolved_type) = resolved_type {
-                            typ = Some(resolved_type);
+
+                            Some(None) => {}
                         }
                     }
                     IfExprElse::Block(else_) => {
@@ -767,31 +1036,183 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                             )));
 
                         self.thenable_count += 1;
-                        let (local_changed, _terminal) = self.resolve_stmts(&else_.block.stmts)?;
+                        let (local_changed, terminal) = self.resolve_stmts(&else_.block.stmts)?;
                         self.thenable_count -= 1;
 
                         self.scope.try_lock().unwrap().end_scope();
 
                         changed |= local_changed;
-
-                        // TODO: Try to determine and check type here if terminal is Then stmt
+                        may_be_empty |= terminal.is_none();
                     }
                 }
             }
         } else {
-            todo!("TODO: Implicit wrap as optional")
+            may_be_empty = true;
         }
 
         let expr = &mut *shared_expr.try_lock().unwrap();
 
-        if let Some(Some(already)) = &expr.resolved_type {
-            if let Some(typ) = typ {
-                if !Self::can_implicit_cast(&typ, already) {
-                    todo!();
+        // Joined with the values the blocks' then statements gave it
+        let mut joined = expr.resolved_type.clone().flatten();
+        if let Some(typ) = typ {
+            self.join_branch_type(&mut joined, typ, expr.span());
+        }
+
+        if may_be_empty {
+            joined = joined.map(Self::optional_of);
+        }
+
+        // The value depends on which branch runs
+        expr.resolved_type = Some(joined.map(Self::without_known_value));
+
+        return Ok(changed);
+    }
+
+    fn visit_match_expr(
+        &mut self,
+        shared_expr: Arc<Mutex<MatchExpr<Option<FeType>>>>,
+    ) -> Result<bool> {
+        {
+            let expr = &mut *shared_expr.try_lock().unwrap();
+
+            if expr.is_resolved() {
+                return Ok(false);
+            }
+        }
+
+        let mut changed = false;
+
+        let value = {
+            let expr = &mut *shared_expr.try_lock().unwrap();
+            expr.value.clone()
+        };
+
+        let value_type = {
+            let value = value.0.try_lock().unwrap();
+
+            changed |= value.accept(self)?;
+
+            match value.resolved_type() {
+                None => {
+                    self.report(TypeError::NoValue { span: value.span() });
+                    Some(FeType::Error)
                 }
+                Some(resolved_type) => resolved_type,
+            }
+        };
+
+        let Some(value_type) = value_type else {
+            return Ok(changed);
+        };
+
+        // Patterns match against the value itself, whatever it's known to be
+        let expected = Self::without_known_value(value_type.actual_type().clone());
+
+        let arm_count = { shared_expr.try_lock().unwrap().arms.len() };
+
+        let mut typ = None;
+        let mut pats = vec![];
+        let mut patterns_resolved = true;
+        let mut open_arms = vec![];
+
+        for idx in 0..arm_count {
+            self.scope
+                .try_lock()
+                .unwrap()
+                .begin_scope(Some(ScopeCreator::MatchExpr(shared_expr.clone())));
+
+            let (pat, guard, body) = {
+                let expr = &mut *shared_expr.try_lock().unwrap();
+                let arm = &mut expr.arms[idx];
+
+                let (local_changed, pat) = self.check_pattern(&mut arm.pattern, &expected)?;
+                changed |= local_changed;
+
+                patterns_resolved &= arm.pattern.is_resolved();
+
+                (pat, arm.guard.clone(), arm.body.clone())
+            };
+
+            if let Some(guard) = &guard {
+                let condition = guard.condition.0.try_lock().unwrap();
+
+                changed |= condition.accept(self)?;
+
+                self.check_condition(&condition);
+            } else {
+                // A guard can fail, so its arm doesn't cover its pattern
+                pats.push(pat);
+            }
+
+            match &body {
+                MatchArmBody::Ternary(ternary) => {
+                    let expr = ternary.then_expr.0.try_lock().unwrap();
+
+                    changed |= expr.accept(self)?;
+
+                    match expr.resolved_type() {
+                        // Fine when the match is a statement, like `then print(x)`
+                        None => open_arms.push(expr.span()),
+
+                        Some(Some(resolved_type)) => {
+                            self.join_branch_type(&mut typ, resolved_type, expr.span());
+                        }
+
+                        Some(None) => {}
+                    }
+                }
+                MatchArmBody::Block(block) => {
+                    self.thenable_count += 1;
+                    let (local_changed, terminal) = self.resolve_stmts(&block.stmts)?;
+                    self.thenable_count -= 1;
+
+                    changed |= local_changed;
+
+                    if terminal.is_none() {
+                        let expr = shared_expr.try_lock().unwrap();
+                        open_arms.push(expr.arms[idx].pattern.span());
+                    }
+                }
+            }
+
+            self.scope.try_lock().unwrap().end_scope();
+        }
+
+        if patterns_resolved && expected != FeType::Error {
+            let span = {
+                let expr = shared_expr.try_lock().unwrap();
+                expr.match_token
+                    .span
+                    .to(&value.0.try_lock().unwrap().span())
+            };
+
+            if let Some(missing) = Self::missing_pattern(pats, &expected) {
+                self.report(TypeError::NonExhaustiveMatch {
+                    missing: missing.into(),
+                    span,
+                });
+            }
+        }
+
+        let expr = &mut *shared_expr.try_lock().unwrap();
+
+        // Joined with the values the blocks' then statements gave it
+        let mut joined = expr.resolved_type.clone().flatten();
+        if let Some(typ) = typ {
+            self.join_branch_type(&mut joined, typ, expr.span());
+        }
+
+        // The value depends on which arm matches
+        expr.resolved_type = Some(joined.map(Self::without_known_value));
+
+        // Every arm has to give the match its value, if any of them does
+        if let Some(Some(expected)) = &expr.resolved_type {
+            for span in open_arms {
+                self.report(TypeError::MissingArmValue {
+                    expected: expected.clone(),
+                    span,
+                });
             }
-        } else {
-            expr.resolved_type = Some(typ);
         }
 
         return Ok(changed);
@@ -849,6 +1270,23 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         let condition = {
             let expr = &mut *shared_expr.try_lock().unwrap();
+
+            // Reported before the branches are left unresolved, which would fail to finalize
+            let branch_token = match (&expr.then, &expr.else_) {
+                (Some(WhileExprThen::Ternary(then)), _) => Some(&then.then_token),
+                (Some(WhileExprThen::Block(then)), _) => Some(&then.then_token),
+                (None, Some(WhileExprElse::Ternary(else_))) => Some(&else_.else_token),
+                (None, Some(WhileExprElse::Block(else_))) => Some(&else_.else_token),
+                (None, None) => None,
+            };
+
+            if let Some(branch_token) = branch_token {
+                self.report(TypeError::UnsupportedWhileBranch {
+                    keyword: branch_token.lexeme.clone(),
+                    span: branch_token.span.clone(),
+                });
+            }
+
             expr.condition.clone()
         };
 
@@ -872,4 +1310,157 @@ impl ExprVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         return Ok(changed);
     }
+
+    fn visit_for_expr(&mut self, shared_expr: Arc<Mutex<ForExpr<Option<FeType>>>>) -> Result<bool> {
+        let (iter, name, stmts, else_expr) = {
+            let expr = &mut *shared_expr.try_lock().unwrap();
+
+            if expr.is_resolved() {
+                return Ok(false);
+            }
+
+            (
+                expr.iter.clone(),
+                expr.name.clone(),
+                expr.block.stmts.clone(),
+                expr.else_.else_expr.clone(),
+            )
+        };
+
+        let mut changed = false;
+
+        changed |= iter.0.try_lock().unwrap().accept(self)?;
+
+        // The else value can't see the loop's name, and decides the type breaks must match
+        changed |= else_expr.0.try_lock().unwrap().accept(self)?;
+
+        let else_type = else_expr.0.try_lock().unwrap().resolved_type();
+
+        match else_type {
+            None => {
+                self.report(TypeError::NoValue {
+                    span: else_expr.0.try_lock().unwrap().span(),
+                });
+
+                shared_expr.try_lock().unwrap().resolved_type = Some(Some(FeType::Error));
+            }
+
+            Some(Some(else_type)) => {
+                let expr = &mut *shared_expr.try_lock().unwrap();
+
+                if expr.resolved_type.is_none() {
+                    expr.resolved_type = Some(Some(Self::without_known_value(else_type)));
+                    changed = true;
+                }
+            }
+
+            Some(None) => {}
+        }
+
+        let Some(item_type) = self.for_item_type(&iter.0.try_lock().unwrap()) else {
+            return Ok(changed);
+        };
+
+        self.scope
+            .try_lock()
+            .unwrap()
+            .begin_scope(Some(ScopeCreator::ForExpr(shared_expr)));
+
+        self.declare_for_name(&name, item_type.with_default_number_size());
+
+        self.breakable_count += 1;
+        changed |= self.resolve_stmts(&stmts)?.0;
+        self.breakable_count -= 1;
+
+        self.scope.try_lock().unwrap().end_scope();
+
+        return Ok(changed);
+    }
+}
+
+impl FeTypeResolver {
+    /// Reports the known operands of a binary expression that don't fit the
+    /// number type it's done in, like the `300` of `level + 300` with a `U8` level.
+    fn check_number_operands(
+        &self,
+        common: &Option<NumberDetails>,
+        operands: [(&FeType, &Span); 2],
+    ) {
+        let common = FeType::Number(common.clone());
+
+        for (operand, span) in operands {
+            if !Self::can_implicit_cast(operand, &common) {
+                self.report(Self::mismatched_types(
+                    common.clone(),
+                    operand.clone(),
+                    span.clone(),
+                ));
+            }
+        }
+    }
+
+    /// Suggests filling in the missing fields of `expr` with placeholder values,
+    /// e.g. `Point { x: 1 }` to `Point { x: 1, y: 0 }`.
+    ///
+    /// Only suggested when every missing field has a placeholder for its type.
+    fn missing_fields_suggestion(
+        expr: &ConstructExpr<Option<FeType>>,
+        target: &FeStruct,
+        seen: &HashSet<Arc<str>>,
+    ) -> Option<DiagnosticSuggestion> {
+        let mut fields = vec![];
+
+        for field in target
+            .fields
+            .iter()
+            .filter(|field| !seen.contains(&field.name))
+        {
+            let placeholder = match &field.typ {
+                FeType::Number(_) => "0",
+                FeType::String(_) => "\"\"",
+                FeType::Bool(_) => "false",
+                FeType::Optional(_) => "none",
+                _ => return None,
+            };
+
+            fields.push(format!("{}: {placeholder}", field.name));
+        }
+
+        let fields = fields.join(", ");
+        let close = &expr.close_squirly_brace;
+
+        let last_field = expr.args.last().map(|arg| match arg {
+            ConstructArg::Field(field) => field,
+        });
+
+        let (span, replacement) = match last_field {
+            // Replace from the end of the last value, so the new fields follow it directly
+            Some(field) if field.comma_token.is_none() => {
+                let value_end = field.value.0.try_lock().unwrap().span().end;
+
+                let start = Position {
+                    index: value_end.index + 1,
+                    line: value_end.line,
+                    column: value_end.column + 1,
+                };
+
+                (
+                    Span {
+                        start,
+                        end: close.span.end.clone(),
+                    },
+                    format!(", {fields} }}"),
+                )
+            }
+
+            Some(_) => (close.span.clone(), format!("{fields} }}")),
+
+            None => (close.span.clone(), format!(" {fields} }}")),
+        };
+
+        return Some(
+            DiagnosticSuggestion::new("add the missing fields", span, replacement)
+                .maybe_incorrect(),
+        );
+    }
 }
@@ -0,0 +1,220 @@
+use super::*;
+
+type GenericBindings = HashMap<Arc<str>, FeType>;
+
+impl FeTypeResolver {
+    /// Brings a declaration's type parameters into the current scope, so its
+    /// params, fields and body can refer to them.
+    pub(super) fn insert_generics(&self, names: &[Arc<str>]) {
+        let mut scope = self.scope.try_lock().unwrap();
+
+        for name in names {
+            scope.insert(
+                name.clone(),
+                ScopedType {
+                    is_pub: false,
+                    typ: FeType::Generic(name.clone()),
+                    decl: None,
+                },
+            );
+        }
+    }
+
+    /// Binds the type parameters in `param` to the types in the same place in
+    /// `arg`, like `T` to `Int` for an `Int` passed as a `Box<T>`'s `T`.
+    ///
+    /// The first binding wins, later ones are checked against it like any other
+    /// argument.
+    pub(super) fn infer_generics(param: &FeType, arg: &FeType, bindings: &mut GenericBindings) {
+        match (param, arg) {
+            (_, FeType::Owned(arg)) => return Self::infer_generics(param, &arg.of, bindings),
+
+            (FeType::Generic(name), arg) => {
+                bindings
+                    .entry(name.clone())
+                    .or_insert_with(|| Self::without_known_value(arg.clone()));
+            }
+
+            (FeType::Ref(param), FeType::Ref(arg)) => {
+                return Self::infer_generics(&param.of, &arg.of, bindings);
+            }
+
+            // Borrowed implicitly
+            (FeType::Ref(param), arg) => return Self::infer_generics(&param.of, arg, bindings),
+
+            (FeType::Range(param), FeType::Range(arg)) => {
+                return Self::infer_generics(param, arg, bindings);
+            }
+
+            (FeType::Optional(param), FeType::Optional(arg)) => {
+                return Self::infer_generics(param, arg, bindings);
+            }
+
+            // Wrapped implicitly, `none` alone doesn't say what `T` is
+            (FeType::Optional(_), FeType::EmptyOptional) => {}
+            (FeType::Optional(param), arg) => return Self::infer_generics(param, arg, bindings),
+
+            (FeType::Instance(param), FeType::Instance(arg)) if param.name == arg.name => {
+                for (param, arg) in param.generic_args.iter().zip(&arg.generic_args) {
+                    Self::infer_generics(param, arg, bindings);
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    /// `typ` with each bound type parameter replaced by what it's bound to.
+    pub(super) fn substitute_generics(typ: &FeType, bindings: &GenericBindings) -> FeType {
+        match typ {
+            FeType::Generic(name) => {
+                return bindings.get(name).cloned().unwrap_or_else(|| typ.clone());
+            }
+
+            FeType::Ref(of) => {
+                return FeType::Ref(FeRefOf {
+                    ref_type: of.ref_type.clone(),
+                    of: Box::new(Self::substitute_generics(&of.of, bindings)),
+                });
+            }
+
+            FeType::Owned(of) => {
+                return FeType::Owned(FeOwnedOf {
+                    owned_mut: of.owned_mut.clone(),
+                    of: Box::new(Self::substitute_generics(&of.of, bindings)),
+                });
+            }
+
+            FeType::Range(of) => {
+                return FeType::Range(Box::new(Self::substitute_generics(of, bindings)));
+            }
+
+            FeType::Optional(of) => {
+                return FeType::Optional(Box::new(Self::substitute_generics(of, bindings)));
+            }
+
+            FeType::Instance(instance) => {
+                return FeType::Instance(FeInstance {
+                    special: instance.special.clone(),
+                    name: instance.name.clone(),
+                    generic_args: instance
+                        .generic_args
+                        .iter()
+                        .map(|arg| Self::substitute_generics(arg, bindings))
+                        .collect(),
+                    fields: instance
+                        .fields
+                        .iter()
+                        .map(|(name, field)| {
+                            let field = FeStructField {
+                                is_pub: field.is_pub,
+                                name: field.name.clone(),
+                                typ: Self::substitute_generics(&field.typ, bindings),
+                            };
+
+                            return (name.clone(), field);
+                        })
+                        .collect(),
+                    methods: instance.methods.clone(),
+                });
+            }
+
+            typ => return typ.clone(),
+        }
+    }
+
+    /// An instance of `fe_struct`, with its type parameters bound to `generic_args`.
+    pub(super) fn instance_of(fe_struct: FeStruct, generic_args: Vec<FeType>) -> FeInstance {
+        let bindings = fe_struct
+            .generics
+            .iter()
+            .cloned()
+            .zip(generic_args.iter().cloned())
+            .collect::<GenericBindings>();
+
+        return FeInstance {
+            special: None,
+            name: fe_struct.name,
+            generic_args,
+            fields: fe_struct
+                .fields
+                .into_iter()
+                .map(|field| {
+                    let field = FeStructField {
+                        typ: Self::substitute_generics(&field.typ, &bindings),
+                        ..field
+                    };
+
+                    return (field.name.clone(), field);
+                })
+                .collect(),
+            methods: fe_struct.methods,
+        };
+    }
+
+    /// What each of `generics` is bound to, reporting the ones nothing was
+    /// inferred for at `span`.
+    pub(super) fn bound_generics(
+        &self,
+        generics: &[Arc<str>],
+        bindings: &GenericBindings,
+        span: &Span,
+    ) -> Vec<FeType> {
+        return generics
+            .iter()
+            .map(|name| {
+                if let Some(bound) = bindings.get(name) {
+                    return bound.clone();
+                }
+
+                self.report(TypeError::CannotInferGeneric {
+                    name: name.clone(),
+                    span: span.clone(),
+                });
+
+                return FeType::Error;
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int() -> FeType {
+        return FeType::Number(Some(NumberDetails::Integer(None, None)));
+    }
+
+    fn generic(name: &str) -> FeType {
+        return FeType::Generic(name.into());
+    }
+
+    #[test]
+    fn test_infer_generics() {
+        let mut bindings = GenericBindings::new();
+
+        FeTypeResolver::infer_generics(
+            &FeType::Ref(FeRefOf {
+                ref_type: FeRefType::Const,
+                of: Box::new(generic("T")),
+            }),
+            &FeType::Owned(FeOwnedOf {
+                owned_mut: FeOwnedMut::Const,
+                of: Box::new(FeType::Number(Some(NumberDetails::Integer(None, Some(3))))),
+            }),
+            &mut bindings,
+        );
+        FeTypeResolver::infer_generics(&generic("T"), &FeType::Bool(None), &mut bindings);
+
+        assert_eq!(bindings.get("T"), Some(&int()));
+        assert_eq!(
+            FeTypeResolver::substitute_generics(&FeType::Range(Box::new(generic("T"))), &bindings),
+            FeType::Range(Box::new(int()))
+        );
+        assert_eq!(
+            FeTypeResolver::substitute_generics(&generic("U"), &bindings),
+            generic("U")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use super::*;
+
+impl FeTypeResolver {
+    /// Reports an `impl` block for anything but a struct declared in the current
+    /// file, and resolves its target to an error so its methods aren't added.
+    pub(super) fn check_impl_target(&self, target: &mut StaticPath<Option<FeType>>) {
+        let Some(resolved_type) = &target.resolved_type else {
+            return;
+        };
+
+        match resolved_type {
+            FeType::Struct(fe_struct) => {
+                let is_local = target.root.is_none()
+                    && self
+                        .scope
+                        .try_lock()
+                        .unwrap()
+                        .search(&target.name.lexeme)
+                        .and_then(|found| found.decl.as_ref())
+                        .is_some_and(|decl| decl.file == self.current_file);
+
+                if is_local {
+                    return;
+                }
+
+                self.report(TypeError::ForeignImpl {
+                    name: fe_struct.name.clone(),
+                    span: target.span(),
+                });
+            }
+
+            FeType::Error => return,
+
+            other => self.report(TypeError::ImplTargetNotStruct {
+                found: other.clone(),
+                span: target.span(),
+            }),
+        }
+
+        target.resolved_type = Some(FeType::Error);
+    }
+
+    pub(super) fn receiver_of(self_param: &FnDeclSelfParam) -> FeReceiver {
+        match (&self_param.ref_type, &self_param.mut_token) {
+            (Some(RefType::Shared { .. }), _) => return FeReceiver::Ref(FeRefType::Const),
+            (Some(RefType::Mut { .. }), _) => return FeReceiver::Ref(FeRefType::Mut),
+            (None, Some(_)) => return FeReceiver::Owned(FeOwnedMut::Mut),
+            (None, None) => return FeReceiver::Owned(FeOwnedMut::Const),
+        }
+    }
+
+    /// The type of `self` in a method that takes `instance` as `receiver`.
+    pub(super) fn receiver_type(receiver: &FeReceiver, instance: FeType) -> FeType {
+        match receiver {
+            FeReceiver::Ref(ref_type) => {
+                return FeType::Ref(FeRefOf {
+                    ref_type: ref_type.clone(),
+                    of: Box::new(instance),
+                });
+            }
+
+            FeReceiver::Owned(owned_mut) => {
+                return FeType::Owned(FeOwnedOf {
+                    owned_mut: owned_mut.clone(),
+                    of: Box::new(instance),
+                });
+            }
+        }
+    }
+
+    /// An instance of `target` inside its own `impl` blocks, where its type
+    /// parameters are still unknown.
+    pub(super) fn impl_instance(target: &FeStruct) -> FeType {
+        let generic_args = target
+            .generics
+            .iter()
+            .cloned()
+            .map(FeType::Generic)
+            .collect();
+
+        return FeType::Instance(Self::instance_of(target.clone(), generic_args));
+    }
+
+    /// The type of `target.name` for a method of `instance`, where `target` has
+    /// the type `target_type`.
+    pub(super) fn method_on_instance(
+        &self,
+        target: &Expr<Option<FeType>>,
+        target_type: &FeType,
+        instance: &FeInstance,
+        method: FeMethod,
+        name: &Arc<Token>,
+    ) -> FeType {
+        let Some(receiver) = &method.receiver else {
+            self.report(TypeError::NotAMethod {
+                name: name.lexeme.clone(),
+                struct_name: instance.name.clone(),
+                span: name.span.clone(),
+            });
+
+            return FeType::Error;
+        };
+
+        let is_mut = matches!(
+            target_type,
+            FeType::Owned(FeOwnedOf {
+                owned_mut: FeOwnedMut::Mut,
+                ..
+            }) | FeType::Ref(FeRefOf {
+                ref_type: FeRefType::Mut,
+                ..
+            })
+        );
+
+        if *receiver == FeReceiver::Ref(FeRefType::Mut) && !is_mut {
+            let mut diagnostic = self.type_error(TypeError::MutMethodOnConst {
+                name: name.lexeme.clone(),
+                span: name.span.clone(),
+            });
+
+            if let Some(suggestion) = Self::declare_mut_suggestion(self.root_decl(target)) {
+                diagnostic = diagnostic.with_suggestion(suggestion);
+            }
+
+            self.report_diagnostic(diagnostic);
+        }
+
+        // The struct's type parameters come first, and are known from the instance
+        let mut callable = method.callable;
+        let own_generics = callable.generics.split_off(instance.generic_args.len());
+
+        let bindings = callable
+            .generics
+            .iter()
+            .cloned()
+            .zip(instance.generic_args.iter().cloned())
+            .collect();
+
+        return FeType::Callable(Callable {
+            special: callable.special,
+            name: callable.name,
+            generics: own_generics,
+            params: callable
+                .params
+                .iter()
+                .map(|(name, typ)| (name.clone(), Self::substitute_generics(typ, &bindings)))
+                .collect(),
+            return_type: callable
+                .return_type
+                .map(|typ| Box::new(Self::substitute_generics(&typ, &bindings))),
+        });
+    }
+
+    /// The type of `Struct::name` for a method of `target`. A method that takes
+    /// `self` takes it as its first param instead.
+    pub(super) fn method_on_struct(target: &FeStruct, method: FeMethod) -> FeType {
+        let mut callable = method.callable;
+
+        if let Some(receiver) = &method.receiver {
+            let self_type = Self::receiver_type(receiver, Self::impl_instance(target));
+            callable.params.insert(0, ("self".into(), self_type));
+        }
+
+        return FeType::Callable(callable);
+    }
+}
@@ -1,24 +1,40 @@
+mod assignment;
+mod const_eval;
 mod decl;
+mod error;
+mod exhaustiveness;
 mod expr;
+mod generics;
+mod method;
+mod pattern;
 mod scope;
 mod r#static;
 mod stmt;
 mod r#use;
 
+pub use error::*;
 pub use scope::*;
 
+use assignment::DefiniteAssignment;
+use const_eval::ConstEvalError;
+
 use crate::config::Config;
+use crate::diagnostics::{Diagnostic, DiagnosticSuggestion, Diagnostics, ErrorCode};
 use crate::r#type::*;
 use crate::syntax::*;
+use crate::utils::closest_names;
 
 use crate::log;
 use crate::result::Result;
 
+use crate::token::Position;
+use crate::token::Span;
 use crate::token::Token;
 use crate::token::TokenType;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 type SharedDecl = Arc<Mutex<Decl<Option<FeType>>>>;
@@ -27,6 +43,12 @@ pub struct FeTypeResolver {
     cfg: Arc<Config>,
     node_id_gen: NodeIdGen,
 
+    current_file: PathBuf,
+    errors: Arc<Mutex<Vec<Diagnostic>>>,
+
+    /// Set once nothing else can be resolved, so anything still unknown gets reported
+    report_unresolved: bool,
+
     expr_lookup: HashMap<NodeId<Expr>, FeType>,
     decls_to_eval: HashMap<NodeId<Decl>, SharedDecl>,
 
@@ -38,6 +60,10 @@ pub struct FeTypeResolver {
     current_return_type: Option<Option<FeType>>,
     breakable_count: usize,
     thenable_count: usize,
+
+    /// The types named in the fields of each struct and enum of the current file
+    type_refs: HashMap<Arc<str>, Vec<Arc<str>>>,
+    current_type_decl: Option<Arc<str>>,
 }
 
 impl FeTypeResolver {
@@ -57,6 +83,10 @@ impl FeTypeResolver {
         let mut this = Self {
             cfg,
 
+            current_file: PathBuf::new(),
+            errors: Arc::new(Mutex::new(vec![])),
+            report_unresolved: false,
+
             expr_lookup: HashMap::new(),
             decls_to_eval: HashMap::new(),
             scope: Arc::new(Mutex::new(Scope::new(node_id_gen.next()))),
@@ -68,6 +98,9 @@ impl FeTypeResolver {
             breakable_count: 0,
             thenable_count: 0,
 
+            type_refs: HashMap::new(),
+            current_type_decl: None,
+
             node_id_gen,
         };
 
@@ -80,18 +113,58 @@ impl FeTypeResolver {
             log::trace!(2);
 
             if !changed {
-                todo!("Can't resolve!");
+                if !this.report_unresolved {
+                    this.report_unresolved = true;
+                    continue;
+                }
+
+                if this.errors.try_lock().unwrap().is_empty() {
+                    return Err(Diagnostic::error("Can't resolve all types in this package")
+                        .with_code(ErrorCode::UNRESOLVABLE_TYPES)
+                        .with_note("Some declarations depend on types that are never resolved")
+                        .into());
+                }
+
+                break;
             }
         }
 
+        let errors = std::mem::take(&mut *this.errors.try_lock().unwrap());
+        if !errors.is_empty() {
+            return Err(Self::sorted_diagnostics(errors).into());
+        }
+
         let pkg: Mutex<FeSyntaxPackage<Option<FeType>>> =
             Arc::try_unwrap(pkg).expect("Why didn't this work?");
 
         let pkg: FeSyntaxPackage<Option<FeType>> = pkg.into_inner()?;
+        let pkg: FeSyntaxPackage<FeType> = pkg.try_into().map_err(|e| {
+            return Diagnostic::error("Can't resolve all types in this package")
+                .with_code(ErrorCode::UNRESOLVABLE_TYPES)
+                .with_note(format!("This is a compiler bug: {e}"));
+        })?;
+
+        let errors = DefiniteAssignment::check_package(&pkg);
+        if !errors.is_empty() {
+            return Err(Self::sorted_diagnostics(errors).into());
+        }
 
-        return Ok(pkg.try_into()?);
+        return Ok(pkg);
     }
 
+    fn sorted_diagnostics(mut errors: Vec<Diagnostic>) -> Diagnostics {
+        errors.sort_by_key(|e| {
+            let start = e
+                .primary
+                .as_ref()
+                .map(|p| (p.span.start.line, p.span.start.column));
+            return (e.file.clone(), start);
+        });
+
+        return Diagnostics(errors);
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn internal_resolve_package(
         cfg: Arc<Config>,
         node_id_gen: NodeIdGen,
@@ -99,11 +172,17 @@ impl FeTypeResolver {
         current_pkg_exports: Arc<Mutex<ExportsPackage>>,
         scope: Arc<Mutex<Scope>>,
         pkg: Arc<Mutex<FeSyntaxPackage<Option<FeType>>>>,
+        errors: Arc<Mutex<Vec<Diagnostic>>>,
+        report_unresolved: bool,
     ) -> Result<bool> {
         let mut this = Self {
             cfg,
             node_id_gen,
 
+            current_file: PathBuf::new(),
+            errors,
+            report_unresolved,
+
             expr_lookup: HashMap::new(),
             decls_to_eval: HashMap::new(),
             scope,
@@ -114,6 +193,9 @@ impl FeTypeResolver {
             current_return_type: None,
             breakable_count: 0,
             thenable_count: 0,
+
+            type_refs: HashMap::new(),
+            current_type_decl: None,
         };
 
         match &mut *pkg.try_lock().unwrap() {
@@ -161,6 +243,8 @@ impl FeTypeResolver {
                 self.current_pkg_exports.clone(),
                 scope.clone(),
                 pkg.clone(),
+                self.errors.clone(),
+                self.report_unresolved,
             )?;
             is_changed |= changed;
 
@@ -172,6 +256,8 @@ impl FeTypeResolver {
                     self.current_pkg_exports.clone(),
                     scope.clone(),
                     pkg.clone(),
+                    self.errors.clone(),
+                    self.report_unresolved,
                 )?;
             }
         }
@@ -187,12 +273,16 @@ impl FeTypeResolver {
     }
 
     fn resolve_file(&mut self, file: &mut FeSyntaxFile<Option<FeType>>) -> Result<bool> {
+        self.current_file = file.path.clone();
+
         self.fill_scope_with_global_imports()?;
 
         let mut changed = None;
 
         let syntax = file.syntax.try_lock().unwrap();
 
+        self.type_refs = Self::type_refs(&syntax.decls);
+
         for u in &syntax.uses {
             let local = u.accept(self)?;
 
@@ -243,19 +333,44 @@ impl FeTypeResolver {
     fn fill_scope_with_global_imports(&mut self) -> Result {
         let scope = &mut *self.scope.try_lock().unwrap();
 
+        // `Int` is an alias of `I64`
         scope.insert(
             INT_TYPE_NAME.into(),
             ScopedType {
                 is_pub: false,
-                typ: FeType::Number(Some(NumberDetails::Integer(None))),
+                typ: FeType::Number(Some(NumberDetails::Integer(Some(IntegerSize::I64), None))),
+                decl: None,
             },
         );
 
+        for size in IntegerSize::ALL {
+            scope.insert(
+                size.name().into(),
+                ScopedType {
+                    is_pub: false,
+                    typ: FeType::Number(Some(NumberDetails::Integer(Some(*size), None))),
+                    decl: None,
+                },
+            );
+        }
+
+        for size in DecimalSize::ALL {
+            scope.insert(
+                size.name().into(),
+                ScopedType {
+                    is_pub: false,
+                    typ: FeType::Number(Some(NumberDetails::Decimal(Some(*size), None))),
+                    decl: None,
+                },
+            );
+        }
+
         scope.insert(
             STRING_TYPE_NAME.into(),
             ScopedType {
                 is_pub: false,
                 typ: FeType::String(None),
+                decl: None,
             },
         );
 
@@ -264,6 +379,7 @@ impl FeTypeResolver {
             ScopedType {
                 is_pub: false,
                 typ: FeType::Bool(None),
+                decl: None,
             },
         );
 
@@ -275,6 +391,7 @@ impl FeTypeResolver {
                 typ: FeType::Package(Arc::new(Mutex::new(ExportsPackage::File(ExportsFile {
                     scope: Arc::new(Mutex::new(std_pkg_scope)),
                 })))),
+                decl: None,
             },
         );
 
@@ -290,9 +407,11 @@ impl FeTypeResolver {
                 typ: FeType::Callable(Callable {
                     special: Some(SpecialCallable::Print),
                     name: STD_PRINT_FN_NAME.into(),
+                    generics: vec![],
                     params: vec![("text".into(), FeType::String(None))],
                     return_type: None,
                 }),
+                decl: None,
             },
         );
 
@@ -301,42 +420,100 @@ impl FeTypeResolver {
 
     fn evaluate_decl(&mut self, decl: Arc<Mutex<Decl<Option<FeType>>>>) -> Result<bool> {
         match &mut *decl.try_lock().unwrap() {
-            Decl::Fn(shared_decl) => {
-                let decl = &mut *shared_decl.try_lock().unwrap();
+            Decl::Fn(shared_decl) => return self.evaluate_fn(shared_decl, None),
 
-                if let Some(return_type) = &decl.return_type {
-                    if let Some(return_type) = &return_type.resolved_type {
-                        self.current_return_type = Some(Some(return_type.clone()));
-                    } else {
-                        // There is a return type, but haven't resolved it yet?
-                        todo!("I don't think this should ever happen?");
+            Decl::Struct(_) => {
+                // TODO: Check struct field defaults? Otherwise not much to do
+                return Ok(false);
+            }
+
+            Decl::Enum(_) => return Ok(false),
+
+            Decl::Impl(shared_decl) => {
+                let decl = &*shared_decl.try_lock().unwrap();
+
+                let (generics, instance) = match &decl.target.resolved_type {
+                    Some(FeType::Struct(target)) => {
+                        (target.generics.clone(), Self::impl_instance(target))
                     }
-                } else {
-                    self.current_return_type = Some(None);
-                }
+                    _ => (vec![], FeType::Error),
+                };
 
-                self.scope
-                    .try_lock()
-                    .unwrap()
-                    .begin_scope(Some(ScopeCreator::Fn(shared_decl.clone())));
+                let mut changed = false;
+
+                for method in &decl.methods {
+                    let self_type = {
+                        let method = method.try_lock().unwrap();
 
-                let res = self.evaluate_fn_decl(decl);
+                        method.self_param.as_ref().map(|self_param| {
+                            Self::receiver_type(&Self::receiver_of(self_param), instance.clone())
+                        })
+                    };
 
-                self.scope.try_lock().unwrap().end_scope();
+                    self.scope.try_lock().unwrap().begin_scope(None);
+                    self.insert_generics(&generics);
 
-                self.current_return_type = None;
+                    let res = self.evaluate_fn(method, self_type);
 
-                return res;
+                    self.scope.try_lock().unwrap().end_scope();
+
+                    changed |= res?;
+                }
+
+                return Ok(changed);
             }
+        }
+    }
 
-            Decl::Struct(_) => {
-                // TODO: Check struct field defaults? Otherwise not much to do
-                return Ok(false);
+    /// Resolves the body of a function, or of a method that takes `self` as `self_type`.
+    fn evaluate_fn(
+        &mut self,
+        shared_decl: &Arc<Mutex<FnDecl<Option<FeType>>>>,
+        self_type: Option<FeType>,
+    ) -> Result<bool> {
+        let decl = &mut *shared_decl.try_lock().unwrap();
+
+        if let Some(return_type) = &decl.return_type {
+            if let Some(return_type) = &return_type.resolved_type {
+                self.current_return_type = Some(Some(return_type.clone()));
+            } else {
+                // There is a return type, but haven't resolved it yet?
+                todo!("I don't think this should ever happen?");
             }
+        } else {
+            self.current_return_type = Some(None);
+        }
+
+        self.scope
+            .try_lock()
+            .unwrap()
+            .begin_scope(Some(ScopeCreator::Fn(shared_decl.clone())));
+
+        if let Some(self_type) = self_type {
+            self.scope.try_lock().unwrap().insert(
+                "self".into(),
+                ScopedType {
+                    is_pub: false,
+                    typ: self_type,
+                    decl: None,
+                },
+            );
         }
+
+        let res = self.evaluate_fn_decl(decl);
+
+        self.scope.try_lock().unwrap().end_scope();
+
+        self.current_return_type = None;
+
+        return res;
     }
 
     fn evaluate_fn_decl(&mut self, decl: &mut FnDecl<Option<FeType>>) -> Result<bool> {
+        if let Some(generics) = &decl.generics {
+            self.insert_generics(&generics.names());
+        }
+
         // Add fn params to scope
         {
             let mut scope = self.scope.try_lock().unwrap();
@@ -348,6 +525,7 @@ impl FeTypeResolver {
                         ScopedType {
                             is_pub: false,
                             typ: resolved_type.clone(),
+                            decl: None,
                         },
                     );
                 }
@@ -376,16 +554,14 @@ impl FeTypeResolver {
     ) -> Result<(bool, Option<Arc<Mutex<Stmt<Option<FeType>>>>>)> {
         let mut changed = false;
 
+        // Code after the terminal statement is still resolved, the linter reports it
         let mut terminal = None;
-        for stmt in stmts {
-            if let Some(terminal) = &terminal {
-                todo!("Unreachable code after {terminal:#?}!");
-            }
 
+        for stmt in stmts {
             let s = &mut *stmt.try_lock().unwrap();
             changed |= s.accept(self)?;
 
-            if s.is_terminal() {
+            if terminal.is_none() && s.is_terminal() {
                 terminal = Some(stmt.clone());
             }
         }
@@ -397,8 +573,205 @@ impl FeTypeResolver {
         return Ok((changed, None));
     }
 
+    fn error(&self, message: impl Into<Arc<str>>, span: Span) -> Diagnostic {
+        return Diagnostic::error(message)
+            .with_file(self.current_file.clone())
+            .with_span(span);
+    }
+
+    fn check_condition(&self, condition: &Expr<Option<FeType>>) {
+        let Some(resolved_type) = condition.resolved_type() else {
+            self.report(TypeError::NoValue {
+                span: condition.span(),
+            });
+            return;
+        };
+
+        if let Some(resolved_type) = resolved_type {
+            if !Self::can_implicit_cast(&resolved_type, &FeType::Bool(None)) {
+                self.report(TypeError::NonBoolCondition {
+                    found: resolved_type,
+                    span: condition.span(),
+                });
+            }
+        }
+    }
+
+    /// The type of the values `iter` gives a `for` loop, once it's resolved.
+    fn for_item_type(&self, iter: &Expr<Option<FeType>>) -> Option<FeType> {
+        let Some(resolved_type) = iter.resolved_type() else {
+            self.report(TypeError::NoValue { span: iter.span() });
+            return Some(FeType::Error);
+        };
+
+        match resolved_type?.actual_type() {
+            FeType::Range(of) => return Some(*of.clone()),
+            FeType::Error => return Some(FeType::Error),
+
+            other => {
+                self.report(TypeError::NotIterable {
+                    found: other.clone(),
+                    span: iter.span(),
+                });
+                return Some(FeType::Error);
+            }
+        }
+    }
+
+    /// Declares the name a `for` loop binds each value to, in the current scope.
+    fn declare_for_name(&mut self, name: &Arc<Token>, typ: FeType) {
+        self.scope.try_lock().unwrap().insert(
+            name.lexeme.clone(),
+            ScopedType {
+                is_pub: false,
+                typ: FeType::Owned(FeOwnedOf {
+                    owned_mut: FeOwnedMut::Const,
+                    of: Box::new(typ),
+                }),
+                decl: Some(ScopedDecl {
+                    file: self.current_file.clone(),
+                    token: name.clone(),
+                    is_late: false,
+                }),
+            },
+        );
+    }
+
+    fn report(&self, err: TypeError) {
+        self.report_diagnostic(self.type_error(err));
+    }
+
+    /// The error for `found` not implicitly casting to `expected`, which is more
+    /// specific when it's a known number that doesn't fit the size of `expected`.
+    fn mismatched_types(expected: FeType, found: FeType, span: Span) -> TypeError {
+        let out_of_range = match (found.actual_type(), expected.actual_type()) {
+            (
+                FeType::Number(Some(NumberDetails::Integer(None, Some(value)))),
+                FeType::Number(Some(NumberDetails::Integer(Some(size), _))),
+            ) if !size.contains(*value) => Some(value.to_string()),
+
+            (
+                FeType::Number(Some(NumberDetails::Decimal(None, Some(value)))),
+                FeType::Number(Some(NumberDetails::Decimal(Some(size), _))),
+            ) if !size.contains(*value) => Some(value.to_string()),
+
+            _ => None,
+        };
+
+        if let Some(value) = out_of_range {
+            return TypeError::NumberOutOfRange {
+                value: value.into(),
+                expected: expected.actual_type().clone(),
+                span,
+            };
+        }
+
+        return TypeError::MismatchedTypes {
+            expected,
+            found,
+            span,
+        };
+    }
+
+    /// Checks the value of a branch, like one of an `if`'s, against the type of
+    /// the branches before it, which becomes optional when only some are `none`.
+    ///
+    /// A literal without a size in an earlier branch takes the size of a later
+    /// one, like the `1` of `if c then 1 else n`.
+    fn join_branch_type(&self, typ: &mut Option<FeType>, found: FeType, span: Span) {
+        let Some(existing) = typ else {
+            *typ = Some(found);
+            return;
+        };
+
+        if let Some(joined) = Self::join_optional(existing, &found) {
+            *existing = joined;
+        } else if Self::can_implicit_cast(&found, existing) {
+            return;
+        } else if Self::can_implicit_cast(existing, &found) {
+            *existing = found;
+        } else {
+            self.report(Self::mismatched_types(existing.clone(), found, span));
+        }
+    }
+
+    /// The optional both `typ` and `other` cast to, when one of them is `none`
+    /// or only `other` is optional.
+    fn join_optional(typ: &FeType, other: &FeType) -> Option<FeType> {
+        match (typ.actual_type(), other.actual_type()) {
+            (FeType::EmptyOptional, other) | (other, FeType::EmptyOptional) => {
+                return Some(Self::optional_of(other.clone()));
+            }
+
+            (typ, other @ FeType::Optional(of)) if Self::can_implicit_cast(typ, of) => {
+                return Some(other.clone());
+            }
+
+            _ => return None,
+        }
+    }
+
+    /// `T?` for `typ`, unless it's optional already.
+    fn optional_of(typ: FeType) -> FeType {
+        match typ {
+            FeType::Optional(_) | FeType::EmptyOptional | FeType::Error => return typ,
+            typ => return FeType::Optional(Box::new(typ)),
+        }
+    }
+
+    fn type_error(&self, err: TypeError) -> Diagnostic {
+        return self
+            .error(err.to_string(), err.span().clone())
+            .with_code(err.code());
+    }
+
+    /// Suggests the closest of `candidates` in place of the misspelled `name` at `span`.
+    ///
+    /// Only applied automatically when there's exactly one closest candidate.
+    fn typo_suggestion(
+        &self,
+        what: &str,
+        name: &str,
+        span: Span,
+        candidates: Vec<Arc<str>>,
+    ) -> Option<DiagnosticSuggestion> {
+        let matches = closest_names(name, candidates);
+        let best = matches.first()?;
+
+        // A similar name is only a guess, so it's never applied by `--fix`
+        let suggestion = DiagnosticSuggestion::new(
+            format!("{what} with a similar name exists"),
+            span,
+            best.clone(),
+        )
+        .maybe_incorrect();
+
+        return Some(suggestion);
+    }
+
+    fn report_diagnostic(&self, diagnostic: Diagnostic) {
+        let errors = &mut *self.errors.try_lock().unwrap();
+
+        // The same node can be visited on every resolve pass, only report it once
+        if !errors.contains(&diagnostic) {
+            errors.push(diagnostic);
+        }
+    }
+
+    /// Whether a value of this type can be interpolated in a format string. A
+    /// type parameter has no constraints and an optional may be `none`, so
+    /// neither can be.
+    fn is_formattable(typ: &FeType) -> bool {
+        return !matches!(
+            typ,
+            FeType::Generic(_) | FeType::Optional(_) | FeType::EmptyOptional
+        );
+    }
+
     fn can_implicit_cast(from: &FeType, to: &FeType) -> bool {
         match (from, to) {
+            (FeType::Error, _) | (_, FeType::Error) => return true,
+
             (FeType::Ref(from), FeType::Ref(to)) => {
                 if from.ref_type == FeRefType::Const && to.ref_type == FeRefType::Mut {
                     return false;
@@ -411,6 +784,11 @@ impl FeTypeResolver {
                 return Self::can_implicit_cast(owned, of);
             }
 
+            // Numbers and bools are copied out of the reference
+            (FeType::Ref(from), to @ (FeType::Number(_) | FeType::Bool(_))) => {
+                return Self::can_implicit_cast(&from.of, to);
+            }
+
             (FeType::Ref(_), _owned) => return false,
 
             (FeType::Owned(from), to) => {
@@ -421,10 +799,20 @@ impl FeTypeResolver {
                 return Self::can_implicit_cast(from, &to.of);
             }
 
+            (FeType::EmptyOptional, FeType::Optional(_) | FeType::EmptyOptional) => return true,
+            (FeType::Optional(from), FeType::Optional(to)) => {
+                return Self::can_implicit_cast(from, to)
+            }
+
+            // Wrapped in a `Some` implicitly
+            (from, FeType::Optional(to)) => return Self::can_implicit_cast(from, to),
+
             (FeType::String(_), FeType::String(_)) => return true,
             (FeType::String(_), FeType::Bool(_)) => return false,
 
             (FeType::Bool(_), FeType::Bool(_)) => return true,
+
+            (FeType::Range(from), FeType::Range(to)) => return Self::can_implicit_cast(from, to),
             (FeType::Bool(_), FeType::String(_)) => return false,
 
             (FeType::Number(from_details), FeType::Number(to_details)) => {
@@ -432,40 +820,382 @@ impl FeTypeResolver {
                     (_, None) => return true,
 
                     (
-                        Some(NumberDetails::Decimal(from_val)),
-                        Some(NumberDetails::Decimal(to_val)),
-                    ) => match (from_val, to_val) {
-                        // TODO
-                        // (Some(from_val), Some(to_val)) => return *from_val == *to_val,
-                        (None, Some(_)) => false,
-                        _ => true,
-                    },
+                        Some(NumberDetails::Decimal(from_size, from_val)),
+                        Some(NumberDetails::Decimal(to_size, to_val)),
+                    ) => {
+                        // Literals take the size they're cast to, if their value fits it
+                        match (from_size, to_size, from_val) {
+                            (Some(from_size), Some(to_size), _) if from_size != to_size => {
+                                return false;
+                            }
+                            (None, Some(to_size), Some(from_val))
+                                if !to_size.contains(*from_val) =>
+                            {
+                                return false;
+                            }
+                            _ => {}
+                        }
+
+                        match (from_val, to_val) {
+                            // TODO
+                            // (Some(from_val), Some(to_val)) => return *from_val == *to_val,
+                            (None, Some(_)) => false,
+                            _ => true,
+                        }
+                    }
 
-                    (Some(NumberDetails::Decimal(_)), _) => return false,
+                    (Some(NumberDetails::Decimal(..)), _) => return false,
 
                     (
-                        Some(NumberDetails::Integer(from_val)),
-                        Some(NumberDetails::Integer(to_val)),
-                    ) => match (from_val, to_val) {
-                        // (Some(from_val), Some(to_val)) => return *from_val == *to_val,
-                        (None, Some(_)) => false,
-                        _ => true,
-                    },
+                        Some(NumberDetails::Integer(from_size, from_val)),
+                        Some(NumberDetails::Integer(to_size, to_val)),
+                    ) => {
+                        match (from_size, to_size, from_val) {
+                            (Some(from_size), Some(to_size), _) if from_size != to_size => {
+                                return false;
+                            }
+                            (None, Some(to_size), Some(from_val))
+                                if !to_size.contains(*from_val) =>
+                            {
+                                return false;
+                            }
+                            _ => {}
+                        }
+
+                        match (from_val, to_val) {
+                            // (Some(from_val), Some(to_val)) => return *from_val == *to_val,
+                            (None, Some(_)) => false,
+                            _ => true,
+                        }
+                    }
+
+                    // Only literals, a sized integer has to be converted explicitly
+                    (
+                        Some(NumberDetails::Integer(Some(_), _)),
+                        Some(NumberDetails::Decimal(..)),
+                    ) => {
+                        return false;
+                    }
                     (
-                        Some(NumberDetails::Integer(from_val)),
-                        Some(NumberDetails::Decimal(to_val)),
+                        Some(NumberDetails::Integer(None, from_val)),
+                        Some(NumberDetails::Decimal(_, to_val)),
                     ) => match (from_val, to_val) {
                         // (Some(from_val), Some(to_val)) => return *from_val as f64 == *to_val,
                         (None, Some(_)) => false,
                         _ => true,
                     },
 
-                    (None, Some(NumberDetails::Integer(_))) => return false,
-                    (None, Some(NumberDetails::Decimal(_))) => return true,
+                    (None, Some(NumberDetails::Integer(..))) => return false,
+                    (None, Some(NumberDetails::Decimal(..))) => return true,
                 }
             }
 
-            _ => todo!("Can you cast?\nThis: {from:#?}\nTo: {to:#?}"),
+            // Type arguments cast like the values they stand for, so a
+            // `Box { value: 1 }` can be a `Box<U8>`
+            (FeType::Instance(from), FeType::Instance(to)) if !to.generic_args.is_empty() => {
+                return from.name == to.name
+                    && from.generic_args.len() == to.generic_args.len()
+                    && from
+                        .generic_args
+                        .iter()
+                        .zip(&to.generic_args)
+                        .all(|(from, to)| Self::can_implicit_cast(from, to));
+            }
+
+            _ => return from == to,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::lexer::FeLexer;
+    use crate::parser::FeSyntaxParser;
+    use crate::source::{FeSourceFile, FeSourcePackage, SourcePackageName};
+
+    fn resolve(code: &str) -> Result<Vec<(String, usize)>> {
+        let cfg = Arc::new(Config::default());
+
+        let source = FeSourcePackage::File(FeSourceFile {
+            name: SourcePackageName("_main".into()),
+            path: "./_main.fe".into(),
+            content: code.into(),
+        });
+
+        let tokens = FeLexer::scan_package(cfg.clone(), Arc::new(Mutex::new(source)))?;
+        let pkg = FeSyntaxParser::parse_package(cfg.clone(), Arc::new(Mutex::new(tokens)))?;
+
+        let Err(err) = FeTypeResolver::resolve_package(cfg, pkg) else {
+            return Ok(vec![]);
+        };
+
+        let Some(diagnostics) = Diagnostics::from_error(&err) else {
+            return Err(err);
+        };
+
+        return Ok(diagnostics
+            .0
+            .into_iter()
+            .map(|d| {
+                let line = d.primary.as_ref().unwrap().span.start.line;
+                return (d.code.unwrap().to_string(), line);
+            })
+            .collect());
+    }
+
+    fn codes(expected: &[(&str, usize)]) -> Vec<(String, usize)> {
+        return expected
+            .iter()
+            .map(|(code, line)| (code.to_string(), *line))
+            .collect();
+    }
+
+    #[test]
+    fn test_type_errors() -> Result {
+        let diagnostics = resolve(
+            "struct Point {
+    x: Int,
+    y: Int,
+}
+
+fn check(p: &Point, flag: Bool): Int
+    if 1
+    ;
+
+    const diff = \"a\" - 1
+    const dup = Point { x: 1, x: 2, y: 3 }
+    const missing = Point { x: 1 }
+    const size = flag.size
+    p.x = 2
+
+    break
+
+    loop'outer
+        break
+    ;
+
+    if flag
+        return
+    ;
+
+    return \"no\"
+;
+
+fn nothing()
+    return 1
+;
+",
+        )?;
+
+        assert_eq!(
+            diagnostics,
+            codes(&[
+                ("FE0016", 7),  // if 1
+                ("FE0021", 10), // "a" - 1
+                ("FE0024", 11), // x: 2
+                ("FE0025", 12), // missing y
+                ("FE0026", 13), // flag.size
+                ("FE0030", 14), // p.x = 2
+                ("FE0034", 16), // break
+                ("FE0068", 19), // break inside loop'outer
+                ("FE0032", 23), // return
+                ("FE0014", 26), // return "no"
+                ("FE0033", 30), // return 1
+            ])
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_number_out_of_range() -> Result {
+        let diagnostics = resolve(
+            "fn sizes()
+    const small: U8 = 255
+    const big: U8 = 256
+    const low: I8 = -129
+    const largest = 9223372036854775807
+    const over = 9223372036854775808
+;
+",
+        )?;
+
+        assert_eq!(
+            diagnostics,
+            codes(&[
+                ("FE0047", 3), // 256
+                ("FE0047", 4), // -129
+                ("FE0047", 6), // 9223372036854775808
+            ])
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_late_init() -> Result {
+        let diagnostics = resolve(
+            "fn late(flag: Bool): Int
+    const once: Int
+    once = 1
+
+    const twice: Int
+    twice = 1
+    twice = 2
+
+    const branch: Int
+    if flag
+        branch = 1
+    ;
+
+    return once + twice + branch
+;
+",
+        )?;
+
+        assert_eq!(
+            diagnostics,
+            codes(&[
+                ("FE0029", 7),  // twice = 2
+                ("FE0051", 14), // branch
+            ])
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_mut_method_on_const() -> Result {
+        let diagnostics = resolve(
+            "struct Counter {
+    count: Int,
+}
+
+impl Counter
+    fn increment(&mut self)
+        self.count += 1
+    ;
+;
+
+fn count()
+    mut counter = Counter { count: 0 }
+    counter.increment()
+
+    const fixed = Counter { count: 0 }
+    fixed.increment()
+;
+",
+        )?;
+
+        assert_eq!(diagnostics, codes(&[("FE0060", 16)]));
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_format_generic() -> Result {
+        let diagnostics = resolve(
+            "use ::fe::print
+
+fn show<T>(value: T, count: Int)
+    print(\"{count}\")
+    print(\"{value}\")
+;
+",
+        )?;
+
+        assert_eq!(diagnostics, codes(&[("FE0067", 5)]));
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_optional_errors() -> Result {
+        let diagnostics = resolve(
+            "use ::fe::print
+
+fn optionals(m: Int?)
+    const known: Int? = none
+    const unknown = none
+    print(\"{m == none}\")
+    print(\"{m}\")
+    print(\"{none}\")
+;
+",
+        )?;
+
+        assert_eq!(
+            diagnostics,
+            codes(&[
+                ("FE0070", 5), // none
+                ("FE0067", 7), // m
+                ("FE0067", 8), // none
+            ])
+        );
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_while_expr_branches() -> Result {
+        let diagnostics = resolve(
+            "fn count(): Int
+    mut i = 0
+    const found = while i < 3
+        i += 1
+        if i == 2
+            break i
+        ;
+    else 0
+    return found
+;
+",
+        )?;
+
+        assert_eq!(diagnostics, codes(&[("FE0004", 8)])); // else
+
+        return Ok(());
+    }
+
+    #[test]
+    fn test_recursive_types() -> Result {
+        let diagnostics = resolve(
+            "enum List {
+    Cons(Int, List),
+    Nil,
+}
+
+struct Node {
+    next: Node?,
+}
+
+struct Tree {
+    root: Branch,
+    unknown: Leaf,
+}
+
+struct Branch {
+    tree: Tree,
+}
+
+fn length(list: List, node: Node): Int
+    return 0
+;
+",
+        )?;
+
+        assert_eq!(
+            diagnostics,
+            codes(&[
+                ("FE0071", 2),  // List
+                ("FE0071", 7),  // Node
+                ("FE0071", 11), // Branch
+                ("FE0011", 12), // Leaf
+            ])
+        );
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,302 @@
+use super::*;
+
+use exhaustiveness::{Ctor, Pat};
+
+impl FeTypeResolver {
+    /// Checks that `pattern` can match a value of type `expected`, and declares
+    /// the names it binds in the current scope.
+    pub(super) fn check_pattern(
+        &mut self,
+        pattern: &mut Pattern<Option<FeType>>,
+        expected: &FeType,
+    ) -> Result<(bool, Pat)> {
+        match pattern {
+            Pattern::Wildcard(_) => return Ok((false, Pat::Wild)),
+
+            Pattern::Binding(binding) => {
+                let changed = binding.resolved_type.is_none();
+
+                binding.resolved_type = Some(expected.clone());
+                self.declare_for_name(&binding.name, expected.clone());
+
+                return Ok((changed, Pat::Wild));
+            }
+
+            Pattern::Literal(literal) => {
+                let value = literal.value.0.try_lock().unwrap();
+
+                let changed = value.accept(self)?;
+
+                let Some(Some(resolved_type)) = value.resolved_type() else {
+                    return Ok((changed, Pat::Wild));
+                };
+
+                // Only `none` matches an optional, a value isn't wrapped like it's cast
+                let wrapped = matches!(expected.actual_type(), FeType::Optional(_))
+                    && !matches!(resolved_type.actual_type(), FeType::EmptyOptional);
+
+                if wrapped || !Self::can_implicit_cast(&resolved_type, expected) {
+                    self.report(Self::mismatched_types(
+                        expected.clone(),
+                        resolved_type,
+                        value.span(),
+                    ));
+
+                    return Ok((changed, Pat::Wild));
+                }
+
+                return Ok((
+                    changed,
+                    Pat::Ctor(Self::literal_ctor(&resolved_type), vec![]),
+                ));
+            }
+
+            Pattern::Variant(variant) => return self.check_variant_pattern(variant, expected),
+            Pattern::Struct(fe_struct) => return self.check_struct_pattern(fe_struct, expected),
+        }
+    }
+
+    fn literal_ctor(typ: &FeType) -> Ctor {
+        match typ.actual_type() {
+            FeType::Bool(Some(value)) => return Ctor::Bool(*value),
+            FeType::Number(Some(NumberDetails::Integer(_, Some(value)))) => {
+                return Ctor::Literal(value.to_string().into());
+            }
+            FeType::Number(Some(NumberDetails::Decimal(_, Some(value)))) => {
+                return Ctor::Literal(value.to_string().into());
+            }
+            other => return Ctor::Literal(other.to_string().into()),
+        }
+    }
+
+    /// The enum a variant's path like `Shape::Circle` is in, with the variant's index.
+    fn path_variant(path: &StaticPath<Option<FeType>>) -> Option<(FeEnum, usize)> {
+        let Some(FeType::Enum(fe_enum)) = path.root.as_ref()?.resolved_type.clone() else {
+            return None;
+        };
+
+        let idx = fe_enum
+            .variants
+            .iter()
+            .position(|variant| variant.name == path.name.lexeme)?;
+
+        return Some((fe_enum, idx));
+    }
+
+    fn check_variant_pattern(
+        &mut self,
+        pattern: &mut VariantPattern<Option<FeType>>,
+        expected: &FeType,
+    ) -> Result<(bool, Pat)> {
+        let changed = pattern.path.accept(self)?;
+
+        let Some(resolved_type) = pattern.path.resolved_type.clone() else {
+            return Ok((changed, Pat::Wild));
+        };
+
+        let Some((fe_enum, idx)) = Self::path_variant(&pattern.path) else {
+            if resolved_type != FeType::Error {
+                self.report(TypeError::NotAPattern {
+                    found: resolved_type,
+                    span: pattern.path.span(),
+                });
+            }
+
+            return Ok((changed, Pat::Wild));
+        };
+
+        let instance = FeType::EnumInstance(fe_enum.clone());
+        if !Self::can_implicit_cast(&instance, expected) {
+            self.report(Self::mismatched_types(
+                expected.clone(),
+                instance,
+                pattern.path.span(),
+            ));
+
+            return Ok((changed, Pat::Wild));
+        }
+
+        let variant = &fe_enum.variants[idx];
+
+        let types = match (&variant.fields, &mut pattern.fields) {
+            (FeEnumVariantFields::Unit, None) => vec![],
+            (FeEnumVariantFields::Tuple(types), Some(_)) => types.clone(),
+
+            (FeEnumVariantFields::Unit, Some(fields)) => {
+                self.report(TypeError::PatternFieldCount {
+                    name: variant.name.clone(),
+                    expected: 0,
+                    found: fields.fields.len(),
+                    span: fields
+                        .open_paren_token
+                        .span
+                        .to(&fields.close_paren_token.span),
+                });
+
+                return Ok((changed, Pat::Wild));
+            }
+
+            _ => {
+                self.report(TypeError::NotAPattern {
+                    found: resolved_type,
+                    span: pattern.path.span(),
+                });
+
+                return Ok((changed, Pat::Wild));
+            }
+        };
+
+        let Some(fields) = &mut pattern.fields else {
+            return Ok((changed, Pat::Ctor(Ctor::Variant(idx), vec![])));
+        };
+
+        if fields.fields.len() != types.len() {
+            self.report(TypeError::PatternFieldCount {
+                name: variant.name.clone(),
+                expected: types.len(),
+                found: fields.fields.len(),
+                span: fields
+                    .open_paren_token
+                    .span
+                    .to(&fields.close_paren_token.span),
+            });
+
+            return Ok((changed, Pat::Wild));
+        }
+
+        let mut changed = changed;
+        let mut pats = vec![];
+
+        for (field, typ) in fields.fields.iter_mut().zip(&types) {
+            let (field_changed, pat) = self.check_pattern(&mut field.pattern, typ)?;
+
+            changed |= field_changed;
+            pats.push(pat);
+        }
+
+        return Ok((changed, Pat::Ctor(Ctor::Variant(idx), pats)));
+    }
+
+    fn check_struct_pattern(
+        &mut self,
+        pattern: &mut StructPattern<Option<FeType>>,
+        expected: &FeType,
+    ) -> Result<(bool, Pat)> {
+        let mut changed = pattern.path.accept(self)?;
+
+        let Some(resolved_type) = pattern.path.resolved_type.clone() else {
+            return Ok((changed, Pat::Wild));
+        };
+
+        let (struct_name, ctor, fields) = match &resolved_type {
+            FeType::Struct(FeStruct {
+                special: Some(SpecialStruct::EnumVariant(_)),
+                name,
+                ..
+            }) => {
+                let Some((fe_enum, idx)) = Self::path_variant(&pattern.path) else {
+                    return Ok((changed, Pat::Wild));
+                };
+
+                let instance = FeType::EnumInstance(fe_enum);
+                if !Self::can_implicit_cast(&instance, expected) {
+                    self.report(Self::mismatched_types(
+                        expected.clone(),
+                        instance,
+                        pattern.path.span(),
+                    ));
+
+                    return Ok((changed, Pat::Wild));
+                }
+
+                let ctor = Ctor::Variant(idx);
+                let fields = Self::ctor_fields(&instance, &ctor);
+
+                (name.clone(), ctor, fields)
+            }
+
+            FeType::Struct(fe_struct) => {
+                match expected.actual_type() {
+                    FeType::Instance(instance) if instance.name == fe_struct.name => {}
+                    FeType::Error => return Ok((changed, Pat::Wild)),
+
+                    _ => {
+                        self.report(Self::mismatched_types(
+                            expected.clone(),
+                            resolved_type.clone(),
+                            pattern.path.span(),
+                        ));
+
+                        return Ok((changed, Pat::Wild));
+                    }
+                }
+
+                // The expected instance's fields have its type parameters filled in
+                let ctor = Ctor::Single;
+                let fields = Self::ctor_fields(expected, &ctor);
+
+                (fe_struct.name.clone(), ctor, fields)
+            }
+
+            FeType::Error => return Ok((changed, Pat::Wild)),
+
+            other => {
+                self.report(TypeError::NotAPattern {
+                    found: other.clone(),
+                    span: pattern.path.span(),
+                });
+
+                return Ok((changed, Pat::Wild));
+            }
+        };
+
+        let mut pats = vec![Pat::Wild; fields.len()];
+        let mut seen = HashSet::new();
+
+        for field in &mut pattern.fields {
+            let name = &field.name.lexeme;
+
+            let Some(idx) = fields.iter().position(|(field_name, _)| field_name == name) else {
+                self.report(TypeError::UnknownField {
+                    name: name.clone(),
+                    struct_name: struct_name.clone(),
+                    span: field.name.span.clone(),
+                });
+
+                continue;
+            };
+
+            if !seen.insert(name.clone()) {
+                self.report(TypeError::DuplicateField {
+                    name: name.clone(),
+                    span: field.name.span.clone(),
+                });
+
+                continue;
+            }
+
+            let (field_changed, pat) = self.check_pattern(&mut field.pattern, &fields[idx].1)?;
+
+            changed |= field_changed;
+            pats[idx] = pat;
+        }
+
+        if pattern.rest_token.is_none() {
+            let missing = fields
+                .iter()
+                .map(|(name, _)| name.clone())
+                .filter(|name| !seen.contains(name))
+                .collect::<Vec<_>>();
+
+            if !missing.is_empty() {
+                self.report(TypeError::MissingFields {
+                    struct_name,
+                    fields: missing,
+                    span: pattern.path.span(),
+                });
+            }
+        }
+
+        return Ok((changed, Pat::Ctor(ctor, pats)));
+    }
+}
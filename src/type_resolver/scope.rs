@@ -102,12 +102,29 @@ pub enum ScopeCreator {
     WhileExpr(Arc<Mutex<WhileExpr<Option<FeType>>>>),
     LoopStmt(Arc<Mutex<LoopStmt<Option<FeType>>>>),
     LoopExpr(Arc<Mutex<LoopExpr<Option<FeType>>>>),
+    ForStmt(Arc<Mutex<ForStmt<Option<FeType>>>>),
+    ForExpr(Arc<Mutex<ForExpr<Option<FeType>>>>),
+    MatchExpr(Arc<Mutex<MatchExpr<Option<FeType>>>>),
 }
 
 #[derive(Debug, Clone)]
 pub struct ScopedType {
     pub is_pub: bool,
     pub typ: FeType,
+
+    /// Where the name was declared, for suggesting fixes to the declaration.
+    /// Not set for builtins or imported names.
+    pub decl: Option<ScopedDecl>,
+}
+
+/// The first token of a declaration, e.g. `fn`, `struct` or `const`, and its file.
+#[derive(Debug, Clone)]
+pub struct ScopedDecl {
+    pub file: PathBuf,
+    pub token: Arc<Token>,
+
+    /// Declared without a value, to be assigned later
+    pub is_late: bool,
 }
 
 impl Scope {
@@ -138,6 +155,20 @@ impl Scope {
         self.stack.last_mut().unwrap().name_lookup.insert(name, typ);
     }
 
+    /// Every name visible from the current scope.
+    pub fn names(&self) -> Vec<Arc<str>> {
+        let mut names = self
+            .stack
+            .iter()
+            .flat_map(|data| data.name_lookup.keys().cloned())
+            .collect::<Vec<Arc<str>>>();
+
+        names.sort();
+        names.dedup();
+
+        return names;
+    }
+
     // pub fn update(&mut self, name: &str, typ: ScopedType) {
     //     for data in self.stack.iter_mut().rev() {
     //         if let Some(found) = data.name_lookup.get_mut(name) {
@@ -176,10 +207,8 @@ impl Scope {
 
         for scope in self.stack.iter().rev() {
             match &scope.creator {
-                Some(ScopeCreator::IfStmt(block, v)) => {
-                    if label.is_none() {
-                        return Some(ThenHandler::IfStmt(block.clone(), v.clone()));
-                    }
+                Some(ScopeCreator::IfStmt(block, v)) if label.is_none() => {
+                    return Some(ThenHandler::IfStmt(block.clone(), v.clone()));
                 }
 
                 Some(ScopeCreator::IfExpr(block, v)) => {
@@ -213,6 +242,20 @@ impl Scope {
                     return Some(ThenHandler::IfExpr(block.clone(), v.clone()));
                 }
 
+                Some(ScopeCreator::MatchExpr(v)) => {
+                    if let Some(label) = label {
+                        let Some(scope_label) = v.lock().unwrap().label.clone() else {
+                            continue;
+                        };
+
+                        if label != scope_label.lexeme.as_ref() {
+                            continue;
+                        }
+                    }
+
+                    return Some(ThenHandler::MatchExpr(v.clone()));
+                }
+
                 _ => {}
             }
         }
@@ -225,52 +268,70 @@ impl Scope {
 
         for scope in self.stack.iter().rev() {
             match &scope.creator {
-                Some(ScopeCreator::LoopStmt(v)) => {
+                Some(ScopeCreator::LoopStmt(v))
                     if label
                         == v.try_lock()
                             .unwrap()
                             .label
                             .as_ref()
-                            .map(|l| l.lexeme.as_ref())
-                    {
-                        return Some(BreakHandler::LoopStmt(v.clone()));
-                    }
+                            .map(|l| l.lexeme.as_ref()) =>
+                {
+                    return Some(BreakHandler::LoopStmt(v.clone()));
                 }
 
-                Some(ScopeCreator::LoopExpr(v)) => {
+                Some(ScopeCreator::LoopExpr(v))
                     if label
                         == v.try_lock()
                             .unwrap()
                             .label
                             .as_ref()
-                            .map(|l| l.lexeme.as_ref())
-                    {
-                        return Some(BreakHandler::LoopExpr(v.clone()));
-                    }
+                            .map(|l| l.lexeme.as_ref()) =>
+                {
+                    return Some(BreakHandler::LoopExpr(v.clone()));
                 }
 
-                Some(ScopeCreator::WhileStmt(v)) => {
+                Some(ScopeCreator::WhileStmt(v))
                     if label
                         == v.try_lock()
                             .unwrap()
                             .label
                             .as_ref()
-                            .map(|l| l.lexeme.as_ref())
-                    {
-                        return Some(BreakHandler::WhileStmt(v.clone()));
-                    }
+                            .map(|l| l.lexeme.as_ref()) =>
+                {
+                    return Some(BreakHandler::WhileStmt(v.clone()));
                 }
 
-                Some(ScopeCreator::WhileExpr(v)) => {
+                Some(ScopeCreator::WhileExpr(v))
                     if label
                         == v.try_lock()
                             .unwrap()
                             .label
                             .as_ref()
-                            .map(|l| l.lexeme.as_ref())
-                    {
-                        return Some(BreakHandler::WhileExpr(v.clone()));
-                    }
+                            .map(|l| l.lexeme.as_ref()) =>
+                {
+                    return Some(BreakHandler::WhileExpr(v.clone()));
+                }
+
+                Some(ScopeCreator::ForStmt(v))
+                    if label
+                        == v.try_lock()
+                            .unwrap()
+                            .label
+                            .as_ref()
+                            .map(|l| l.lexeme.as_ref()) =>
+                {
+                    return Some(BreakHandler::ForStmt(v.clone()));
+                }
+
+                Some(ScopeCreator::ForExpr(v))
+                    if label
+                        == v.try_lock()
+                            .unwrap()
+                            .label
+                            .as_ref()
+                            .map(|l| l.lexeme.as_ref()) =>
+                {
+                    return Some(BreakHandler::ForExpr(v.clone()));
                 }
 
                 _ => {}
@@ -8,37 +8,57 @@ impl StaticVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         let mut changed = static_type.static_path.accept(self)?;
 
+        let mut generic_args = vec![];
+
+        if let Some(args) = &mut static_type.generic_args {
+            for arg in &mut args.args {
+                changed |= arg.static_type.accept(self)?;
+
+                if let Some(resolved_type) = &arg.static_type.resolved_type {
+                    generic_args.push(resolved_type.clone());
+                }
+            }
+
+            if !args.is_resolved() {
+                return Ok(changed);
+            }
+        }
+
+        let span = static_type.span();
+
+        // A struct or enum's name used as a type stands for an instance of it
+        let of = static_type
+            .static_path
+            .resolved_type
+            .clone()
+            .map(|typ| self.instance_type(typ, generic_args, span));
+
+        let of = match &static_type.question_token {
+            Some(_) => of.map(|typ| FeType::Optional(Box::new(typ))),
+            None => of,
+        };
+
         // TODO: Handle references
         match static_type.ref_type {
             Some(RefType::Shared { .. }) => {
-                static_type.resolved_type =
-                    static_type
-                        .static_path
-                        .resolved_type
-                        .clone()
-                        .map(|resolved_type| {
-                            FeType::Ref(FeRefOf {
-                                ref_type: FeRefType::Const,
-                                of: Box::new(resolved_type),
-                            })
-                        });
+                static_type.resolved_type = of.map(|resolved_type| {
+                    FeType::Ref(FeRefOf {
+                        ref_type: FeRefType::Const,
+                        of: Box::new(resolved_type),
+                    })
+                });
             }
 
             Some(RefType::Mut { .. }) => {
-                static_type.resolved_type =
-                    static_type
-                        .static_path
-                        .resolved_type
-                        .clone()
-                        .map(|resolved_type| {
-                            FeType::Ref(FeRefOf {
-                                ref_type: FeRefType::Mut,
-                                of: Box::new(resolved_type),
-                            })
-                        });
+                static_type.resolved_type = of.map(|resolved_type| {
+                    FeType::Ref(FeRefOf {
+                        ref_type: FeRefType::Mut,
+                        of: Box::new(resolved_type),
+                    })
+                });
             }
 
-            None => static_type.resolved_type = static_type.static_path.resolved_type.clone(),
+            None => static_type.resolved_type = of,
         }
 
         if !changed && static_type.static_path.is_resolved() {
@@ -58,14 +78,36 @@ impl StaticVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let scope = if let Some(root) = &mut static_path.root {
             changed |= root.accept(self)?;
 
-            if let Some(root_type) = &root.resolved_type {
-                let FeType::Package(pkg) = root_type else {
-                    todo!("Cannot path on root type: {root_type:#?}");
-                };
+            match &root.resolved_type {
+                Some(FeType::Package(pkg)) => Some(pkg.try_lock().unwrap().scope()),
 
-                Some(pkg.try_lock().unwrap().scope())
-            } else {
-                None
+                Some(FeType::Struct(target)) => {
+                    let target = target.clone();
+                    return Ok(self.resolve_struct_path(static_path, &target) || changed);
+                }
+
+                Some(FeType::Enum(target)) => {
+                    let target = target.clone();
+                    self.resolve_enum_path(static_path, &target);
+                    return Ok(true);
+                }
+
+                Some(FeType::Error) => {
+                    static_path.resolved_type = Some(FeType::Error);
+                    return Ok(true);
+                }
+
+                Some(other) => {
+                    self.report(TypeError::NotAPackage {
+                        found: other.clone(),
+                        span: root.span(),
+                    });
+
+                    static_path.resolved_type = Some(FeType::Error);
+                    return Ok(true);
+                }
+
+                None => None,
             }
         } else {
             Some(self.scope.clone())
@@ -76,15 +118,260 @@ impl StaticVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
             let name = &static_path.name.lexeme;
 
+            let is_root = static_path.root.is_none();
+
             if let Some(typ) = scope.search(name) {
                 static_path.resolved_type = Some(typ.typ.clone());
                 changed = true;
-            } else {
-                // crate::log::trace!(&scope);
-                // todo!("Check scope for imported type: {name}");
+            } else if is_root && self.type_refs.contains_key(name) {
+                // A type of this file that isn't declared yet, because one of its
+                // fields isn't resolved yet or because it contains itself
+                if !self.report_unresolved {
+                    return Ok(changed);
+                }
+
+                let Some(decl_name) = self.current_type_decl.clone() else {
+                    return Ok(changed);
+                };
+
+                if !self.contains_type(name, &decl_name) {
+                    return Ok(changed);
+                }
+
+                self.report(TypeError::RecursiveType {
+                    name: decl_name,
+                    span: static_path.name.span.clone(),
+                });
+
+                static_path.resolved_type = Some(FeType::Error);
+                changed = true;
+            } else if self.report_unresolved {
+                let mut diagnostic = self.type_error(TypeError::UnknownType {
+                    name: name.clone(),
+                    span: static_path.name.span.clone(),
+                });
+
+                if let Some(suggestion) = self.typo_suggestion(
+                    "a type",
+                    name,
+                    static_path.name.span.clone(),
+                    scope.names(),
+                ) {
+                    diagnostic = diagnostic.with_suggestion(suggestion);
+                }
+
+                self.report_diagnostic(diagnostic);
+
+                static_path.resolved_type = Some(FeType::Error);
+                changed = true;
             }
         }
 
         return Ok(changed);
     }
 }
+
+impl FeTypeResolver {
+    /// The names of the types in the fields of each struct and enum in `decls`.
+    pub(super) fn type_refs(decls: &[SharedDecl]) -> HashMap<Arc<str>, Vec<Arc<str>>> {
+        fn named_types(static_type: &StaticType<Option<FeType>>, names: &mut Vec<Arc<str>>) {
+            if static_type.static_path.root.is_none() {
+                names.push(static_type.static_path.name.lexeme.clone());
+            }
+
+            for arg in static_type.generic_args.iter().flat_map(|args| &args.args) {
+                named_types(&arg.static_type, names);
+            }
+        }
+
+        let mut type_refs = HashMap::new();
+
+        for decl in decls {
+            let mut names = vec![];
+
+            let name = match &*decl.try_lock().unwrap() {
+                Decl::Struct(decl) => {
+                    let decl = decl.try_lock().unwrap();
+
+                    for field in &decl.fields {
+                        named_types(&field.static_type_ref, &mut names);
+                    }
+
+                    decl.name.lexeme.clone()
+                }
+
+                Decl::Enum(decl) => {
+                    let decl = decl.try_lock().unwrap();
+
+                    for variant in &decl.variants {
+                        match &variant.fields {
+                            EnumDeclVariantFields::Unit => {}
+                            EnumDeclVariantFields::Tuple { fields, .. } => {
+                                for field in fields {
+                                    named_types(&field.static_type_ref, &mut names);
+                                }
+                            }
+                            EnumDeclVariantFields::Struct { fields, .. } => {
+                                for field in fields {
+                                    named_types(&field.static_type_ref, &mut names);
+                                }
+                            }
+                        }
+                    }
+
+                    decl.name.lexeme.clone()
+                }
+
+                Decl::Fn(_) | Decl::Impl(_) => continue,
+            };
+
+            type_refs.insert(name, names);
+        }
+
+        return type_refs;
+    }
+
+    /// Whether the fields of the struct or enum `name` hold a `target`, directly or
+    /// through the other types of the current file.
+    fn contains_type(&self, name: &str, target: &str) -> bool {
+        let mut seen = HashSet::new();
+        let mut next = vec![name];
+
+        while let Some(name) = next.pop() {
+            if name == target {
+                return true;
+            }
+
+            if !seen.insert(name) {
+                continue;
+            }
+
+            if let Some(names) = self.type_refs.get(name) {
+                next.extend(names.iter().map(|name| name.as_ref()));
+            }
+        }
+
+        return false;
+    }
+
+    /// Resolves `Struct::name` to one of the struct's methods or associated functions.
+    fn resolve_struct_path(
+        &self,
+        static_path: &mut StaticPath<Option<FeType>>,
+        target: &FeStruct,
+    ) -> bool {
+        let name = &static_path.name;
+
+        if let Some(method) = target.methods.get(&name.lexeme) {
+            static_path.resolved_type = Some(Self::method_on_struct(target, method));
+            return true;
+        }
+
+        // Methods of a struct from another package might not be known yet
+        if !self.report_unresolved {
+            return false;
+        }
+
+        let mut diagnostic = self.type_error(TypeError::UnknownMethod {
+            name: name.lexeme.clone(),
+            struct_name: target.name.clone(),
+            span: name.span.clone(),
+        });
+
+        if let Some(suggestion) = self.typo_suggestion(
+            "a function",
+            &name.lexeme,
+            name.span.clone(),
+            target.methods.names(),
+        ) {
+            diagnostic = diagnostic.with_suggestion(suggestion);
+        }
+
+        self.report_diagnostic(diagnostic);
+
+        static_path.resolved_type = Some(FeType::Error);
+        return true;
+    }
+
+    /// Resolves `Enum::Variant` to what the variant is used as: a value for a unit
+    /// variant, a function for a tuple variant and a struct for a struct variant.
+    fn resolve_enum_path(&self, static_path: &mut StaticPath<Option<FeType>>, target: &FeEnum) {
+        let name = &static_path.name;
+
+        let Some(variant) = target.variant(&name.lexeme) else {
+            let mut diagnostic = self.type_error(TypeError::UnknownVariant {
+                name: name.lexeme.clone(),
+                enum_name: target.name.clone(),
+                span: name.span.clone(),
+            });
+
+            let names = target
+                .variants
+                .iter()
+                .map(|variant| variant.name.clone())
+                .collect();
+            if let Some(suggestion) =
+                self.typo_suggestion("a variant", &name.lexeme, name.span.clone(), names)
+            {
+                diagnostic = diagnostic.with_suggestion(suggestion);
+            }
+
+            self.report_diagnostic(diagnostic);
+
+            static_path.resolved_type = Some(FeType::Error);
+            return;
+        };
+
+        let resolved_type = match &variant.fields {
+            FeEnumVariantFields::Unit => FeType::EnumInstance(target.clone()),
+
+            FeEnumVariantFields::Tuple(types) => FeType::Callable(Callable {
+                special: None,
+                name: variant.name.clone(),
+                generics: vec![],
+                params: types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, typ)| (i.to_string().into(), typ.clone()))
+                    .collect(),
+                return_type: Some(Box::new(FeType::EnumInstance(target.clone()))),
+            }),
+
+            FeEnumVariantFields::Struct(fields) => FeType::Struct(FeStruct {
+                special: Some(SpecialStruct::EnumVariant(target.clone())),
+                name: format!("{}::{}", target.name, variant.name).into(),
+                generics: vec![],
+                fields: fields.clone(),
+                methods: FeMethods::default(),
+            }),
+        };
+
+        static_path.resolved_type = Some(resolved_type);
+    }
+
+    /// The type a name used as a type stands for, given the types in its `<...>`.
+    fn instance_type(&self, typ: FeType, generic_args: Vec<FeType>, span: Span) -> FeType {
+        let expected = match &typ {
+            FeType::Struct(of) => of.generics.len(),
+            FeType::Error => return typ,
+            _ => 0,
+        };
+
+        if generic_args.len() != expected {
+            self.report(TypeError::WrongGenericArgCount {
+                name: typ.to_string().into(),
+                expected,
+                found: generic_args.len(),
+                span,
+            });
+
+            return FeType::Error;
+        }
+
+        match typ {
+            FeType::Struct(of) => return FeType::Instance(Self::instance_of(of, generic_args)),
+            FeType::Enum(of) => return FeType::EnumInstance(of),
+            typ => return typ,
+        }
+    }
+}
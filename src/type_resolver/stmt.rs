@@ -1,7 +1,5 @@
 use super::*;
 
-use crate::log;
-
 impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
     fn visit_expr_stmt(&mut self, stmt: Arc<Mutex<ExprStmt<Option<FeType>>>>) -> Result<bool> {
         return stmt
@@ -21,7 +19,7 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         let mut changed = false;
 
-        let typ = if let Some(value) = &stmt.value {
+        let value_type = if let Some(value) = &stmt.value {
             let value = &mut *value.value.0.try_lock().unwrap();
 
             changed |= value.accept(self)?;
@@ -31,9 +29,69 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             None
         };
 
-        // TODO: check explicit types
+        let typ = if let Some(explicit_type) = &mut stmt.explicit_type {
+            changed |= explicit_type.static_type.accept(self)?;
+
+            let Some(explicit) = explicit_type.static_type.resolved_type.clone() else {
+                return Ok(changed);
+            };
+
+            match (&value_type, &stmt.value) {
+                (Some(value_type), Some(value)) => {
+                    if !Self::can_implicit_cast(value_type, &explicit) {
+                        self.report(Self::mismatched_types(
+                            explicit.clone(),
+                            value_type.clone(),
+                            value.value.0.try_lock().unwrap().span(),
+                        ));
+                    }
+
+                    Some(Self::with_known_value_of(explicit, value_type))
+                }
+
+                // The value isn't resolved yet
+                (None, Some(_)) => None,
+
+                (_, None) => Some(explicit),
+            }
+        } else if stmt.value.is_none() {
+            let VarDeclTarget::Ident(ident) = &stmt.target;
+            let name = ident.try_lock().unwrap().ident.clone();
+
+            self.report(TypeError::MissingTypeAnnotation {
+                name: name.lexeme.clone(),
+                span: name.span.clone(),
+            });
+
+            Some(FeType::Error)
+        } else if let (Some(FeType::EmptyOptional), Some(value)) = (&value_type, &stmt.value) {
+            self.report(TypeError::UnknownNoneType {
+                span: value.value.0.try_lock().unwrap().span(),
+            });
+
+            Some(FeType::Error)
+        } else if let (Some(value_type), Some(value)) = (value_type, &stmt.value) {
+            let typ = value_type.clone().with_default_number_size();
+
+            if !Self::can_implicit_cast(&value_type, &typ) {
+                self.report(Self::mismatched_types(
+                    typ.clone(),
+                    value_type,
+                    value.value.0.try_lock().unwrap().span(),
+                ));
+            }
+
+            Some(typ)
+        } else {
+            None
+        };
+
+        if let Some(mut typ) = typ {
+            // Only a const keeps the value it starts with
+            if matches!(stmt.var_mut, VarDeclMut::Mut(_)) {
+                typ = Self::without_known_value(typ);
+            }
 
-        if let Some(typ) = typ {
             match &mut stmt.target {
                 VarDeclTarget::Ident(ident) => {
                     self.scope.try_lock().unwrap().insert(
@@ -47,6 +105,15 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                                 },
                                 of: Box::new(typ),
                             }),
+                            decl: Some(ScopedDecl {
+                                file: self.current_file.clone(),
+                                token: match &stmt.var_mut {
+                                    VarDeclMut::Const(token) | VarDeclMut::Mut(token) => {
+                                        token.clone()
+                                    }
+                                },
+                                is_late: stmt.value.is_none(),
+                            }),
                         },
                     );
 
@@ -75,8 +142,6 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             let target = &mut *stmt.target.0.try_lock().unwrap();
             changed |= target.accept(self)?;
 
-            // TODO: ensure LHS expr is assignable (unassigned const ident || mut ident || instance_ref)
-
             types.0 = target.resolved_type().flatten();
 
             if let Some(resolved_type) = &types.0 {
@@ -84,18 +149,39 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                     FeType::Ref(ref_of) => {
                         if ref_of.ref_type != FeRefType::Mut {
                             // TODO: handle assigning late to non-assigned const ref
-                            todo!("Reference is not mutable: {:#?}", target);
+                            self.report(TypeError::AssignThroughSharedRef {
+                                span: target.span(),
+                            });
                         }
                     }
 
                     FeType::Owned(owned_of) => {
-                        if owned_of.owned_mut != FeOwnedMut::Mut {
-                            // TODO: handle assigning late to non-assigned const
-                            todo!("Owned type is not mutable: {:#?}", target);
+                        let decl = self.root_decl(target);
+
+                        // Assigning a const declared without a value is checked once
+                        // everything is resolved, see `DefiniteAssignment`
+                        let is_late = matches!(target, Expr::Ident(_))
+                            && decl.as_ref().is_some_and(|decl| decl.is_late);
+
+                        if owned_of.owned_mut != FeOwnedMut::Mut && !is_late {
+                            let mut diagnostic = self.type_error(TypeError::AssignToConst {
+                                span: target.span(),
+                            });
+
+                            if let Some(suggestion) = Self::declare_mut_suggestion(decl) {
+                                diagnostic = diagnostic.with_suggestion(suggestion);
+                            }
+
+                            self.report_diagnostic(diagnostic);
                         }
                     }
 
-                    other => todo!("Cannot assign to {other:?}"),
+                    FeType::Error => {}
+
+                    other => self.report(TypeError::NotAssignable {
+                        found: other.clone(),
+                        span: target.span(),
+                    }),
                 }
             }
         }
@@ -109,11 +195,29 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         if let (Some(target_type), Some(value_type)) = types {
             if !Self::can_implicit_cast(&value_type, target_type.actual_type()) {
-                todo!(
-                    "Can't assign types!\nFrom: {:#?}\nTo: {:#?}",
+                self.report(Self::mismatched_types(
+                    target_type.actual_type().clone(),
                     value_type,
-                    target_type.actual_type()
-                );
+                    stmt.value.0.try_lock().unwrap().span(),
+                ));
+            } else if !matches!(stmt.op, AssignOp::Eq(_))
+                && !matches!(target_type.actual_type(), FeType::Number(_) | FeType::Error)
+            {
+                self.report(TypeError::InvalidBinaryOperands {
+                    op: stmt.op.token().lexeme.clone(),
+                    lhs: target_type.actual_type().clone(),
+                    rhs: value_type,
+                    span: stmt.span(),
+                });
+            } else if matches!(stmt.op, AssignOp::DivideEq(_) | AssignOp::ModuloEq(_))
+                && matches!(
+                    value_type.actual_type(),
+                    FeType::Number(Some(NumberDetails::Integer(_, Some(0))))
+                )
+            {
+                self.report(TypeError::DivisionByZero {
+                    span: stmt.value.0.try_lock().unwrap().span(),
+                });
             }
         }
 
@@ -131,11 +235,20 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let stmt = &mut *shared_stmt.try_lock().unwrap();
 
         let Some(current_return_type) = self.current_return_type.clone() else {
-            todo!("Return statements not allowed!");
+            self.report(TypeError::ReturnOutsideFn { span: stmt.span() });
+            stmt.checked = true;
+            return Ok(false);
         };
 
-        if stmt.value.is_none() && current_return_type.is_some() {
-            todo!("Can't return without a value!");
+        if stmt.value.is_none() && !stmt.checked {
+            stmt.checked = true;
+
+            if let Some(expected) = &current_return_type {
+                self.report(TypeError::MissingReturnValue {
+                    expected: expected.clone(),
+                    span: stmt.return_token.span.clone(),
+                });
+            }
         }
 
         if stmt.is_resolved() {
@@ -147,16 +260,24 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         if let Some(value) = &stmt.value {
             changed |= value.0.try_lock().unwrap().accept(self)?;
 
+            let value_span = value.0.try_lock().unwrap().span();
+
             if let Some(resolved_type) = value.0.try_lock().unwrap().resolved_type().flatten() {
                 match current_return_type {
                     Some(return_type) => {
                         if !Self::can_implicit_cast(&resolved_type, &return_type) {
-                            todo!("Can't cast to return type!")
+                            self.report(Self::mismatched_types(
+                                return_type,
+                                resolved_type,
+                                value_span,
+                            ));
                         }
                     }
 
-                    None => todo!("Can't return a value! No return type!"),
+                    None => self.report(TypeError::UnexpectedReturnValue { span: value_span }),
                 }
+
+                stmt.checked = true;
             }
         }
 
@@ -185,15 +306,7 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             if !condition.is_resolved() {
                 changed |= condition.accept(self)?;
 
-                let Some(resolved_type) = condition.resolved_type() else {
-                    todo!("Can't check if condition on no type!");
-                };
-
-                if let Some(resolved_type) = resolved_type {
-                    if !Self::can_implicit_cast(&resolved_type, &FeType::Bool(None)) {
-                        todo!("Can't cast to bool!");
-                    }
-                }
+                self.check_condition(condition);
             }
         }
 
@@ -230,15 +343,7 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
                         if !condition.is_resolved() {
                             changed |= condition.accept(self)?;
 
-                            let Some(resolved_type) = condition.resolved_type() else {
-                                todo!("Can't check if condition on no type!");
-                            };
-
-                            if let Some(resolved_type) = resolved_type {
-                                if !Self::can_implicit_cast(&resolved_type, &FeType::Bool(None)) {
-                                    todo!("Can't cast to bool!");
-                                }
-                            }
+                            self.check_condition(condition);
                         }
                     }
 
@@ -302,7 +407,7 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         self.scope
             .try_lock()
             .unwrap()
-            .begin_scope(Some(ScopeCreator::LoopStmt(shared_stmt)));
+            .begin_scope(Some(ScopeCreator::LoopStmt(shared_stmt.clone())));
 
         self.breakable_count += 1;
         let (local_changed, _terminal) = self.resolve_stmts(&stmts)?;
@@ -310,6 +415,13 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         self.scope.try_lock().unwrap().end_scope();
 
+        // Unless a `break` exits it, nothing after the loop runs
+        shared_stmt
+            .try_lock()
+            .unwrap()
+            .resolved_terminal
+            .get_or_insert(true);
+
         changed |= local_changed;
 
         return Ok(changed);
@@ -344,7 +456,7 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         self.scope
             .try_lock()
             .unwrap()
-            .begin_scope(Some(ScopeCreator::WhileStmt(shared_stmt)));
+            .begin_scope(Some(ScopeCreator::WhileStmt(shared_stmt.clone())));
 
         self.breakable_count += 1;
         let (local_changed, _terminal) = self.resolve_stmts(&stmts)?;
@@ -352,11 +464,60 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         self.scope.try_lock().unwrap().end_scope();
 
+        // A condition that's always true makes it a `loop`
+        let resolved_type = condition.0.try_lock().unwrap().resolved_type();
+        if let Some(Some(FeType::Bool(Some(true)))) = resolved_type {
+            shared_stmt
+                .try_lock()
+                .unwrap()
+                .resolved_terminal
+                .get_or_insert(true);
+        }
+
         changed |= local_changed;
 
         return Ok(changed);
     }
 
+    fn visit_for_stmt(&mut self, shared_stmt: Arc<Mutex<ForStmt<Option<FeType>>>>) -> Result<bool> {
+        let (iter, name, stmts) = {
+            let stmt = &mut *shared_stmt.try_lock().unwrap();
+
+            if stmt.is_resolved() {
+                return Ok(false);
+            }
+
+            (
+                stmt.iter.clone(),
+                stmt.name.clone(),
+                stmt.block.stmts.clone(),
+            )
+        };
+
+        let mut changed = false;
+
+        changed |= iter.0.try_lock().unwrap().accept(self)?;
+
+        let Some(item_type) = self.for_item_type(&iter.0.try_lock().unwrap()) else {
+            return Ok(changed);
+        };
+
+        self.scope
+            .try_lock()
+            .unwrap()
+            .begin_scope(Some(ScopeCreator::ForStmt(shared_stmt)));
+
+        self.declare_for_name(&name, item_type.with_default_number_size());
+
+        self.breakable_count += 1;
+        changed |= self.resolve_stmts(&stmts)?.0;
+        self.breakable_count -= 1;
+
+        self.scope.try_lock().unwrap().end_scope();
+
+        return Ok(changed);
+    }
+
     fn visit_break_stmt(
         &mut self,
         shared_stmt: Arc<Mutex<BreakStmt<Option<FeType>>>>,
@@ -364,7 +525,8 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let stmt = &mut *shared_stmt.try_lock().unwrap();
 
         if self.breakable_count == 0 {
-            todo!("Can't break here! {stmt:#?}");
+            self.report(TypeError::BreakOutsideLoop { span: stmt.span() });
+            return Ok(false);
         }
 
         if stmt.is_resolved() {
@@ -386,70 +548,118 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
             None
         };
 
-        let Some(break_handler) = self
+        let break_handler = self
             .scope
             .try_lock()
             .unwrap()
-            .handle_break(stmt.label.clone())
-        else {
-            log::error!(&stmt, &self.scope);
-            todo!();
+            .handle_break(stmt.label.clone());
+
+        let Some(break_handler) = break_handler else {
+            match &stmt.label {
+                Some(label) => self.report(TypeError::UnknownLabel {
+                    label: label.lexeme.clone(),
+                    span: label.span.clone(),
+                }),
+                None => self.report(TypeError::UnlabeledBreak {
+                    span: stmt.break_token.span.clone(),
+                }),
+            }
+
+            return Ok(changed);
         };
 
         stmt.handler = Some(break_handler.clone());
 
-        match break_handler {
-            BreakHandler::LoopStmt(_loop_stmt) => {
-                if stmt.value.is_some() {
-                    todo!("Can't break a value");
-                }
+        let value_span = stmt
+            .value
+            .as_ref()
+            .map(|value| value.0.try_lock().unwrap().span());
+
+        // Code after a loop that's broken out of can run
+        match &break_handler {
+            BreakHandler::LoopStmt(loop_stmt) => {
+                loop_stmt.try_lock().unwrap().resolved_terminal = Some(false);
             }
-            BreakHandler::WhileStmt(_while_stmt) => {
-                if stmt.value.is_some() {
-                    todo!("Can't break a value");
-                }
+            BreakHandler::WhileStmt(while_stmt) => {
+                while_stmt.try_lock().unwrap().resolved_terminal = Some(false);
             }
+            _ => {}
+        }
 
-            BreakHandler::LoopExpr(loop_expr) => {
-                if stmt.value.is_none() {
-                    todo!("TODO: ?::None");
+        match break_handler {
+            BreakHandler::LoopStmt(_) | BreakHandler::WhileStmt(_) | BreakHandler::ForStmt(_) => {
+                if let Some(span) = value_span {
+                    self.report(TypeError::BreakWithValue { span });
                 }
+            }
 
+            BreakHandler::LoopExpr(loop_expr) => {
                 let loop_expr = &mut *loop_expr.try_lock().unwrap();
 
-                if let Some(Some(loop_typ)) = &loop_expr.resolved_type {
-                    let Some(typ) = &resolved_type else {
-                        todo!();
-                    };
+                let mut joined = loop_expr.resolved_type.clone().flatten();
 
-                    if !Self::can_implicit_cast(typ, loop_typ) {
-                        todo!();
+                match (value_span, resolved_type) {
+                    // Breaking without a value gives the loop `none`
+                    (None, _) => {
+                        self.join_branch_type(&mut joined, FeType::EmptyOptional, stmt.span());
+                    }
+
+                    (Some(span), Some(typ)) => self.join_branch_type(&mut joined, typ, span),
+
+                    (Some(span), None) => {
+                        if joined.is_some() {
+                            self.report(TypeError::NoValue { span });
+                        }
                     }
                 }
 
-                loop_expr.resolved_type = Some(resolved_type);
+                loop_expr.resolved_type = Some(joined.map(Self::without_known_value));
                 changed = true;
             }
             BreakHandler::WhileExpr(while_expr) => {
-                if stmt.value.is_none() {
-                    todo!("TODO: ?::None");
-                }
-
                 let while_expr = &mut *while_expr.try_lock().unwrap();
 
-                if let Some(Some(loop_typ)) = &while_expr.resolved_type {
-                    let Some(typ) = &resolved_type else {
-                        todo!();
-                    };
+                let mut joined = while_expr.resolved_type.clone().flatten();
 
-                    if !Self::can_implicit_cast(typ, loop_typ) {
-                        todo!();
+                match (value_span, resolved_type) {
+                    // Breaking without a value gives the loop `none`
+                    (None, _) => {
+                        self.join_branch_type(&mut joined, FeType::EmptyOptional, stmt.span());
+                    }
+
+                    (Some(span), Some(typ)) => self.join_branch_type(&mut joined, typ, span),
+
+                    (Some(span), None) => {
+                        if joined.is_some() {
+                            self.report(TypeError::NoValue { span });
+                        }
                     }
                 }
 
-                while_expr.resolved_type = Some(resolved_type);
+                while_expr.resolved_type = Some(joined.map(Self::without_known_value));
                 changed = true;
             }
+            BreakHandler::ForExpr(for_expr) => {
+                let Some(span) = value_span else {
+                    self.report(TypeError::NoValue { span: stmt.span() });
+                    return Ok(changed);
+                };
+
+                let for_expr = &mut *for_expr.try_lock().unwrap();
+
+                match (&resolved_type, &for_expr.resolved_type) {
+                    (None, _) => self.report(TypeError::NoValue { span }),
+
+                    // The else value decides the type of the loop
+                    (Some(typ), Some(Some(for_typ))) => {
+                        if !Self::can_implicit_cast(typ, for_typ) {
+                            self.report(Self::mismatched_types(for_typ.clone(), typ.clone(), span));
+                        }
+                    }
+
+                    (Some(_), _) => {}
+                }
+            }
         }
 
         return Ok(changed);
@@ -462,7 +672,8 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let stmt = &mut *shared_stmt.try_lock().unwrap();
 
         if self.thenable_count == 0 {
-            todo!("Can't then here! {stmt:#?}");
+            self.report(TypeError::ThenOutsideIf { span: stmt.span() });
+            return Ok(false);
         }
 
         if stmt.is_resolved() {
@@ -473,46 +684,100 @@ impl StmtVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
 
         changed |= stmt.value.0.try_lock().unwrap().accept(self)?;
 
-        if let Some(resolved_type) = stmt.value.0.try_lock().unwrap().resolved_type() {
+        let value_span = stmt.value.0.try_lock().unwrap().span();
+        let resolved_type = stmt.value.0.try_lock().unwrap().resolved_type();
+
+        if let Some(resolved_type) = resolved_type {
             stmt.resolved_type = resolved_type.clone();
 
-            let Some(then_handler) = self
+            let then_handler = self
                 .scope
                 .try_lock()
                 .unwrap()
-                .handle_then(stmt.label.clone())
-            else {
-                log::error!(&stmt, &self.scope);
-                todo!();
+                .handle_then(stmt.label.clone());
+
+            let Some(then_handler) = then_handler else {
+                match &stmt.label {
+                    Some(label) => self.report(TypeError::UnknownLabel {
+                        label: label.lexeme.clone(),
+                        span: label.span.clone(),
+                    }),
+                    None => self.report(TypeError::UnlabeledThen {
+                        span: stmt.then_token.span.clone(),
+                    }),
+                }
+
+                return Ok(changed);
             };
 
             stmt.handler = Some(then_handler.clone());
 
             match then_handler {
-                ThenHandler::IfStmt(_block, if_stmt) => {
-                    todo!("If statement cannot accept a value: {if_stmt:#?}");
+                ThenHandler::IfStmt(_block, _if_stmt) => {
+                    self.report(TypeError::ThenWithValue { span: value_span });
                 }
                 ThenHandler::IfExpr(_block, if_expr) => {
                     let if_expr = &mut *if_expr.try_lock().unwrap();
 
-                    if let Some(Some(if_typ)) = &if_expr.resolved_type {
-                        let Some(typ) = &resolved_type else {
-                            todo!();
-                        };
+                    let mut joined = if_expr.resolved_type.clone().flatten();
+                    if let Some(typ) = resolved_type {
+                        self.join_branch_type(&mut joined, typ, value_span);
+                    }
 
-                        if !Self::can_implicit_cast(typ, if_typ) {
-                            todo!();
-                        }
+                    if_expr.resolved_type = Some(joined.map(Self::without_known_value));
+                    changed |= true;
+                }
+                ThenHandler::MatchExpr(match_expr) => {
+                    let match_expr = &mut *match_expr.try_lock().unwrap();
+
+                    let mut joined = match_expr.resolved_type.clone().flatten();
+                    if let Some(typ) = resolved_type {
+                        self.join_branch_type(&mut joined, typ, value_span);
                     }
 
-                    if_expr.resolved_type = Some(resolved_type);
+                    match_expr.resolved_type = Some(joined.map(Self::without_known_value));
                     changed |= true;
                 }
             }
         } else {
-            todo!();
+            self.report(TypeError::NoValue { span: value_span });
+            stmt.resolved_type = Some(FeType::Error);
         }
 
         return Ok(changed);
     }
 }
+
+impl FeTypeResolver {
+    /// Where the variable an expression like `a.b.c` uses, `a`, is declared.
+    pub(super) fn root_decl(&self, expr: &Expr<Option<FeType>>) -> Option<ScopedDecl> {
+        let name = root_ident(expr)?;
+
+        let scope = self.scope.try_lock().unwrap();
+        return scope.search(&name).and_then(|found| found.decl.clone());
+    }
+
+    /// Suggests changing a `const` declaration to `mut`, so what it declares can change.
+    pub(super) fn declare_mut_suggestion(decl: Option<ScopedDecl>) -> Option<DiagnosticSuggestion> {
+        let decl = decl?;
+
+        if decl.token.token_type != TokenType::Const {
+            return None;
+        }
+
+        return Some(DiagnosticSuggestion::new(
+            "declare it with 'mut'",
+            decl.token.span.clone(),
+            "mut",
+        ));
+    }
+}
+
+/// The variable an assignment target like `a.b.c` writes to, `a`.
+fn root_ident(expr: &Expr<Option<FeType>>) -> Option<Arc<str>> {
+    match expr {
+        Expr::Ident(ident) => return Some(ident.try_lock().unwrap().ident.lexeme.clone()),
+        Expr::Get(get) => return root_ident(&get.try_lock().unwrap().target.0.try_lock().unwrap()),
+        _ => return None,
+    }
+}
@@ -11,12 +11,19 @@ impl UseVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
         let mut changed = false;
         let is_pub = matches!(use_decl.use_mod, Some(UseMod::Pub(_)));
 
-        let types = recursive_resolve(self, self.scope.clone(), &mut use_decl.path)?;
+        let types = recursive_resolve(self, self.scope.clone(), false, &mut use_decl.path)?;
 
         let scope = &mut *self.scope.try_lock().unwrap();
 
         for (name, typ) in types {
-            scope.insert(name, ScopedType { is_pub, typ });
+            scope.insert(
+                name,
+                ScopedType {
+                    is_pub,
+                    typ,
+                    decl: None,
+                },
+            );
             changed = true;
         }
 
@@ -24,9 +31,12 @@ impl UseVisitor<Option<FeType>, Result<bool>> for FeTypeResolver {
     }
 }
 
+/// `is_exports` is set once the path has entered another package, where only
+/// `pub` items can be imported.
 fn recursive_resolve(
     resolver: &mut FeTypeResolver,
     search_scope: Arc<Mutex<Scope>>,
+    is_exports: bool,
     path: &mut UseStaticPath<Option<FeType>>,
 ) -> Result<Vec<(Arc<str>, FeType)>> {
     let search_scope = match &path.pre {
@@ -49,33 +59,51 @@ fn recursive_resolve(
                     .get(&SyntaxPackageName(path.name.lexeme.clone()))
                     .cloned(),
 
-                ExportsPackage::File(f) => f
-                    .scope
-                    .try_lock()
-                    .unwrap()
-                    .search(&path.name.lexeme)
-                    .map(|st| match &st.typ {
-                        FeType::Package(pkg) => pkg.clone(),
-                        _ => todo!("Can't export!"),
-                    }),
+                ExportsPackage::File(f) => {
+                    let found = f
+                        .scope
+                        .try_lock()
+                        .unwrap()
+                        .search(&path.name.lexeme)
+                        .map(|st| st.typ.clone());
+
+                    match found {
+                        Some(FeType::Package(pkg)) => Some(pkg),
+                        Some(other) => {
+                            resolver.report(TypeError::NotAPackage {
+                                found: other,
+                                span: path.name.span.clone(),
+                            });
+
+                            return Ok(vec![]);
+                        }
+                        None => None,
+                    }
+                }
             };
 
             let Some(search_scope) = search_scope else {
+                report_unresolved_import(resolver, path);
                 return Ok(vec![]);
-                // todo!("Couldn't find! {path:#?}");
             };
 
             let search_scope = search_scope.try_lock().unwrap().scope();
 
             match next {
                 UseStaticPathNext::Single(next) => {
-                    types.extend(recursive_resolve(resolver, search_scope, &mut next.path)?);
+                    types.extend(recursive_resolve(
+                        resolver,
+                        search_scope,
+                        true,
+                        &mut next.path,
+                    )?);
                 }
                 UseStaticPathNext::Many(nexts) => {
                     for next in &mut nexts.nexts {
                         types.extend(recursive_resolve(
                             resolver,
                             search_scope.clone(),
+                            true,
                             &mut next.path,
                         )?);
                     }
@@ -90,7 +118,7 @@ fn recursive_resolve(
                     .cloned();
 
                 let Some(found) = found else {
-                    // todo!("package not found: {:#?}", path.name);
+                    report_unresolved_import(resolver, path);
                     return Ok(vec![]);
                 };
 
@@ -105,23 +133,76 @@ fn recursive_resolve(
                 let t = if let Some(typ) = typ {
                     Some(typ.clone())
                 } else {
-                    f.scope
+                    let found = f
+                        .scope
                         .try_lock()
                         .unwrap()
                         .search(&path.name.lexeme)
-                        .map(|st| st.typ.clone())
+                        .cloned();
+
+                    if let Some(found) = &found {
+                        if is_exports && !found.is_pub {
+                            report_private_import(resolver, &path.name, found);
+                        }
+                    }
+
+                    found.map(|st| st.typ)
                 };
 
-                if let Some(t) = t {
-                    *typ = Some(t.clone());
-                    types.push((path.name.lexeme.clone(), t));
-                } else {
-                    // log::trace!(&search_scope, &path.name);
-                    todo!("type not found: {:#?}", path.name);
-                }
+                // The package may not have declared it yet, see `report_unresolved_import`
+                let Some(t) = t else {
+                    if !resolver.report_unresolved {
+                        return Ok(vec![]);
+                    }
+
+                    resolver.report(TypeError::UnresolvedImport {
+                        name: path.name.lexeme.clone(),
+                        span: path.name.span.clone(),
+                    });
+
+                    *typ = Some(FeType::Error);
+                    types.push((path.name.lexeme.clone(), FeType::Error));
+                    return Ok(types);
+                };
+
+                *typ = Some(t.clone());
+                types.push((path.name.lexeme.clone(), t));
             }
         },
     }
 
     return Ok(types);
 }
+
+/// Local packages are registered as the resolver walks the package tree, so a
+/// missing one is only an error once nothing else can be resolved.
+fn report_unresolved_import(resolver: &FeTypeResolver, path: &UseStaticPath<Option<FeType>>) {
+    if !resolver.report_unresolved {
+        return;
+    }
+
+    resolver.report(TypeError::UnresolvedImport {
+        name: path.name.lexeme.clone(),
+        span: path.name.span.clone(),
+    });
+}
+
+fn report_private_import(resolver: &FeTypeResolver, name: &Token, found: &ScopedType) {
+    let mut diagnostic = resolver.type_error(TypeError::PrivateImport {
+        name: name.lexeme.clone(),
+        span: name.span.clone(),
+    });
+
+    if let Some(decl) = &found.decl {
+        diagnostic = diagnostic.with_suggestion(
+            DiagnosticSuggestion::new(
+                format!("make '{}' public", name.lexeme),
+                decl.token.span.clone(),
+                format!("pub {}", decl.token.lexeme),
+            )
+            .in_file(decl.file.clone()),
+        );
+    }
+
+    resolver.report_diagnostic(diagnostic);
+}
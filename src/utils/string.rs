@@ -1,4 +1,5 @@
 use std::ops::Deref;
+use std::sync::Arc;
 
 pub trait StringUtils {
     fn char_at(&self, index: usize) -> Option<char>;
@@ -13,3 +14,85 @@ impl<T: Deref<Target = str>> StringUtils for T {
         return self.chars().nth(index);
     }
 }
+
+/// The number of single character insertions, deletions and substitutions to turn `a` into `b`.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(a_char != *b_char);
+
+            current[j + 1] = substitution.min(prev[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut current);
+    }
+
+    return prev[b.len()];
+}
+
+/// The candidates closest to `name`, for suggesting a fix to a typo.
+///
+/// Only candidates within a third of the length of `name` are considered, so
+/// unrelated short names aren't suggested. Sorted, so the result is stable.
+pub fn closest_names(name: &str, candidates: impl IntoIterator<Item = Arc<str>>) -> Vec<Arc<str>> {
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    let mut best_distance = max_distance;
+    let mut best = vec![];
+
+    for candidate in candidates {
+        let distance = edit_distance(name, &candidate);
+
+        if distance == 0 || distance > best_distance {
+            continue;
+        }
+
+        if distance < best_distance {
+            best_distance = distance;
+            best.clear();
+        }
+
+        if !best.contains(&candidate) {
+            best.push(candidate);
+        }
+    }
+
+    best.sort();
+
+    return best;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_names() {
+        assert_eq!(edit_distance("count", "cuont"), 2);
+        assert_eq!(edit_distance("", "abc"), 3);
+
+        let candidates: Vec<Arc<str>> = vec!["count".into(), "counter".into(), "x".into()];
+
+        assert_eq!(
+            closest_names("cont", candidates.clone()),
+            Vec::<Arc<str>>::from(["count".into()])
+        );
+        assert_eq!(
+            closest_names("y", candidates.clone()),
+            Vec::<Arc<str>>::from(["x".into()])
+        );
+        assert!(closest_names("total", candidates).is_empty());
+
+        let candidates: Vec<Arc<str>> = vec!["dot".into(), "I8".into()];
+
+        assert!(closest_names("bog", candidates.clone()).is_empty());
+        assert!(closest_names("zz", candidates).is_empty());
+    }
+}
@@ -1,10 +1,12 @@
+#![allow(clippy::needless_return)]
+
 use ferrum_compiler::helpers::run_full;
 use ferrum_compiler::result::Result;
 
 use std::path::PathBuf;
 use std::{env, fs};
 
-const CARGO_MANIFEST_DIR: &'static str = "CARGO_MANIFEST_DIR";
+const CARGO_MANIFEST_DIR: &str = "CARGO_MANIFEST_DIR";
 
 #[test]
 fn test_examples() -> Result {